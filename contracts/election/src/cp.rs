@@ -0,0 +1,89 @@
+/// Chaum-Pedersen proof checks for share disputes (no_std, k256).
+///
+/// Mirrors `stellot_client::cp`:
+///
+///   c = SHA256(G || PK_j || C1 || D_j || R1 || R2) mod q
+///   s·G == R1 + c·PK_j  and  s·C1 == R2 + c·D_j
+///
+/// `PK_j` is derived from the DKG's Feldman commitment matrix, which the
+/// election's dispute policy pins with `vss_digest`.
+use k256::elliptic_curve::ops::Reduce;
+use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+use k256::elliptic_curve::PrimeField;
+use k256::{AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar, U256};
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+use crate::types::CpProof;
+
+const VSS_PREFIX: &[u8] = b"stellot:vss";
+
+/// SHA256("stellot:vss" || rows_le32 || (len_le32 || A_j0 || A_j1 || …) per row).
+/// Every row's length is hashed, so a ragged matrix with the same points
+/// cannot share a digest with the DKG's.
+pub fn vss_digest(env: &Env, commitments: &Vec<Vec<Bytes>>) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.extend_from_slice(VSS_PREFIX);
+    data.extend_from_slice(&commitments.len().to_le_bytes());
+    for row in commitments.iter() {
+        data.extend_from_slice(&row.len().to_le_bytes());
+        for a in row.iter() {
+            data.append(&a);
+        }
+    }
+    env.crypto().sha256(&data).into()
+}
+
+/// Public share `PK_i = Σ_j Σ_k A_jk · i^k` of Shamir index `i` (1-based),
+/// or `None` if any commitment is not a point.
+pub fn public_share(commitments: &Vec<Vec<Bytes>>, i: u32) -> Option<ProjectivePoint> {
+    let x = Scalar::from(i as u64);
+    let mut pk = ProjectivePoint::IDENTITY;
+    for row in commitments.iter() {
+        let mut acc = ProjectivePoint::IDENTITY;
+        for k in (0..row.len()).rev() {
+            acc = acc * x + decode_point(&row.get(k).unwrap())?;
+        }
+        pk += acc;
+    }
+    Some(pk)
+}
+
+/// Whether `proof` shows `d` is `c1` raised to the discrete log of `pk_j`.
+/// Malformed `d`, `R1`, `R2` or `s` fail the proof; `None` if `c1` itself
+/// is not a point, in which case no share for it can be proven wrong.
+pub fn verify(env: &Env, pk_j: &ProjectivePoint, c1: &Bytes, d: &Bytes, proof: &CpProof) -> Option<bool> {
+    let c1 = decode_point(c1)?;
+    let (Some(d), Some(r1), Some(r2)) =
+        (decode_point(d), decode_point(&proof.r1), decode_point(&proof.r2))
+    else {
+        return Some(false);
+    };
+    let Some(s) = Option::<Scalar>::from(Scalar::from_repr(FieldBytes::from(proof.s.to_array())))
+    else {
+        return Some(false);
+    };
+
+    let mut data = Bytes::new(env);
+    for point in [&ProjectivePoint::GENERATOR, pk_j, &c1, &d, &r1, &r2] {
+        data.extend_from_slice(&encode_point(point)?);
+    }
+    let hash = env.crypto().sha256(&data).to_array();
+    let c = <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(hash));
+
+    Some(ProjectivePoint::GENERATOR * s == r1 + *pk_j * c && c1 * s == r2 + d * c)
+}
+
+fn decode_point(bytes: &Bytes) -> Option<ProjectivePoint> {
+    if bytes.len() != 33 {
+        return None;
+    }
+    let mut buf = [0u8; 33];
+    bytes.copy_into_slice(&mut buf);
+    let encoded = EncodedPoint::from_bytes(buf).ok()?;
+    Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded)).map(ProjectivePoint::from)
+}
+
+/// Compressed encoding; `None` for the identity.
+fn encode_point(point: &ProjectivePoint) -> Option<[u8; 33]> {
+    point.to_affine().to_encoded_point(true).as_bytes().try_into().ok()
+}
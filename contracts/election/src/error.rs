@@ -4,7 +4,8 @@ use soroban_sdk::contracterror;
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum ContractError {
-    /// Election already exists for this eid
+    /// Election already exists for this eid (a single-election instance
+    /// already holds its election)
     AlreadyExists = 1,
     /// Election not found
     NotFound = 2,
@@ -36,4 +37,76 @@ pub enum ContractError {
     NotDistributor = 15,
     /// Arithmetic / encoding error
     EncodingError = 16,
+    /// Election is paused by the organizer
+    ElectionPaused = 17,
+    /// Election was cancelled by the organizer
+    ElectionCancelled = 18,
+    /// Election is not paused
+    NotPaused = 19,
+    /// Requested voting-window change is not allowed
+    InvalidSchedule = 20,
+    /// Election has not been tallied yet
+    NotTallied = 21,
+    /// Ballot Merkle tree is full (2^TREE_DEPTH - 1 leaves)
+    BoardFull = 22,
+    /// Voter registry is frozen once voting opens
+    RegistrationClosed = 23,
+    /// Operation or proof does not match the election's eligibility mode
+    WrongEligibilityMode = 24,
+    /// Voter is on the election's revocation list
+    VoterRevoked = 25,
+    /// Batch is empty or its leaf indices are not strictly increasing
+    InvalidBatch = 26,
+    /// Token holder balance is below the election's minimum
+    InsufficientBalance = 27,
+    /// Token-gated election deployed with a non-positive `min_balance`
+    InvalidTokenGate = 28,
+    /// No upgrade has been proposed
+    NoPendingUpgrade = 29,
+    /// Upgrade timelock has not elapsed yet
+    UpgradeTimelocked = 30,
+    /// An election is in its voting or tallying phase
+    ElectionInProgress = 31,
+    /// Share batch layout version is not `SHARE_BATCH_VERSION`
+    UnsupportedShareBatch = 32,
+    /// Share batch does not cover the board: wrong length, or a share's
+    /// ballot index or C1 differs from the stored ballot
+    ShareBatchMismatch = 33,
+    /// No chunked share upload was begun for this key holder
+    NoShareUpload = 34,
+    /// Chunk does not start where the upload left off
+    ChunkOutOfOrder = 35,
+    /// Upload does not yet cover every ballot on the board
+    ShareUploadIncomplete = 36,
+    /// Stored chunks do not hash to the digest signed in `begin_shares`
+    ShareDigestMismatch = 37,
+    /// `finalize_tally` before the dispute window after the threshold closed
+    DisputeWindowOpen = 38,
+    /// The election's dispute policy is `None`
+    DisputesDisabled = 39,
+    /// The evidence does not show the share to be invalid
+    EvidenceRejected = 40,
+    /// Commitment matrix is not `kh_threshold` wide or does not match the
+    /// dispute policy's `vss_digest`
+    VssMismatch = 41,
+    /// The key holder has no counted share batch
+    SharesNotPosted = 42,
+    /// Stake amount is not positive or the tally deadline is not after
+    /// `max_end_time`
+    InvalidStakePolicy = 43,
+    /// The election's stake policy is `None`
+    StakingDisabled = 44,
+    /// The roster slot already has a stake bonded
+    AlreadyStaked = 45,
+    /// `slash_absent` before the tally deadline
+    TallyDeadlineNotReached = 46,
+    /// `pk_cast` already has a casting account in this election
+    CastingAccountExists = 47,
+    /// Cursor does not continue the pending archive or delete walk
+    InvalidCursor = 48,
+    /// A bonded election has roster slots without a stake (code 49 was
+    /// `AlreadyDeployed` before any release; it is `AlreadyExists` now)
+    StakesIncomplete = 49,
+    /// The stored schema version has no migration path to this code's
+    UnknownSchema = 50,
 }
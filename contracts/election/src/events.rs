@@ -0,0 +1,286 @@
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Vec};
+
+use crate::types::{Committee, ElectionConfig};
+
+// ── Event payloads ────────────────────────────────────────────────────────────
+//
+// Every state transition publishes one of these structs as its event data.
+// Topics are `(symbol, eid)` for per-election events and `(symbol,)` for
+// contract-wide ones; the topic → payload mapping is exported as the `events`
+// contract meta entry so indexers can decode without reading this file.
+//
+// `version` is bumped whenever a payload's fields change.  Fields are only
+// ever appended, so a decoder for version N can read any payload ≥ N by name.
+//
+// Version 2 appended the remaining deploy parameters, the ballot ciphertexts
+// and the tally, so that an election can be rebuilt from its events alone
+// (share blobs excepted; `SharePosted` carries their digest).  Version 3
+// appended the voter leaf to `AccountIssued`, which `issue_msg` now binds.
+
+/// Current payload version carried by every event.
+pub const EVENT_VERSION: u32 = 3;
+
+/// `("deploy", eid)`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Deployed {
+    pub version: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub options_count: u32,
+    pub organizer: Address,
+    pub title: Bytes,
+    pub enc_pubkey: Bytes,
+    pub eligibility_root: BytesN<32>,
+    pub dist: Committee,
+    pub kh: Committee,
+    pub config: ElectionConfig,
+}
+
+/// `("commit", eid)` — a key holder published its constant-term commitment.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct CommitmentSet {
+    pub version: u32,
+    pub kh_idx: u32,
+    pub commitment: Bytes,
+}
+
+/// `("voter_add", eid)` / `("voter_rev", eid)`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegistryUpdated {
+    pub version: u32,
+    pub voter: Bytes,
+    pub registry_version: u32,
+    pub root: BytesN<32>,
+}
+
+/// `("revoked", eid)` — a `root` equal to the empty sparse-tree root (E_256
+/// in `smt.rs`) means the list was cleared.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RevocationSet {
+    pub version: u32,
+    pub root: BytesN<32>,
+}
+
+/// `("issued", eid)`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountIssued {
+    pub version: u32,
+    pub nf_issue: BytesN<32>,
+    pub pk_cast: BytesN<32>,
+    pub weight: u32,
+    /// `voter_leaf` signed in `issue_msg` (zero without an eligibility proof)
+    pub voter: BytesN<32>,
+}
+
+/// `("batch", eid)` — follows the batch's individual `issued` events.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchIssued {
+    pub version: u32,
+    pub digest: BytesN<32>,
+    pub count: u32,
+}
+
+/// `("cast", eid)` / `("recast", eid)` — `msg_hash` is the voter's receipt.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BallotCast {
+    pub version: u32,
+    pub nf_cast: BytesN<32>,
+    pub index: u32,
+    pub msg_hash: BytesN<32>,
+    pub board_head: BytesN<32>,
+    pub pk_cast: BytesN<32>,
+    pub c1: Bytes,
+    pub c2: Bytes,
+    pub weight: u32,
+}
+
+/// `("share", eid)` — one key holder's decryption shares were accepted.
+/// `shares_hash` is the digest the key holder signed: `shares_msg` for
+/// `post_share`, the upload digest for `commit_shares`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SharePosted {
+    pub version: u32,
+    pub kh_idx: u32,
+    pub shares_hash: BytesN<32>,
+    pub share_count: u32,
+}
+
+/// `("upload", eid)` — a key holder began (or restarted) a chunked upload.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShareUploadBegun {
+    pub version: u32,
+    pub kh_idx: u32,
+    pub digest: BytesN<32>,
+}
+
+/// `("chunk", eid)` — shares `start .. start + count` of an upload stored.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShareChunkPosted {
+    pub version: u32,
+    pub kh_idx: u32,
+    pub start: u32,
+    pub count: u32,
+    pub chunk_hash: BytesN<32>,
+}
+
+/// `("struck", eid)` — a key holder's batch was proven invalid at
+/// `ballot_index` by `dispute_share` and no longer counts.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SharesStruck {
+    pub version: u32,
+    pub kh_idx: u32,
+    pub shares_hash: BytesN<32>,
+    pub ballot_index: u32,
+    pub share_count: u32,
+}
+
+/// `("withdrawn", eid)` — a key holder withdrew its batch.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SharesWithdrawn {
+    pub version: u32,
+    pub kh_idx: u32,
+    pub shares_hash: BytesN<32>,
+    pub share_count: u32,
+}
+
+/// `("staked", eid)` — `staker` bonded roster slot `kh_idx`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakeDeposited {
+    pub version: u32,
+    pub kh_idx: u32,
+    pub staker: Address,
+    pub amount: i128,
+}
+
+/// `("slashed", eid)` — the slot had no counted shares at the tally
+/// deadline; its stake went to `recipient`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakeSlashed {
+    pub version: u32,
+    pub kh_idx: u32,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+/// `("returned", eid)` — the slot's stake went back to its staker.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StakeReturned {
+    pub version: u32,
+    pub kh_idx: u32,
+    pub staker: Address,
+    pub amount: i128,
+}
+
+/// `("released", eid)` — a token holder's escrowed balance went back to them.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TokensReleased {
+    pub version: u32,
+    pub holder: Address,
+    pub amount: i128,
+}
+
+/// `("threshold", eid)` — emitted with each share at or above the threshold.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThresholdReached {
+    pub version: u32,
+    pub share_count: u32,
+    pub threshold: u32,
+}
+
+/// `("tallied", eid)`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tallied {
+    pub version: u32,
+    pub share_count: u32,
+    pub board_head: BytesN<32>,
+    pub ballot_root: BytesN<32>,
+    pub tally: Vec<u32>,
+    pub tallied_at: u64,
+}
+
+/// `("archived", eid)`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Archived {
+    pub version: u32,
+    pub board_hash: BytesN<32>,
+    pub ballot_count: u32,
+}
+
+/// `("paused", eid)` / `("resumed", eid)`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatusChanged {
+    pub version: u32,
+    pub changed_at: u64,
+}
+
+/// `("cancelled", eid)`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cancelled {
+    pub version: u32,
+    pub cancelled_at: u64,
+    pub reason: Bytes,
+}
+
+/// `("extended", eid)`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VotingExtended {
+    pub version: u32,
+    pub old_end_time: u64,
+    pub new_end_time: u64,
+}
+
+/// `("bumped", eid)`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TtlBumped {
+    pub version: u32,
+    pub ttl: u32,
+}
+
+/// `("deleted", eid)`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Deleted {
+    pub version: u32,
+}
+
+/// `("upg_prop",)` / `("upg_cancl",)` / `("upgraded",)` — `executable_at` is
+/// zero on cancel and execute.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UpgradeEvent {
+    pub version: u32,
+    pub wasm_hash: BytesN<32>,
+    pub executable_at: u64,
+}
+
+/// `("migrated",)`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Migrated {
+    pub version: u32,
+    pub from: u32,
+    pub to: u32,
+}
@@ -1,39 +1,104 @@
 #![no_std]
 
-mod error;
-mod merkle;
-mod types;
+pub mod cp;
+pub mod error;
+pub mod events;
+pub mod merkle;
+mod smt;
+pub mod types;
 
 #[cfg(test)]
 mod test;
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
 
 use soroban_sdk::{
-    contract, contractimpl, contractmeta, symbol_short,
-    Bytes, BytesN, Env, Vec,
+    contract, contractimpl, contractmeta, symbol_short, token, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, TryFromVal, Val, Vec,
 };
 
 use error::ContractError;
-use types::{DataKey, ElectionParams, EncryptedBallot};
+use events::EVENT_VERSION;
+use types::{
+    AccountPage, AccountStatus, ArchivedElection, Cancellation, CastingAccount, Committee, DataKey,
+    ElectionConfig, ElectionPage, ElectionParams, ElectionPhase, ElectionSummary, ElectionStatus, EligibilityMode, EligibilityProof, TokenEscrow, TokenGate,
+    EncryptedBallot, IssueItem, MerkleFrontier, MerkleMultiProof, NullifierPage, PendingUpgrade,
+    VersionedBallot, VersionedElection, RegistryRoot, ArchivedElectionV1, ArchivedElectionV2,
+    ElectionParamsV1, EncryptedBallotV1, KhBond, StakePolicy,
+    DisputePolicy, ShareEvidence, CpProof, DecryptionShare, ScheduleChange, ShareBatch, ShareProof,
+    ShareUpload, SmtProof, TallyRecord, UpgradeCheck, WalkPage, SHARE_BATCH_VERSION,
+};
 
 contractmeta!(
     key = "Description",
     val = "Stellot dagger - threshold ElGamal e-voting on Soroban (PoC)"
 );
 
+// Event schema: `topic:Payload` pairs; payload types are in the contract spec.
+contractmeta!(key = "event_version", val = "3");
+contractmeta!(
+    key = "events",
+    val = "deploy:Deployed;commit:CommitmentSet;voter_add:RegistryUpdated;\
+           voter_rev:RegistryUpdated;revoked:RevocationSet;issued:AccountIssued;\
+           staked:StakeDeposited;batch:BatchIssued;cast:BallotCast;recast:BallotCast;\
+           upload:ShareUploadBegun;chunk:ShareChunkPosted;share:SharePosted;\
+           struck:SharesStruck;withdrawn:SharesWithdrawn;\
+           threshold:ThresholdReached;tallied:Tallied;slashed:StakeSlashed;\
+           returned:StakeReturned;released:TokensReleased;archived:Archived;\
+           paused:StatusChanged;resumed:StatusChanged;cancelled:Cancelled;\
+           extended:VotingExtended;bumped:TtlBumped;deleted:Deleted;\
+           upg_prop:UpgradeEvent;upg_cancl:UpgradeEvent;upgraded:UpgradeEvent;\
+           migrated:Migrated"
+);
+
 // ── Message construction helpers ──────────────────────────────────────────────
 
-/// msg = SHA256("stellot:issue" || eid_le64 || pk_cast_32 || nf_issue_32)
-fn issue_msg(env: &Env, eid: u64, pk_cast: &BytesN<32>, nf_issue: &BytesN<32>) -> BytesN<32> {
+/// msg = SHA256("stellot:issue" || eid_le64 || pk_cast_32 || nf_issue_32 || voter_32)
+///
+/// `voter` is the `voter_leaf` of the identity the issuance proves eligible,
+/// so the distributors' approval cannot be replayed for another voter.
+pub fn issue_msg(
+    env: &Env,
+    eid: u64,
+    pk_cast: &BytesN<32>,
+    nf_issue: &BytesN<32>,
+    voter: &BytesN<32>,
+) -> BytesN<32> {
     let mut data = Bytes::new(env);
     data.extend_from_slice(b"stellot:issue");
     data.extend_from_slice(&eid.to_le_bytes());
     data.append(&pk_cast.into());
     data.append(&nf_issue.into());
+    data.append(&voter.into());
+    env.crypto().sha256(&data).into()
+}
+
+/// `voter_32` of `issue_msg`: `merkle::leaf_hash` of the eligibility proof's
+/// voter (static-tree leaf, registry key or token holder's XDR address), or
+/// 32 zero bytes for an issuance without one.
+pub fn voter_leaf(env: &Env, voter: &Option<Bytes>) -> BytesN<32> {
+    match voter {
+        Some(voter) => merkle::leaf_hash(env, voter),
+        None => BytesN::from_array(env, &[0u8; 32]),
+    }
+}
+
+/// msg = SHA256("stellot:batch" || eid_le64 || count_le32 || issue_msg_1 || …),
+/// each `issue_msg` bound to its item's `leaf`.
+pub fn batch_issue_msg(env: &Env, eid: u64, items: &Vec<IssueItem>) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.extend_from_slice(b"stellot:batch");
+    data.extend_from_slice(&eid.to_le_bytes());
+    data.extend_from_slice(&items.len().to_le_bytes());
+    for item in items.iter() {
+        let voter = voter_leaf(env, &Some(item.leaf));
+        data.append(&issue_msg(env, eid, &item.pk_cast, &item.nf_issue, &voter).into());
+    }
     env.crypto().sha256(&data).into()
 }
 
 /// msg = SHA256("stellot:cast" || eid_le64 || nf_cast_32 || c1 || c2)
-fn cast_msg(env: &Env, eid: u64, nf_cast: &BytesN<32>, c1: &Bytes, c2: &Bytes) -> BytesN<32> {
+pub fn cast_msg(env: &Env, eid: u64, nf_cast: &BytesN<32>, c1: &Bytes, c2: &Bytes) -> BytesN<32> {
     let mut data = Bytes::new(env);
     data.extend_from_slice(b"stellot:cast");
     data.extend_from_slice(&eid.to_le_bytes());
@@ -44,7 +109,7 @@ fn cast_msg(env: &Env, eid: u64, nf_cast: &BytesN<32>, c1: &Bytes, c2: &Bytes) -
 }
 
 /// msg = SHA256("stellot:shares" || eid_le64 || shares_blob)
-fn shares_msg(env: &Env, eid: u64, shares_blob: &Bytes) -> BytesN<32> {
+pub fn shares_msg(env: &Env, eid: u64, shares_blob: &Bytes) -> BytesN<32> {
     let mut data = Bytes::new(env);
     data.extend_from_slice(b"stellot:shares");
     data.extend_from_slice(&eid.to_le_bytes());
@@ -52,27 +117,924 @@ fn shares_msg(env: &Env, eid: u64, shares_blob: &Bytes) -> BytesN<32> {
     env.crypto().sha256(&data).into()
 }
 
-/// Serialise a shares batch to a flat byte blob for signing / storage.
-/// Format: [pair_count u32_le] then pairs of [c1_len u32_le][c1][d_len u32_le][d]
-pub fn serialise_shares(env: &Env, shares: &Vec<(Bytes, Bytes)>) -> Bytes {
+/// msg = SHA256("stellot:stake" || eid_le64 || kh_idx_le32 || staker_xdr)
+///
+/// Signed by a key holder to accept `staker`'s bond on their roster slot.
+pub fn stake_msg(env: &Env, eid: u64, kh_idx: u32, staker: &Address) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.extend_from_slice(b"stellot:stake");
+    data.extend_from_slice(&eid.to_le_bytes());
+    data.extend_from_slice(&kh_idx.to_le_bytes());
+    data.append(&staker.clone().to_xdr(env));
+    env.crypto().sha256(&data).into()
+}
+
+/// msg = SHA256("stellot:withdraw" || eid_le64 || nonce_le32 || shares_hash_32)
+///
+/// Signed by a key holder to withdraw their counted shares, where
+/// `shares_hash` is the `shares_msg` digest of a `post_share` batch or the
+/// digest of a committed upload.  `nonce` is the slot's withdrawal count, so
+/// a signature cannot withdraw the same batch again once it is re-posted.
+pub fn withdraw_msg(env: &Env, eid: u64, nonce: u32, shares_hash: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.extend_from_slice(b"stellot:withdraw");
+    data.extend_from_slice(&eid.to_le_bytes());
+    data.extend_from_slice(&nonce.to_le_bytes());
+    data.append(&shares_hash.into());
+    env.crypto().sha256(&data).into()
+}
+
+/// msg = SHA256("stellot:begin" || eid_le64 || digest_32)
+///
+/// Signed by a key holder to open a chunked upload whose chunks chain to
+/// `digest`.
+pub fn shares_begin_msg(env: &Env, eid: u64, digest: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.extend_from_slice(b"stellot:begin");
+    data.extend_from_slice(&eid.to_le_bytes());
+    data.append(&digest.into());
+    env.crypto().sha256(&data).into()
+}
+
+/// hash = SHA256("stellot:chunk" || eid_le64 || start_le32 || chunk_blob)
+pub fn shares_chunk_hash(env: &Env, eid: u64, start: u32, chunk_blob: &Bytes) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.extend_from_slice(b"stellot:chunk");
+    data.extend_from_slice(&eid.to_le_bytes());
+    data.extend_from_slice(&start.to_le_bytes());
+    data.append(chunk_blob);
+    env.crypto().sha256(&data).into()
+}
+
+/// msg = SHA256("stellot:chunksig" || eid_le64 || digest_32 || chunk_hash_32)
+///
+/// Signed by a key holder for each chunk.  Binding the upload's declared
+/// `digest` keeps a chunk signed for one upload out of any other.
+pub fn shares_chunk_msg(env: &Env, eid: u64, digest: &BytesN<32>, chunk_hash: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.extend_from_slice(b"stellot:chunksig");
+    data.extend_from_slice(&eid.to_le_bytes());
+    data.append(&digest.into());
+    data.append(&chunk_hash.into());
+    env.crypto().sha256(&data).into()
+}
+
+/// chain' = SHA256("stellot:chunks" || chain_32 || chunk_hash_32)
+///
+/// Hash chain over an upload's chunks in order, from 32 zero bytes.  Its
+/// final value is the digest signed in `begin_shares`.
+pub fn shares_chain_step(env: &Env, chain: &BytesN<32>, chunk_digest: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.extend_from_slice(b"stellot:chunks");
+    data.append(&chain.into());
+    data.append(&chunk_digest.into());
+    env.crypto().sha256(&data).into()
+}
+
+/// head' = SHA256("stellot:board" || head_32 || ballot_digest_32)
+///
+/// One step of the append-only ballot-board hash chain, starting from 32 zero
+/// bytes.  `ballot_digest` is the `cast_msg` the voter signed, so a voter can
+/// recognise their own ballot from the receipt alone.
+pub fn board_step(env: &Env, head: &BytesN<32>, ballot_digest: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.extend_from_slice(b"stellot:board");
+    data.append(&head.into());
+    data.append(&ballot_digest.into());
+    env.crypto().sha256(&data).into()
+}
+
+/// Leaf of the ballot Merkle tree: `leaf_hash(cast_msg(ballot))`.
+pub fn ballot_leaf(env: &Env, ballot_digest: &BytesN<32>) -> BytesN<32> {
+    merkle::leaf_hash(env, &ballot_digest.into())
+}
+
+fn ballot_tree(env: &Env, eid: u64) -> MerkleFrontier {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BallotTree(eid))
+        .unwrap_or(merkle::empty_frontier(env))
+}
+
+fn board_head(env: &Env, eid: u64) -> BytesN<32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BoardHead(eid))
+        .unwrap_or(BytesN::from_array(env, &[0u8; 32]))
+}
+
+/// Serialise a share batch to the blob a key holder signs and the contract
+/// stores.  Format: [version u8][share_count u32_le] then per share
+/// [ballot_index u32_le][c1 33][d 33][proof_kind u8], followed by
+/// [r1 33][r2 33][s 32] when proof_kind is 1 (`ShareProof::Cp`).
+pub fn serialise_share_batch(env: &Env, batch: &ShareBatch) -> Bytes {
     let mut out = Bytes::new(env);
-    let len = shares.len();
-    out.extend_from_slice(&len.to_le_bytes());
-    for i in 0..len {
-        let (c1, d) = shares.get(i).unwrap();
-        out.extend_from_slice(&c1.len().to_le_bytes());
-        out.append(&c1);
-        out.extend_from_slice(&d.len().to_le_bytes());
-        out.append(&d);
+    out.push_back(batch.version as u8);
+    out.extend_from_slice(&batch.shares.len().to_le_bytes());
+    for share in batch.shares.iter() {
+        out.extend_from_slice(&share.ballot_index.to_le_bytes());
+        out.append(&share.c1);
+        out.append(&share.d);
+        match share.proof {
+            ShareProof::None => out.push_back(0),
+            ShareProof::Cp(proof) => {
+                out.push_back(1);
+                out.append(&proof.r1);
+                out.append(&proof.r2);
+                out.append(&proof.s.into());
+            }
+        }
     }
     out
 }
 
+// ── Versioned records ─────────────────────────────────────────────────────────
+//
+// `Election` and `Ballot` entries are stored as `Versioned*` enums.  Reads
+// accept every variant plus the bare schema-1 struct and convert to the
+// current type, so old entries stay readable after an upgrade without a
+// migration pass.
+
 fn load_election(env: &Env, eid: u64) -> Result<ElectionParams, ContractError> {
-    env.storage()
+    let raw: Val = env
+        .storage()
         .persistent()
         .get(&DataKey::Election(eid))
-        .ok_or(ContractError::NotFound)
+        .ok_or(ContractError::NotFound)?;
+    match VersionedElection::try_from_val(env, &raw) {
+        Ok(VersionedElection::V3(params)) => Ok(params),
+        Ok(VersionedElection::V2(params)) => Ok(params.into()),
+        Ok(VersionedElection::V1(params)) => Ok(params.into()),
+        Err(_) => ElectionParamsV1::try_from_val(env, &raw)
+            .map(|params| upgrade_params_v1(env, params))
+            .map_err(|_| ContractError::EncodingError),
+    }
+}
+
+/// Fill in what a schema-1 election never stored: it is run by the contract
+/// admin, active, and uses the defaults `deploy` would pick.  Without an
+/// `Admin` entry yet the organizer is the contract itself, which cannot sign.
+fn upgrade_params_v1(env: &Env, v1: ElectionParamsV1) -> ElectionParams {
+    let organizer = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| env.current_contract_address());
+    ElectionParams {
+        eid: v1.eid,
+        organizer,
+        title: v1.title,
+        options_count: v1.options_count,
+        start_time: v1.start_time,
+        end_time: v1.end_time,
+        enc_pubkey: v1.enc_pubkey,
+        tallied: v1.tallied,
+        status: ElectionStatus::Active,
+        config: ElectionConfig {
+            revoting: false,
+            max_end_time: v1.end_time,
+            eligibility: EligibilityMode::Static,
+            disputes: DisputePolicy::None,
+            staking: StakePolicy::None,
+        },
+    }
+}
+
+fn save_election(env: &Env, params: &ElectionParams) {
+    store(env, params, &DataKey::Election(params.eid), &VersionedElection::V3(params.clone()));
+}
+
+fn load_archive(env: &Env, eid: u64) -> Option<ArchivedElection> {
+    let raw: Val = env.storage().persistent().get(&DataKey::Archive(eid))?;
+    if let Ok(record) = ArchivedElection::try_from_val(env, &raw) {
+        return Some(record);
+    }
+    if let Ok(record) = ArchivedElectionV2::try_from_val(env, &raw) {
+        return Some(record.into());
+    }
+    ArchivedElectionV1::try_from_val(env, &raw).ok().map(Into::into)
+}
+
+fn load_ballot(env: &Env, eid: u64, index: u32) -> Option<EncryptedBallot> {
+    let raw: Val = env.storage().persistent().get(&DataKey::Ballot(eid, index))?;
+    if let Ok(VersionedBallot::V1(ballot)) = VersionedBallot::try_from_val(env, &raw) {
+        return Some(ballot);
+    }
+    let v1 = EncryptedBallotV1::try_from_val(env, &raw).ok()?;
+    Some(EncryptedBallot { nf_cast: v1.nf_cast, c1: v1.c1, c2: v1.c2, weight: 1 })
+}
+
+fn save_ballot(env: &Env, params: &ElectionParams, index: u32, ballot: &EncryptedBallot) {
+    store(
+        env,
+        params,
+        &DataKey::Ballot(params.eid, index),
+        &VersionedBallot::V1(ballot.clone()),
+    );
+}
+
+fn election_phase(env: &Env, params: &ElectionParams) -> ElectionPhase {
+    let now = env.ledger().timestamp();
+    if params.status == ElectionStatus::Cancelled {
+        ElectionPhase::Cancelled
+    } else if params.tallied {
+        ElectionPhase::Tallied
+    } else if now < params.start_time {
+        ElectionPhase::Registration
+    } else if now < params.end_time {
+        ElectionPhase::Voting
+    } else {
+        ElectionPhase::Tallying
+    }
+}
+
+/// Reject state-changing calls on a paused or cancelled election.
+fn ensure_active(params: &ElectionParams) -> Result<(), ContractError> {
+    match params.status {
+        ElectionStatus::Active => Ok(()),
+        ElectionStatus::Paused => Err(ContractError::ElectionPaused),
+        ElectionStatus::Cancelled => Err(ContractError::ElectionCancelled),
+    }
+}
+
+/// Under `StakePolicy::Bond`, refuse ballots until every roster slot is
+/// bonded.  An election left short at `start_time` can only be cancelled.
+fn ensure_bonded(env: &Env, eid: u64, params: &ElectionParams) -> Result<(), ContractError> {
+    if !matches!(params.config.staking, StakePolicy::Bond(_)) {
+        return Ok(());
+    }
+    let storage = env.storage().persistent();
+    let kh_roster: Vec<BytesN<32>> = storage.get(&DataKey::KhRoster(eid)).unwrap();
+    if (0..kh_roster.len()).all(|i| storage.has(&DataKey::KhStake(eid, i))) {
+        Ok(())
+    } else {
+        Err(ContractError::StakesIncomplete)
+    }
+}
+
+// ── Storage TTL ───────────────────────────────────────────────────────────────
+
+/// Approximate ledger close time, used to turn schedule timestamps into TTLs.
+const LEDGER_SECS: u64 = 5;
+
+/// How long election data is kept alive past the latest possible deadline
+/// (or past the last bump), leaving room for tallying and audit.
+const RETENTION_SECS: u64 = 90 * 24 * 60 * 60;
+
+/// TTL (in ledgers) that keeps an entry alive until `RETENTION_SECS` after the
+/// later of now, the latest deadline `extend_voting` could reach and any
+/// stake's tally deadline, capped at the network maximum.
+fn election_ttl(env: &Env, params: &ElectionParams) -> u32 {
+    let tally_deadline = match &params.config.staking {
+        StakePolicy::Bond(bond) => bond.tally_deadline,
+        StakePolicy::None => 0,
+    };
+    let deadline = params.end_time
+        .max(params.config.max_end_time)
+        .max(tally_deadline)
+        .max(env.ledger().timestamp())
+        + RETENTION_SECS;
+    let ledgers = (deadline - env.ledger().timestamp()) / LEDGER_SECS;
+    ledgers.min(env.storage().max_ttl() as u64) as u32
+}
+
+/// Write a persistent election entry and extend its TTL to `election_ttl`.
+fn store<V: IntoVal<Env, Val>>(env: &Env, params: &ElectionParams, key: &DataKey, val: &V) {
+    env.storage().persistent().set(key, val);
+    let ttl = election_ttl(env, params);
+    env.storage().persistent().extend_ttl(key, ttl, ttl);
+}
+
+/// Extend the contract instance (code + `NextElectionId`) by `RETENTION_SECS`.
+fn bump_instance(env: &Env) {
+    let ttl = (RETENTION_SECS / LEDGER_SECS).min(env.storage().max_ttl() as u64) as u32;
+    env.storage().instance().extend_ttl(ttl, ttl);
+}
+
+// ── Pagination ────────────────────────────────────────────────────────────────
+
+/// Upper bound on entries returned by one paginated view.
+const MAX_PAGE: u32 = 100;
+
+/// Indices `start .. start + limit` clamped to `MAX_PAGE` and to `len`.
+fn page_range(start: u32, limit: u32, len: u32) -> core::ops::Range<u32> {
+    let end = start.saturating_add(limit.min(MAX_PAGE)).min(len);
+    start.min(end)..end
+}
+
+/// Upper bound on walk positions visited by one `walk_entries` page.  A
+/// position touches at most four entries, which keeps a page well inside the
+/// per-transaction ledger-entry limits.
+const MAX_WALK: u32 = 20;
+
+/// Part of `cursor .. end` that falls in a group of `len` positions starting
+/// at walk position `base`, as indices into the group.
+fn walk_window(base: u32, len: u32, cursor: u32, end: u32) -> core::ops::Range<u32> {
+    cursor.saturating_sub(base).min(len)..end.saturating_sub(base).min(len)
+}
+
+/// Visit the entries of `eid` whose number grows with the election, calling
+/// `f` on each key at walk positions `cursor .. cursor + limit` (`limit`
+/// clamped to `MAX_WALK`).  Returns the cursor of the next page, or `None`
+/// once the walk is complete.
+///
+/// The walk runs over issued accounts, ballots, cast nullifiers, ballot-tree
+/// leaves, registry roots and share chunks, in that order; each group is
+/// append-only, so entries added between pages shift later positions forward
+/// and are never skipped.  The counters the walk is sized from are not passed
+/// to `f`.
+fn walk_entries(env: &Env, eid: u64, cursor: u32, limit: u32, f: &mut impl FnMut(DataKey)) -> Option<u32> {
+    let storage = env.storage().persistent();
+    let end = cursor.saturating_add(limit.clamp(1, MAX_WALK));
+    let mut base = 0u32;
+
+    let issued_count: u32 = storage.get(&DataKey::IssuedCount(eid)).unwrap_or(0);
+    for i in walk_window(base, issued_count, cursor, end) {
+        let Some(pk_cast) = storage.get::<_, BytesN<32>>(&DataKey::Issued(eid, i)) else { continue };
+        let account_key = DataKey::CastingAccount(eid, pk_cast);
+        if let Some(account) = storage.get::<_, CastingAccount>(&account_key) {
+            f(DataKey::IssueNullifier(eid, account.nf_issue));
+            if let Some(holder) = account.holder {
+                f(DataKey::TokenHolder(eid, holder.clone()));
+                f(DataKey::TokenEscrow(eid, holder));
+            }
+        }
+        f(account_key);
+        f(DataKey::Issued(eid, i));
+    }
+    base += issued_count;
+
+    let ballot_count: u32 = storage.get(&DataKey::BallotCount(eid)).unwrap_or(0);
+    for i in walk_window(base, ballot_count, cursor, end) {
+        if let Some(ballot) = load_ballot(env, eid, i) {
+            f(DataKey::CastNullifier(eid, ballot.nf_cast));
+        }
+        f(DataKey::Ballot(eid, i));
+    }
+    base += ballot_count;
+
+    // Also reaches the nullifiers of ballots replaced by a revote
+    let cast_count: u32 = storage.get(&DataKey::CastCount(eid)).unwrap_or(0);
+    for i in walk_window(base, cast_count, cursor, end) {
+        if let Some(nf_cast) = storage.get::<_, BytesN<32>>(&DataKey::Cast(eid, i)) {
+            f(DataKey::CastNullifier(eid, nf_cast));
+        }
+        f(DataKey::Cast(eid, i));
+    }
+    base += cast_count;
+
+    let leaves = ballot_tree(env, eid).size;
+    for i in walk_window(base, leaves, cursor, end) {
+        f(DataKey::BallotLeaf(eid, i));
+    }
+    base += leaves;
+
+    let registry_version: u32 = storage.get(&DataKey::RegistryVersion(eid)).unwrap_or(0);
+    for v in walk_window(base, registry_version + 1, cursor, end) {
+        f(DataKey::RegistryRoot(eid, v));
+    }
+    base += registry_version + 1;
+
+    let kh_count = storage
+        .get::<_, Vec<BytesN<32>>>(&DataKey::KhRoster(eid))
+        .map_or(0, |roster| roster.len());
+    for kh_idx in 0..kh_count {
+        let chunks = load_upload(env, eid, kh_idx).map_or(0, |upload| upload.chunks);
+        for c in walk_window(base, chunks, cursor, end) {
+            f(DataKey::ShareChunk(eid, kh_idx, c));
+        }
+        base += chunks;
+    }
+
+    (end < base).then_some(end)
+}
+
+/// Free one page of the walk an `archive_election` / `delete_election` left
+/// under `DataKey::Purge(eid)`, and the counters the walk is sized from once
+/// it is complete.  `cursor` must be where the previous page stopped.
+fn purge_page(env: &Env, eid: u64, cursor: u32, limit: u32) -> Result<WalkPage, ContractError> {
+    let storage = env.storage().persistent();
+    let expected: u32 = storage.get(&DataKey::Purge(eid)).ok_or(ContractError::NotFound)?;
+    if cursor != expected {
+        return Err(ContractError::InvalidCursor);
+    }
+
+    // Escrowed tokens still held go back to their holders rather than being
+    // dropped with the entry
+    let next = walk_entries(env, eid, cursor, limit, &mut |key| match &key {
+        DataKey::TokenEscrow(_, holder) => {
+            release_escrow(env, eid, holder);
+        }
+        _ => storage.remove(&key),
+    });
+    if let Some(next) = next {
+        storage.set(&DataKey::Purge(eid), &next);
+        return Ok(WalkPage { next: Some(next) });
+    }
+
+    let kh_roster: Vec<BytesN<32>> = storage.get(&DataKey::KhRoster(eid)).unwrap();
+    for i in 0..kh_roster.len() {
+        storage.remove(&DataKey::ShareUpload(eid, i));
+        storage.remove(&DataKey::WithdrawNonce(eid, i));
+    }
+    storage.remove(&DataKey::KhRoster(eid));
+    storage.remove(&DataKey::IssuedCount(eid));
+    storage.remove(&DataKey::BallotCount(eid));
+    storage.remove(&DataKey::CastCount(eid));
+    storage.remove(&DataKey::BallotTree(eid));
+    storage.remove(&DataKey::RegistryVersion(eid));
+    storage.remove(&DataKey::Purge(eid));
+    Ok(WalkPage { next: None })
+}
+
+// ── Upgrades ──────────────────────────────────────────────────────────────────
+
+/// Minimum delay between `propose_upgrade` and `execute_upgrade`.
+const UPGRADE_DELAY_SECS: u64 = 2 * 24 * 60 * 60;
+
+/// Storage schema written by this code.  Bump it, and add a step to
+/// `migrate_step`, whenever a stored type or key layout changes.
+const SCHEMA_VERSION: u32 = 4;
+
+/// Require the admin's authorization.  `NotFound` until a deployment from
+/// before admins existed has run `migrate`.
+fn require_admin(env: &Env) -> Result<Address, ContractError> {
+    let admin: Address = env
+        .storage().instance().get(&DataKey::Admin)
+        .ok_or(ContractError::NotFound)?;
+    admin.require_auth();
+    Ok(admin)
+}
+
+/// Check that stored data at schema `from` can be brought to `from + 1`.
+///
+/// Every step is lazy: read paths accept each older layout (see
+/// `load_election` / `load_archive`) and `save_election` writes the current
+/// one, so records are rewritten as elections are next touched rather than
+/// in one walk over every eid.  Only contract-wide instance entries are
+/// written eagerly.
+fn migrate_step(env: &Env, from: u32, admin: &Address) -> Result<(), ContractError> {
+    match from {
+        // 1 → 2: election records wrapped in `VersionedElection`; ballots
+        // stay readable as bare structs.  Schema 1 had no admin and kept the
+        // eid counter in persistent storage: record `admin` and move the
+        // counter, so the next `deploy` does not reuse eid 0.
+        1 => {
+            let instance = env.storage().instance();
+            if !instance.has(&DataKey::Admin) {
+                instance.set(&DataKey::Admin, admin);
+            }
+            let persistent = env.storage().persistent();
+            if let Some(next_eid) = persistent.get::<_, u64>(&DataKey::NextElectionId) {
+                persistent.remove(&DataKey::NextElectionId);
+                instance.set(&DataKey::NextElectionId, &next_eid);
+            }
+            Ok(())
+        }
+        // 2 → 3 and 3 → 4: `ElectionConfig` gained `disputes`, then
+        // `staking`; older election and archive records read with neither.
+        2..=3 => Ok(()),
+        _ => Err(ContractError::UnknownSchema),
+    }
+}
+
+// ── Issuance ──────────────────────────────────────────────────────────────────
+
+/// Require at least `dist_threshold` valid roster signatures over `msg`.
+fn check_dist_sigs(
+    env: &Env,
+    eid: u64,
+    msg: &BytesN<32>,
+    dist_sigs: &Vec<(BytesN<32>, BytesN<64>)>,
+) -> Result<(), ContractError> {
+    let dist_roster: Vec<BytesN<32>> = env
+        .storage().persistent().get(&DataKey::DistRoster(eid)).unwrap();
+    let dist_threshold: u32 = env
+        .storage().persistent().get(&DataKey::DistThreshold(eid)).unwrap();
+
+    let msg_bytes: Bytes = msg.into();
+    let mut valid_count: u32 = 0;
+    for i in 0..dist_sigs.len() {
+        let (signer_pk, sig) = dist_sigs.get(i).unwrap();
+        if !dist_roster.contains(&signer_pk) {
+            continue;
+        }
+        env.crypto().ed25519_verify(&signer_pk, &msg_bytes, &sig);
+        valid_count += 1;
+    }
+
+    if valid_count < dist_threshold {
+        return Err(ContractError::InvalidDistributorSig);
+    }
+    Ok(())
+}
+
+/// Token mode: `holder` authorizes, has not been issued an account yet and
+/// holds at least `min_balance`.  Returns the ballot weight.
+///
+/// SEP-41 only exposes current balances, so the balance a weight is drawn
+/// from is escrowed by `record_issuance` until the election ends; otherwise
+/// it could be moved to another address and counted again.
+fn check_token_holder(
+    env: &Env,
+    eid: u64,
+    gate: &TokenGate,
+    holder: &Address,
+) -> Result<u32, ContractError> {
+    holder.require_auth();
+    if env.storage().persistent().has(&DataKey::TokenHolder(eid, holder.clone())) {
+        return Err(ContractError::AlreadyIssued);
+    }
+
+    let balance = token::TokenClient::new(env, &gate.token).balance(holder);
+    if balance < gate.min_balance {
+        return Err(ContractError::InsufficientBalance);
+    }
+    if !gate.weighted {
+        return Ok(1);
+    }
+    Ok((balance / gate.min_balance).min(u32::MAX as i128) as u32)
+}
+
+/// Move the balance a token-mode issuance counted (`min_balance` per unit of
+/// `weight`) from `holder` into the contract.
+fn escrow_tokens(env: &Env, params: &ElectionParams, gate: &TokenGate, holder: &Address, weight: u32) {
+    let amount = gate.min_balance * weight as i128;
+    token::TokenClient::new(env, &gate.token).transfer(holder, &env.current_contract_address(), &amount);
+    store(
+        env,
+        params,
+        &DataKey::TokenEscrow(params.eid, holder.clone()),
+        &TokenEscrow { token: gate.token.clone(), amount },
+    );
+}
+
+/// Return `holder`'s escrowed tokens, if any.  Returns the amount released.
+fn release_escrow(env: &Env, eid: u64, holder: &Address) -> i128 {
+    let storage = env.storage().persistent();
+    let key = DataKey::TokenEscrow(eid, holder.clone());
+    let Some(escrow) = storage.get::<_, TokenEscrow>(&key) else {
+        return 0;
+    };
+    storage.remove(&key);
+    token::TokenClient::new(env, &escrow.token).transfer(&env.current_contract_address(), holder, &escrow.amount);
+    env.events().publish(
+        (symbol_short!("released"), eid),
+        events::TokensReleased { version: EVENT_VERSION, holder: holder.clone(), amount: escrow.amount },
+    );
+    escrow.amount
+}
+
+/// Store a new casting account, its issue nullifier and index entry.
+fn record_issuance(
+    env: &Env,
+    params: &ElectionParams,
+    eid: u64,
+    pk_cast: BytesN<32>,
+    nf_issue: BytesN<32>,
+    voter: BytesN<32>,
+    holder: Option<Address>,
+    weight: u32,
+) -> Result<(), ContractError> {
+    // Re-issuing would reset status and ballot_index, reopening the account.
+    if env.storage().persistent().has(&DataKey::CastingAccount(eid, pk_cast.clone())) {
+        return Err(ContractError::CastingAccountExists);
+    }
+    store(env, params, &DataKey::IssueNullifier(eid, nf_issue.clone()), &true);
+    if let Some(holder) = &holder {
+        store(env, params, &DataKey::TokenHolder(eid, holder.clone()), &true);
+        if let EligibilityMode::Token(gate) = &params.config.eligibility {
+            escrow_tokens(env, params, gate, holder, weight);
+        }
+    }
+    store(
+        env,
+        params,
+        &DataKey::CastingAccount(eid, pk_cast.clone()),
+        &CastingAccount {
+            status: AccountStatus::Issued,
+            issued_at: env.ledger().timestamp(),
+            nf_issue: nf_issue.clone(),
+            ballot_index: None,
+            holder,
+            weight,
+        },
+    );
+
+    let issued_count: u32 = env
+        .storage().persistent().get(&DataKey::IssuedCount(eid)).unwrap_or(0u32);
+    store(env, params, &DataKey::Issued(eid, issued_count), &pk_cast);
+    store(env, params, &DataKey::IssuedCount(eid), &(issued_count + 1));
+
+    env.events().publish(
+        (symbol_short!("issued"), eid),
+        events::AccountIssued { version: EVENT_VERSION, nf_issue, pk_cast, weight, voter },
+    );
+    Ok(())
+}
+
+/// Mark `nf_cast` as consumed and append it to the cast-nullifier index.
+fn record_cast_nullifier(env: &Env, params: &ElectionParams, eid: u64, nf_cast: &BytesN<32>) {
+    store(env, params, &DataKey::CastNullifier(eid, nf_cast.clone()), &true);
+    let cast_count: u32 = env
+        .storage().persistent().get(&DataKey::CastCount(eid)).unwrap_or(0u32);
+    store(env, params, &DataKey::Cast(eid, cast_count), nf_cast);
+    store(env, params, &DataKey::CastCount(eid), &(cast_count + 1));
+}
+
+// ── Share posting ─────────────────────────────────────────────────────────────
+
+/// Load election `eid` if it accepts decryption shares: active, voting
+/// closed and not yet tallied.
+fn load_for_shares(env: &Env, eid: u64) -> Result<ElectionParams, ContractError> {
+    let params = load_election(env, eid)?;
+    ensure_active(&params)?;
+    if env.ledger().timestamp() < params.end_time {
+        return Err(ContractError::OutsideVotingWindow);
+    }
+    if params.tallied {
+        return Err(ContractError::AlreadyTallied);
+    }
+    Ok(params)
+}
+
+/// Ed25519 key of roster slot `kh_idx`.
+fn kh_key(env: &Env, eid: u64, kh_idx: u32) -> Result<BytesN<32>, ContractError> {
+    let kh_roster: Vec<BytesN<32>> = env
+        .storage().persistent().get(&DataKey::KhRoster(eid)).unwrap();
+    kh_roster.get(kh_idx).ok_or(ContractError::NotKeyHolder)
+}
+
+fn load_upload(env: &Env, eid: u64, kh_idx: u32) -> Option<ShareUpload> {
+    env.storage().persistent().get(&DataKey::ShareUpload(eid, kh_idx))
+}
+
+/// Whether `kh_idx` has a complete share set, posted whole or committed.
+fn shares_posted(env: &Env, eid: u64, kh_idx: u32) -> bool {
+    env.storage().persistent().has(&DataKey::KhShare(eid, kh_idx))
+        || load_upload(env, eid, kh_idx).is_some_and(|upload| upload.committed)
+}
+
+/// Check shares `start ..` of a batch against the board: the current
+/// layout, 33-byte points, and one share per ballot, in board order, for
+/// that ballot's C1.
+fn check_share_batch(env: &Env, eid: u64, start: u32, batch: &ShareBatch) -> Result<(), ContractError> {
+    if batch.version != SHARE_BATCH_VERSION {
+        return Err(ContractError::UnsupportedShareBatch);
+    }
+    for (i, share) in batch.shares.iter().enumerate() {
+        let proof_ok = match &share.proof {
+            ShareProof::None => true,
+            ShareProof::Cp(p) => p.r1.len() == 33 && p.r2.len() == 33,
+        };
+        if share.d.len() != 33 || !proof_ok {
+            return Err(ContractError::EncodingError);
+        }
+        let index = start + i as u32;
+        let ballot = load_ballot(env, eid, index).ok_or(ContractError::ShareBatchMismatch)?;
+        if share.ballot_index != index || share.c1 != ballot.c1 {
+            return Err(ContractError::ShareBatchMismatch);
+        }
+    }
+    Ok(())
+}
+
+/// Count a key holder's complete share set and publish `share` (and
+/// `threshold` once reached).  Returns the new share count.
+fn record_share(
+    env: &Env,
+    params: &ElectionParams,
+    eid: u64,
+    kh_idx: u32,
+    shares_hash: BytesN<32>,
+) -> u32 {
+    let share_count: u32 = env
+        .storage().persistent().get(&DataKey::ShareCount(eid)).unwrap_or(0u32);
+    let new_count = share_count + 1;
+    store(env, params, &DataKey::ShareCount(eid), &new_count);
+
+    let kh_threshold: u32 = env
+        .storage().persistent().get(&DataKey::KhThreshold(eid)).unwrap();
+    env.events().publish(
+        (symbol_short!("share"), eid),
+        events::SharePosted {
+            version: EVENT_VERSION,
+            kh_idx,
+            shares_hash,
+            share_count: new_count,
+        },
+    );
+    if new_count >= kh_threshold {
+        if !env.storage().persistent().has(&DataKey::ThresholdAt(eid)) {
+            store(env, params, &DataKey::ThresholdAt(eid), &env.ledger().timestamp());
+        }
+        env.events().publish(
+            (symbol_short!("threshold"), eid),
+            events::ThresholdReached {
+                version: EVENT_VERSION,
+                share_count: new_count,
+                threshold: kh_threshold,
+            },
+        );
+    }
+    new_count
+}
+
+/// Digest identifying `kh_idx`'s counted shares: the `shares_msg` of a
+/// whole batch or the digest of a committed upload.
+fn posted_digest(env: &Env, eid: u64, kh_idx: u32) -> Option<BytesN<32>> {
+    if let Some(blob) = env.storage().persistent().get::<_, Bytes>(&DataKey::KhShare(eid, kh_idx)) {
+        return Some(shares_msg(env, eid, &blob));
+    }
+    load_upload(env, eid, kh_idx).filter(|u| u.committed).map(|u| u.digest)
+}
+
+/// Share for board index `ballot_index` in `kh_idx`'s counted shares.
+fn posted_share(env: &Env, eid: u64, kh_idx: u32, ballot_index: u32) -> Option<DecryptionShare> {
+    let storage = env.storage().persistent();
+    if let Some(blob) = storage.get::<_, Bytes>(&DataKey::KhShare(eid, kh_idx)) {
+        return blob_share(env, &blob, ballot_index);
+    }
+    let upload = load_upload(env, eid, kh_idx).filter(|u| u.committed)?;
+    // Chunks cover the board contiguously from 0
+    let mut start = 0u32;
+    for c in 0..upload.chunks {
+        let blob: Bytes = storage.get(&DataKey::ShareChunk(eid, kh_idx, c))?;
+        let count = u32::from_le_bytes(blob_array(&blob, 1)?);
+        if ballot_index < start + count {
+            return blob_share(env, &blob, ballot_index - start);
+        }
+        start += count;
+    }
+    None
+}
+
+/// Parse share `pos` of a `serialise_share_batch` blob.
+fn blob_share(env: &Env, blob: &Bytes, pos: u32) -> Option<DecryptionShare> {
+    const SHARE_LEN: u32 = 4 + 33 + 33 + 1;
+    const PROOF_LEN: u32 = 33 + 33 + 32;
+    let mut at = 5u32;
+    for _ in 0..pos {
+        let kind = blob.get(at + SHARE_LEN - 1)?;
+        at += SHARE_LEN + if kind == 1 { PROOF_LEN } else { 0 };
+    }
+    let proof = match blob.get(at + SHARE_LEN - 1)? {
+        1 => {
+            let p = at + SHARE_LEN;
+            ShareProof::Cp(CpProof {
+                r1: blob.slice(p..p + 33),
+                r2: blob.slice(p + 33..p + 66),
+                s: BytesN::from_array(env, &blob_array(blob, p + 66)?),
+            })
+        }
+        _ => ShareProof::None,
+    };
+    Some(DecryptionShare {
+        ballot_index: u32::from_le_bytes(blob_array(blob, at)?),
+        c1: blob.slice(at + 4..at + 37),
+        d: blob.slice(at + 37..at + 70),
+        proof,
+    })
+}
+
+fn blob_array<const N: usize>(blob: &Bytes, at: u32) -> Option<[u8; N]> {
+    if blob.len() < at + N as u32 {
+        return None;
+    }
+    let mut out = [0u8; N];
+    blob.slice(at..at + N as u32).copy_into_slice(&mut out);
+    Some(out)
+}
+
+/// Drop `kh_idx`'s counted shares so the key holder can post again.
+/// Returns the new share count; the dispute window restarts once the
+/// threshold is met again.
+fn remove_shares(env: &Env, params: &ElectionParams, eid: u64, kh_idx: u32) -> u32 {
+    let storage = env.storage().persistent();
+    storage.remove(&DataKey::KhShare(eid, kh_idx));
+    if let Some(upload) = load_upload(env, eid, kh_idx) {
+        for c in 0..upload.chunks {
+            storage.remove(&DataKey::ShareChunk(eid, kh_idx, c));
+        }
+        storage.remove(&DataKey::ShareUpload(eid, kh_idx));
+    }
+    let share_count: u32 = storage.get(&DataKey::ShareCount(eid)).unwrap_or(0);
+    let new_count = share_count.saturating_sub(1);
+    store(env, params, &DataKey::ShareCount(eid), &new_count);
+    let kh_threshold: u32 = storage.get(&DataKey::KhThreshold(eid)).unwrap();
+    if new_count < kh_threshold {
+        storage.remove(&DataKey::ThresholdAt(eid));
+    }
+    new_count
+}
+
+/// Forfeit the stakes of bonded slots without counted shares to the bond's
+/// recipient.  Returns the number of slots slashed.
+fn slash_stakes(env: &Env, eid: u64, bond: &KhBond) -> u32 {
+    let storage = env.storage().persistent();
+    let kh_roster: Vec<BytesN<32>> = storage.get(&DataKey::KhRoster(eid)).unwrap();
+    let token = token::TokenClient::new(env, &bond.token);
+    let mut slashed = 0u32;
+    for kh_idx in 0..kh_roster.len() {
+        let key = DataKey::KhStake(eid, kh_idx);
+        if !storage.has(&key) || shares_posted(env, eid, kh_idx) {
+            continue;
+        }
+        storage.remove(&key);
+        token.transfer(&env.current_contract_address(), &bond.recipient, &bond.amount);
+        env.events().publish(
+            (symbol_short!("slashed"), eid),
+            events::StakeSlashed {
+                version: EVENT_VERSION,
+                kh_idx,
+                recipient: bond.recipient.clone(),
+                amount: bond.amount,
+            },
+        );
+        slashed += 1;
+    }
+    slashed
+}
+
+/// Send every bonded stake of `eid` back to its staker.  Called once the
+/// election is tallied, cancelled or deleted.
+fn return_stakes(env: &Env, params: &ElectionParams) {
+    let StakePolicy::Bond(bond) = &params.config.staking else {
+        return;
+    };
+    let storage = env.storage().persistent();
+    let kh_roster: Vec<BytesN<32>> = storage.get(&DataKey::KhRoster(params.eid)).unwrap();
+    let token = token::TokenClient::new(env, &bond.token);
+    for kh_idx in 0..kh_roster.len() {
+        let key = DataKey::KhStake(params.eid, kh_idx);
+        let Some(staker) = storage.get::<_, Address>(&key) else {
+            continue;
+        };
+        storage.remove(&key);
+        token.transfer(&env.current_contract_address(), &staker, &bond.amount);
+        env.events().publish(
+            (symbol_short!("returned"), params.eid),
+            events::StakeReturned { version: EVENT_VERSION, kh_idx, staker, amount: bond.amount },
+        );
+    }
+}
+
+// ── Voter registry ────────────────────────────────────────────────────────────
+
+/// Swap `voter`'s registry leaf from `old_leaf` to `new_leaf`.  `proof` must
+/// open `old_leaf` against the current root; the new root is recomputed from
+/// the same siblings.  Returns the new registry version.
+fn update_registry(
+    env: &Env,
+    eid: u64,
+    voter: &Bytes,
+    old_leaf: &BytesN<32>,
+    new_leaf: &BytesN<32>,
+    proof: &SmtProof,
+    topic: soroban_sdk::Symbol,
+) -> Result<u32, ContractError> {
+    let params = load_election(env, eid)?;
+    params.organizer.require_auth();
+    if !matches!(params.config.eligibility, EligibilityMode::Registry) {
+        return Err(ContractError::WrongEligibilityMode);
+    }
+    if params.status == ElectionStatus::Cancelled {
+        return Err(ContractError::ElectionCancelled);
+    }
+    if env.ledger().timestamp() >= params.start_time {
+        return Err(ContractError::RegistrationClosed);
+    }
+
+    let root: BytesN<32> = env
+        .storage().persistent().get(&DataKey::EligibleRoot(eid)).unwrap();
+    if smt::root_with(env, voter, old_leaf, proof) != Some(root) {
+        return Err(ContractError::InvalidMerkleProof);
+    }
+    let new_root = smt::root_with(env, voter, new_leaf, proof)
+        .ok_or(ContractError::InvalidMerkleProof)?;
+
+    let version: u32 = env
+        .storage().persistent().get(&DataKey::RegistryVersion(eid)).unwrap_or(0) + 1;
+    store(env, &params, &DataKey::EligibleRoot(eid), &new_root);
+    store(env, &params, &DataKey::RegistryVersion(eid), &version);
+    store(
+        env,
+        &params,
+        &DataKey::RegistryRoot(eid, version),
+        &RegistryRoot { root: new_root.clone(), updated_at: env.ledger().timestamp() },
+    );
+
+    env.events().publish(
+        (topic, eid),
+        events::RegistryUpdated {
+            version: EVENT_VERSION,
+            voter: voter.clone(),
+            registry_version: version,
+            root: new_root,
+        },
+    );
+
+    Ok(version)
 }
 
 // ── Contract ──────────────────────────────────────────────────────────────────
@@ -86,60 +1048,111 @@ impl ElectionContract {
 
     /// Deploy a new election.  Returns the new election-id (eid).
     ///
+    /// On a single-election instance the instance admin must authorize, and
+    /// only the first call succeeds (eid 0).
+    ///
     /// Soroban contracts are limited to 10 function parameters; KH VSS
     /// commitments are submitted via `set_kh_commitment()` after deployment.
     ///
     /// Parameters (10 total):
-    /// 1. title            — UTF-8 bytes
-    /// 2. options_count    — number of voting options (≥ 2)
-    /// 3. start_time       — Unix timestamp (s)
-    /// 4. end_time         — Unix timestamp (s)
-    /// 5. enc_pubkey       — 33-byte compressed secp256k1 combined KH pubkey
-    /// 6. eligibility_root — SHA256 Merkle root of eligible voter pubkey set
-    /// 7. dist_roster      — distributor Ed25519 pubkeys (32-byte each)
-    /// 8. dist_threshold   — M-of-N distributor threshold
-    /// 9. kh_roster        — key-holder Ed25519 pubkeys (32-byte each)
-    /// 10. kh_threshold    — t-of-m KH threshold
+    /// 1. organizer        — account authorised to pause / resume / cancel
+    /// 2. title            — UTF-8 bytes
+    /// 3. options_count    — number of voting options (≥ 2)
+    /// 4. start_time       — Unix timestamp (s)
+    /// 5. end_time         — Unix timestamp (s)
+    /// 6. enc_pubkey       — 33-byte compressed secp256k1 combined KH pubkey
+    /// 7. eligibility_root — SHA256 Merkle root of eligible voter pubkey set
+    ///                       (initial sparse root in registry mode)
+    /// 8. dist             — distributor committee (Ed25519 pubkeys, M-of-N threshold)
+    /// 9. kh               — key-holder committee (Ed25519 pubkeys, t-of-m threshold)
+    /// 10. config          — per-election behaviour switches (e.g. revoting)
+    #[allow(clippy::too_many_arguments)]
     pub fn deploy(
         env: Env,
+        organizer: Address,
         title: Bytes,
         options_count: u32,
         start_time: u64,
         end_time: u64,
         enc_pubkey: Bytes,
         eligibility_root: BytesN<32>,
-        dist_roster: Vec<BytesN<32>>,
-        dist_threshold: u32,
-        kh_roster: Vec<BytesN<32>>,
-        kh_threshold: u32,
+        dist: Committee,
+        kh: Committee,
+        config: ElectionConfig,
     ) -> Result<u64, ContractError> {
+        organizer.require_auth();
+        if let EligibilityMode::Token(gate) = &config.eligibility {
+            if gate.min_balance <= 0 {
+                return Err(ContractError::InvalidTokenGate);
+            }
+        }
+        if let StakePolicy::Bond(bond) = &config.staking {
+            if bond.amount <= 0 || bond.tally_deadline <= config.max_end_time.max(end_time) {
+                return Err(ContractError::InvalidStakePolicy);
+            }
+        }
+
         let eid: u64 = env
             .storage()
-            .persistent()
+            .instance()
             .get(&DataKey::NextElectionId)
             .unwrap_or(0u64);
+        if env.storage().instance().has(&DataKey::SingleElection) {
+            if eid > 0 {
+                return Err(ContractError::AlreadyExists);
+            }
+            let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            if admin != organizer {
+                admin.require_auth();
+            }
+        }
 
         let params = ElectionParams {
             eid,
+            organizer,
             title,
             options_count,
             start_time,
             end_time,
             enc_pubkey,
             tallied: false,
+            status: ElectionStatus::Active,
+            config,
         };
 
-        env.storage().persistent().set(&DataKey::Election(eid), &params);
-        env.storage().persistent().set(&DataKey::EligibleRoot(eid), &eligibility_root);
-        env.storage().persistent().set(&DataKey::DistRoster(eid), &dist_roster);
-        env.storage().persistent().set(&DataKey::DistThreshold(eid), &dist_threshold);
-        env.storage().persistent().set(&DataKey::KhRoster(eid), &kh_roster);
-        env.storage().persistent().set(&DataKey::KhThreshold(eid), &kh_threshold);
-        env.storage().persistent().set(&DataKey::NextElectionId, &(eid + 1));
+        save_election(&env, &params);
+        store(&env, &params, &DataKey::EligibleRoot(eid), &eligibility_root);
+        if matches!(params.config.eligibility, EligibilityMode::Registry) {
+            store(&env, &params, &DataKey::RegistryVersion(eid), &0u32);
+            store(
+                &env,
+                &params,
+                &DataKey::RegistryRoot(eid, 0),
+                &RegistryRoot { root: eligibility_root.clone(), updated_at: env.ledger().timestamp() },
+            );
+        }
+        store(&env, &params, &DataKey::DistRoster(eid), &dist.members);
+        store(&env, &params, &DataKey::DistThreshold(eid), &dist.threshold);
+        store(&env, &params, &DataKey::KhRoster(eid), &kh.members);
+        store(&env, &params, &DataKey::KhThreshold(eid), &kh.threshold);
+        env.storage().instance().set(&DataKey::NextElectionId, &(eid + 1));
+        bump_instance(&env);
 
         env.events().publish(
             (symbol_short!("deploy"), eid),
-            (params.start_time, params.end_time, params.options_count),
+            events::Deployed {
+                version: EVENT_VERSION,
+                start_time: params.start_time,
+                end_time: params.end_time,
+                options_count: params.options_count,
+                organizer: params.organizer,
+                title: params.title,
+                enc_pubkey: params.enc_pubkey,
+                eligibility_root,
+                dist,
+                kh,
+                config: params.config,
+            },
         );
 
         Ok(eid)
@@ -153,7 +1166,10 @@ impl ElectionContract {
         kh_idx: u32,
         commitment: Bytes,
     ) -> Result<(), ContractError> {
-        load_election(&env, eid)?;
+        let params = load_election(&env, eid)?;
+        if params.status == ElectionStatus::Cancelled {
+            return Err(ContractError::ElectionCancelled);
+        }
         let kh_roster: Vec<BytesN<32>> = env
             .storage()
             .persistent()
@@ -162,9 +1178,99 @@ impl ElectionContract {
         if kh_idx >= kh_roster.len() {
             return Err(ContractError::NotKeyHolder);
         }
-        env.storage()
-            .persistent()
-            .set(&DataKey::KhCommitment(eid, kh_idx), &commitment);
+        store(&env, &params, &DataKey::KhCommitment(eid, kh_idx), &commitment);
+
+        env.events().publish(
+            (symbol_short!("commit"), eid),
+            events::CommitmentSet { version: EVENT_VERSION, kh_idx, commitment },
+        );
+
+        Ok(())
+    }
+
+    /// Bond roster slot `kh_idx` with the election's stake, paid from
+    /// `staker` (normally the key holder's own account), which also receives
+    /// it back after the tally.  `sig` signs `stake_msg(eid, kh_idx, staker)`
+    /// with the roster key, so no one else can bond, and later forfeit, the
+    /// slot.  Only under `StakePolicy::Bond`, once per slot, before
+    /// `start_time`.
+    pub fn deposit_stake(
+        env: Env,
+        eid: u64,
+        kh_idx: u32,
+        staker: Address,
+        sig: BytesN<64>,
+    ) -> Result<(), ContractError> {
+        let params = load_election(&env, eid)?;
+        ensure_active(&params)?;
+        let StakePolicy::Bond(bond) = &params.config.staking else {
+            return Err(ContractError::StakingDisabled);
+        };
+        staker.require_auth();
+        let kh_pk = kh_key(&env, eid, kh_idx)?;
+        let msg_bytes: Bytes = stake_msg(&env, eid, kh_idx, &staker).into();
+        env.crypto().ed25519_verify(&kh_pk, &msg_bytes, &sig);
+        if env.ledger().timestamp() >= params.start_time {
+            return Err(ContractError::RegistrationClosed);
+        }
+        if env.storage().persistent().has(&DataKey::KhStake(eid, kh_idx)) {
+            return Err(ContractError::AlreadyStaked);
+        }
+
+        token::TokenClient::new(&env, &bond.token)
+            .transfer(&staker, &env.current_contract_address(), &bond.amount);
+        store(&env, &params, &DataKey::KhStake(eid, kh_idx), &staker);
+        env.events().publish(
+            (symbol_short!("staked"), eid),
+            events::StakeDeposited { version: EVENT_VERSION, kh_idx, staker, amount: bond.amount },
+        );
+        Ok(())
+    }
+
+    // ── Stage 0b: Voter registry ──────────────────────────────────────────────
+
+    /// Registry mode: add `voter` to the eligibility tree.  `proof` shows the
+    /// voter's slot is empty under the current root; the same siblings yield
+    /// the new root.  Only before `start_time`.  Organizer only.
+    pub fn add_voter(env: Env, eid: u64, voter: Bytes, proof: SmtProof) -> Result<u32, ContractError> {
+        let empty = BytesN::from_array(&env, &smt::EMPTY_LEAF);
+        let member = smt::member_leaf(&env, &voter);
+        update_registry(&env, eid, &voter, &empty, &member, &proof, symbol_short!("voter_add"))
+    }
+
+    /// Registry mode: remove `voter` from the eligibility tree.  `proof` shows
+    /// membership under the current root.  Casting accounts already issued to
+    /// the voter are unaffected.  Only before `start_time`.  Organizer only.
+    pub fn revoke_voter(env: Env, eid: u64, voter: Bytes, proof: SmtProof) -> Result<u32, ContractError> {
+        let empty = BytesN::from_array(&env, &smt::EMPTY_LEAF);
+        let member = smt::member_leaf(&env, &voter);
+        update_registry(&env, eid, &voter, &member, &empty, &proof, symbol_short!("voter_rev"))
+    }
+
+    /// Replace the revocation root: a sparse Merkle tree (see `smt.rs`) of
+    /// voters who may no longer be issued casting accounts, keyed like the
+    /// eligibility proof.  Takes effect for subsequent issuances only; setting
+    /// the empty-tree root clears the list.  Organizer only.
+    pub fn set_revocation_root(env: Env, eid: u64, root: BytesN<32>) -> Result<(), ContractError> {
+        let params = load_election(&env, eid)?;
+        params.organizer.require_auth();
+        if params.status == ElectionStatus::Cancelled {
+            return Err(ContractError::ElectionCancelled);
+        }
+        if params.tallied {
+            return Err(ContractError::AlreadyTallied);
+        }
+
+        if root == smt::empty_root(&env) {
+            env.storage().persistent().remove(&DataKey::RevocationRoot(eid));
+        } else {
+            store(&env, &params, &DataKey::RevocationRoot(eid), &root);
+        }
+        env.events().publish(
+            (symbol_short!("revoked"), eid),
+            events::RevocationSet { version: EVENT_VERSION, root },
+        );
+
         Ok(())
     }
 
@@ -173,60 +1279,148 @@ impl ElectionContract {
     /// Register a casting account after the distributor committee approves.
     ///
     /// Parameters:
-    /// * eid       — election id
-    /// * pk_cast   — 32-byte Ed25519 pubkey of the new casting account
-    /// * nf_issue  — 32-byte issue nullifier
-    /// * dist_sigs — Vec<(distributor_pk_32, ed25519_sig_64)>
+    /// * eid         — election id
+    /// * pk_cast     — 32-byte Ed25519 pubkey of the new casting account
+    /// * nf_issue    — 32-byte issue nullifier
+    /// * eligibility — voter eligibility proof, checked against the current
+    ///   eligibility root (required in registry mode, optional in static mode)
+    /// * revocation  — proof that the same voter is absent from the revocation
+    ///   root; required (together with `eligibility`) once a root is set
+    /// * dist_sigs   — Vec<(distributor_pk_32, ed25519_sig_64)>
     pub fn issue_account(
         env: Env,
         eid: u64,
         pk_cast: BytesN<32>,
         nf_issue: BytesN<32>,
+        eligibility: Option<EligibilityProof>,
+        revocation: Option<SmtProof>,
         dist_sigs: Vec<(BytesN<32>, BytesN<64>)>,
     ) -> Result<(), ContractError> {
-        load_election(&env, eid)?;
+        let params = load_election(&env, eid)?;
+        ensure_active(&params)?;
 
         if env.storage().persistent().has(&DataKey::IssueNullifier(eid, nf_issue.clone())) {
             return Err(ContractError::AlreadyIssued);
         }
 
-        let dist_roster: Vec<BytesN<32>> = env
-            .storage().persistent().get(&DataKey::DistRoster(eid)).unwrap();
-        let dist_threshold: u32 = env
-            .storage().persistent().get(&DataKey::DistThreshold(eid)).unwrap();
+        let root: BytesN<32> = env
+            .storage().persistent().get(&DataKey::EligibleRoot(eid)).unwrap();
+        let mut holder = None;
+        let mut weight = 1u32;
+        let (voter, eligible) = match (params.config.eligibility.clone(), eligibility) {
+            (EligibilityMode::Static, None) => (None, true),
+            (EligibilityMode::Static, Some(EligibilityProof::Merkle(leaf, path))) => {
+                let ok = merkle::verify(&env, &root, &leaf, &path);
+                (Some(leaf), ok)
+            }
+            (EligibilityMode::Registry, Some(EligibilityProof::Registry(voter, proof))) => {
+                let ok = smt::verify_membership(&env, &root, &voter, &proof);
+                (Some(voter), ok)
+            }
+            (EligibilityMode::Token(gate), Some(EligibilityProof::Token(addr))) => {
+                weight = check_token_holder(&env, eid, &gate, &addr)?;
+                let key = addr.clone().to_xdr(&env);
+                holder = Some(addr);
+                (Some(key), true)
+            }
+            _ => return Err(ContractError::WrongEligibilityMode),
+        };
+        if !eligible {
+            return Err(ContractError::InvalidMerkleProof);
+        }
+        let voter_hash = voter_leaf(&env, &voter);
+
+        let revocation_root: Option<BytesN<32>> = env
+            .storage().persistent().get(&DataKey::RevocationRoot(eid));
+        if let Some(revocation_root) = revocation_root {
+            let (Some(voter), Some(proof)) = (voter, revocation) else {
+                return Err(ContractError::InvalidMerkleProof);
+            };
+            if !smt::verify_non_membership(&env, &revocation_root, &voter, &proof) {
+                if smt::verify_membership(&env, &revocation_root, &voter, &proof) {
+                    return Err(ContractError::VoterRevoked);
+                }
+                return Err(ContractError::InvalidMerkleProof);
+            }
+        }
+
+        let digest = issue_msg(&env, eid, &pk_cast, &nf_issue, &voter_hash);
+        check_dist_sigs(&env, eid, &digest, &dist_sigs)?;
+        record_issuance(&env, &params, eid, pk_cast, nf_issue, voter_hash, holder, weight)?;
+
+        Ok(())
+    }
 
-        let msg_hash = issue_msg(&env, eid, &pk_cast, &nf_issue);
-        let msg_bytes: Bytes = msg_hash.into();
+    /// Register many casting accounts at once.
+    ///
+    /// All `items` are checked against the eligibility root with a single
+    /// multiproof, and the distributor committee signs one digest
+    /// (`batch_issue_msg`) for the whole batch.  The batch is atomic: any
+    /// failing item (e.g. a reused nullifier) rejects all of them.  Each item
+    /// still emits its own `issued` event.
+    ///
+    /// Only for static-mode elections without a revocation list; use
+    /// `issue_account` otherwise.  Returns the number of accounts issued.
+    pub fn batch_issue_accounts(
+        env: Env,
+        eid: u64,
+        items: Vec<IssueItem>,
+        proof: MerkleMultiProof,
+        dist_sigs: Vec<(BytesN<32>, BytesN<64>)>,
+    ) -> Result<u32, ContractError> {
+        let params = load_election(&env, eid)?;
+        ensure_active(&params)?;
+        if !matches!(params.config.eligibility, EligibilityMode::Static)
+            || env.storage().persistent().has(&DataKey::RevocationRoot(eid))
+        {
+            return Err(ContractError::WrongEligibilityMode);
+        }
 
-        let mut valid_count: u32 = 0;
-        for i in 0..dist_sigs.len() {
-            let (signer_pk, sig) = dist_sigs.get(i).unwrap();
-            if !dist_roster.contains(&signer_pk) {
-                continue;
+        let mut leaves: Vec<(u32, BytesN<32>)> = Vec::new(&env);
+        for item in items.iter() {
+            if leaves.last().is_some_and(|(prev, _)| prev >= item.leaf_index) {
+                return Err(ContractError::InvalidBatch);
             }
-            env.crypto().ed25519_verify(&signer_pk, &msg_bytes, &sig);
-            valid_count += 1;
+            leaves.push_back((item.leaf_index, merkle::leaf_hash(&env, &item.leaf)));
+        }
+        if leaves.is_empty() {
+            return Err(ContractError::InvalidBatch);
         }
 
-        if valid_count < dist_threshold {
-            return Err(ContractError::InvalidDistributorSig);
+        let root: BytesN<32> = env
+            .storage().persistent().get(&DataKey::EligibleRoot(eid)).unwrap();
+        if !merkle::verify_multi(&env, &root, proof.depth, &leaves, &proof.siblings) {
+            return Err(ContractError::InvalidMerkleProof);
         }
 
-        env.storage().persistent()
-            .set(&DataKey::IssueNullifier(eid, nf_issue.clone()), &true);
-        env.storage().persistent()
-            .set(&DataKey::CastingAccount(eid, pk_cast.clone()), &true);
+        let digest = batch_issue_msg(&env, eid, &items);
+        check_dist_sigs(&env, eid, &digest, &dist_sigs)?;
 
-        env.events()
-            .publish((symbol_short!("issued"), eid), (nf_issue, pk_cast));
+        for item in items.iter() {
+            if env.storage().persistent().has(&DataKey::IssueNullifier(eid, item.nf_issue.clone())) {
+                return Err(ContractError::AlreadyIssued);
+            }
+            let voter = voter_leaf(&env, &Some(item.leaf));
+            record_issuance(&env, &params, eid, item.pk_cast, item.nf_issue, voter, None, 1)?;
+        }
 
-        Ok(())
+        env.events().publish(
+            (symbol_short!("batch"), eid),
+            events::BatchIssued { version: EVENT_VERSION, digest, count: items.len() },
+        );
+
+        Ok(items.len())
     }
 
     // ── Stage 2: Cast ─────────────────────────────────────────────────────────
 
     /// Submit an encrypted ballot.
     ///
+    /// Each casting account holds at most one ballot.  A second cast from the
+    /// same account is rejected with `AlreadyVoted`, unless the election was
+    /// deployed with `config.revoting`, in which case the new ballot replaces
+    /// the account's previous one at the same index.
+    ///
     /// Parameters:
     /// * eid     — election id
     /// * nf_cast — 32-byte cast nullifier
@@ -235,7 +1429,9 @@ impl ElectionContract {
     /// * pk_cast — 32-byte Ed25519 pubkey of the casting account
     /// * sig     — 64-byte Ed25519 signature over cast_msg(...)
     ///
-    /// Returns the ballot index.
+    /// Returns the ballot index.  The `cast` event carries the ballot digest
+    /// (`cast_msg`) and the new board head, which together form the voter's
+    /// inclusion receipt.
     pub fn cast(
         env: Env,
         eid: u64,
@@ -246,40 +1442,100 @@ impl ElectionContract {
         sig: BytesN<64>,
     ) -> Result<u32, ContractError> {
         let params = load_election(&env, eid)?;
+        ensure_active(&params)?;
 
         let now = env.ledger().timestamp();
         if now < params.start_time || now >= params.end_time {
             return Err(ContractError::OutsideVotingWindow);
         }
+        ensure_bonded(&env, eid, &params)?;
 
         if env.storage().persistent().has(&DataKey::CastNullifier(eid, nf_cast.clone())) {
             return Err(ContractError::AlreadyVoted);
         }
 
-        if !env.storage().persistent().has(&DataKey::CastingAccount(eid, pk_cast.clone())) {
-            return Err(ContractError::NotIssuedAccount);
+        let account_key = DataKey::CastingAccount(eid, pk_cast.clone());
+        let mut account: CastingAccount = env
+            .storage().persistent().get(&account_key)
+            .ok_or(ContractError::NotIssuedAccount)?;
+
+        if account.status == AccountStatus::Voted && !params.config.revoting {
+            return Err(ContractError::AlreadyVoted);
         }
 
         let msg_hash = cast_msg(&env, eid, &nf_cast, &c1, &c2);
-        let msg_bytes: Bytes = msg_hash.into();
+        let msg_bytes: Bytes = msg_hash.clone().into();
         env.crypto().ed25519_verify(&pk_cast, &msg_bytes, &sig);
 
+        let ballot = EncryptedBallot {
+            nf_cast: nf_cast.clone(),
+            c1,
+            c2,
+            weight: account.weight,
+        };
+
+        // Every accepted ballot, including a revote, is appended to both the
+        // hash chain and the Merkle tree.
+        let mut tree = ballot_tree(&env, eid);
+        let leaf = ballot_leaf(&env, &msg_hash);
+        if !merkle::append(&env, &mut tree, &leaf) {
+            return Err(ContractError::BoardFull);
+        }
+        store(&env, &params, &DataKey::BallotTree(eid), &tree);
+        store(&env, &params, &DataKey::BallotLeaf(eid, tree.size - 1), &leaf);
+
+        let head = board_step(&env, &board_head(&env, eid), &msg_hash);
+        store(&env, &params, &DataKey::BoardHead(eid), &head);
+
+        // Revote: overwrite the account's existing slot so the board keeps
+        // exactly one ballot per casting account.
+        if let Some(index) = account.ballot_index {
+            save_ballot(&env, &params, index, &ballot);
+            record_cast_nullifier(&env, &params, eid, &nf_cast);
+
+            env.events().publish(
+                (symbol_short!("recast"), eid),
+                events::BallotCast {
+                    version: EVENT_VERSION,
+                    nf_cast,
+                    index,
+                    msg_hash,
+                    board_head: head,
+                    pk_cast,
+                    c1: ballot.c1,
+                    c2: ballot.c2,
+                    weight: ballot.weight,
+                },
+            );
+
+            return Ok(index);
+        }
+
         let ballot_count: u32 = env
             .storage().persistent().get(&DataKey::BallotCount(eid)).unwrap_or(0u32);
 
-        env.storage().persistent()
-            .set(&DataKey::Ballot(eid, ballot_count), &EncryptedBallot {
-                nf_cast: nf_cast.clone(),
-                c1,
-                c2,
-            });
-        env.storage().persistent()
-            .set(&DataKey::BallotCount(eid), &(ballot_count + 1));
-        env.storage().persistent()
-            .set(&DataKey::CastNullifier(eid, nf_cast.clone()), &true);
+        save_ballot(&env, &params, ballot_count, &ballot);
+        store(&env, &params, &DataKey::BallotCount(eid), &(ballot_count + 1));
+        record_cast_nullifier(&env, &params, eid, &nf_cast);
+
+        account.status = AccountStatus::Voted;
+        account.ballot_index = Some(ballot_count);
+        store(&env, &params, &account_key, &account);
 
-        env.events()
-            .publish((symbol_short!("cast"), eid), (nf_cast, ballot_count));
+        env.events().publish(
+            (symbol_short!("cast"), eid),
+            events::BallotCast {
+                version: EVENT_VERSION,
+                nf_cast,
+                index: ballot_count,
+                msg_hash,
+                board_head: head,
+                pk_cast,
+                c1: ballot.c1,
+                c2: ballot.c2,
+                weight: ballot.weight,
+            },
+        );
 
         Ok(ballot_count)
     }
@@ -291,65 +1547,261 @@ impl ElectionContract {
     /// Parameters:
     /// * eid     — election id
     /// * kh_idx  — index of this KH in kh_roster
-    /// * shares  — Vec<(c1_compressed_bytes, D_ji_compressed_bytes)> one per ballot
+    /// * batch   — one `DecryptionShare` per ballot on the board, in order,
+    ///             each for that ballot's stored C1
     /// * kh_pk   — 32-byte Ed25519 pubkey (must match kh_roster[kh_idx])
-    /// * sig     — 64-byte Ed25519 signature over shares_msg(...)
+    /// * sig     — 64-byte Ed25519 signature over
+    ///             shares_msg(eid, serialise_share_batch(batch))
     ///
     /// Returns the cumulative share count.
     pub fn post_share(
         env: Env,
         eid: u64,
         kh_idx: u32,
-        shares: Vec<(Bytes, Bytes)>,
+        batch: ShareBatch,
         kh_pk: BytesN<32>,
         sig: BytesN<64>,
     ) -> Result<u32, ContractError> {
-        let params = load_election(&env, eid)?;
-
-        let now = env.ledger().timestamp();
-        if now < params.end_time {
-            return Err(ContractError::OutsideVotingWindow);
+        let params = load_for_shares(&env, eid)?;
+        if kh_key(&env, eid, kh_idx)? != kh_pk {
+            return Err(ContractError::NotKeyHolder);
+        }
+        if shares_posted(&env, eid, kh_idx) || load_upload(&env, eid, kh_idx).is_some() {
+            return Err(ContractError::AlreadyPosted);
         }
 
-        if params.tallied {
-            return Err(ContractError::AlreadyTallied);
+        let ballot_count: u32 = env
+            .storage().persistent().get(&DataKey::BallotCount(eid)).unwrap_or(0);
+        if batch.shares.len() != ballot_count {
+            return Err(ContractError::ShareBatchMismatch);
         }
+        check_share_batch(&env, eid, 0, &batch)?;
+        let shares_blob = serialise_share_batch(&env, &batch);
+        let msg_hash = shares_msg(&env, eid, &shares_blob);
+        let msg_bytes: Bytes = msg_hash.clone().into();
+        env.crypto().ed25519_verify(&kh_pk, &msg_bytes, &sig);
 
-        let kh_roster: Vec<BytesN<32>> = env
-            .storage().persistent().get(&DataKey::KhRoster(eid)).unwrap();
-        if kh_idx >= kh_roster.len() || kh_roster.get(kh_idx).unwrap() != kh_pk {
+        store(&env, &params, &DataKey::KhShare(eid, kh_idx), &shares_blob);
+        Ok(record_share(&env, &params, eid, kh_idx, msg_hash))
+    }
+
+    /// Open a chunked upload of a key holder's shares, for boards too large
+    /// for one `post_share` transaction.  `digest` is the final
+    /// `shares_chain_step` value over every chunk the key holder will post,
+    /// and `sig` signs `shares_begin_msg(eid, digest)`.
+    ///
+    /// Calling it again with the same digest resumes the upload; a new
+    /// digest restarts it from ballot 0, deleting the chunks posted so far.
+    /// Returns the board index the next
+    /// chunk must start at.
+    pub fn begin_shares(
+        env: Env,
+        eid: u64,
+        kh_idx: u32,
+        digest: BytesN<32>,
+        kh_pk: BytesN<32>,
+        sig: BytesN<64>,
+    ) -> Result<u32, ContractError> {
+        let params = load_for_shares(&env, eid)?;
+        if kh_key(&env, eid, kh_idx)? != kh_pk {
             return Err(ContractError::NotKeyHolder);
         }
+        if shares_posted(&env, eid, kh_idx) {
+            return Err(ContractError::AlreadyPosted);
+        }
+        let msg_bytes: Bytes = shares_begin_msg(&env, eid, &digest).into();
+        env.crypto().ed25519_verify(&kh_pk, &msg_bytes, &sig);
+
+        if let Some(previous) = load_upload(&env, eid, kh_idx) {
+            if previous.digest == digest {
+                return Ok(previous.next);
+            }
+            // The new upload's chunk count would not reach an abandoned
+            // upload's later chunks, so drop them all now
+            for c in 0..previous.chunks {
+                env.storage().persistent().remove(&DataKey::ShareChunk(eid, kh_idx, c));
+            }
+        }
+        let upload = ShareUpload {
+            digest: digest.clone(),
+            chain: BytesN::from_array(&env, &[0u8; 32]),
+            next: 0,
+            chunks: 0,
+            committed: false,
+        };
+        store(&env, &params, &DataKey::ShareUpload(eid, kh_idx), &upload);
+        env.events().publish(
+            (symbol_short!("upload"), eid),
+            events::ShareUploadBegun { version: EVENT_VERSION, kh_idx, digest },
+        );
+        Ok(0)
+    }
 
-        if env.storage().persistent().has(&DataKey::KhShare(eid, kh_idx)) {
+    /// Store the next chunk of an upload: shares for ballots `start ..`,
+    /// checked against the board like `post_share`.  `start` must be where
+    /// the upload left off (`get_share_upload(..).next`), and `sig` signs
+    /// `shares_chunk_msg` over the upload's digest and the chunk's
+    /// `shares_chunk_hash` with the roster key.  Returns the board index the
+    /// next chunk must start at.
+    pub fn post_share_chunk(
+        env: Env,
+        eid: u64,
+        kh_idx: u32,
+        start: u32,
+        batch: ShareBatch,
+        sig: BytesN<64>,
+    ) -> Result<u32, ContractError> {
+        let params = load_for_shares(&env, eid)?;
+        let kh_pk = kh_key(&env, eid, kh_idx)?;
+        let mut upload = load_upload(&env, eid, kh_idx).ok_or(ContractError::NoShareUpload)?;
+        if upload.committed {
             return Err(ContractError::AlreadyPosted);
         }
+        if start != upload.next {
+            return Err(ContractError::ChunkOutOfOrder);
+        }
+        if batch.shares.is_empty() {
+            return Err(ContractError::ShareBatchMismatch);
+        }
+        check_share_batch(&env, eid, start, &batch)?;
+        let chunk_blob = serialise_share_batch(&env, &batch);
+        let chunk_hash = shares_chunk_hash(&env, eid, start, &chunk_blob);
+        let msg_bytes: Bytes = shares_chunk_msg(&env, eid, &upload.digest, &chunk_hash).into();
+        env.crypto().ed25519_verify(&kh_pk, &msg_bytes, &sig);
 
-        let shares_blob = serialise_shares(&env, &shares);
-        let msg_hash = shares_msg(&env, eid, &shares_blob);
-        let msg_bytes: Bytes = msg_hash.into();
+        store(&env, &params, &DataKey::ShareChunk(eid, kh_idx, upload.chunks), &chunk_blob);
+        upload.chain = shares_chain_step(&env, &upload.chain, &chunk_hash);
+        upload.next += batch.shares.len();
+        upload.chunks += 1;
+        store(&env, &params, &DataKey::ShareUpload(eid, kh_idx), &upload);
+
+        env.events().publish(
+            (symbol_short!("chunk"), eid),
+            events::ShareChunkPosted {
+                version: EVENT_VERSION,
+                kh_idx,
+                start,
+                count: batch.shares.len(),
+                chunk_hash,
+            },
+        );
+        Ok(upload.next)
+    }
+
+    /// Close an upload once it covers the whole board and its chunks chain
+    /// to the signed digest.  Only then does the key holder count towards
+    /// `ShareCount`.  Anyone may call it.  Returns the cumulative share count.
+    pub fn commit_shares(env: Env, eid: u64, kh_idx: u32) -> Result<u32, ContractError> {
+        let params = load_for_shares(&env, eid)?;
+        let mut upload = load_upload(&env, eid, kh_idx).ok_or(ContractError::NoShareUpload)?;
+        if upload.committed {
+            return Err(ContractError::AlreadyPosted);
+        }
+        let ballot_count: u32 = env
+            .storage().persistent().get(&DataKey::BallotCount(eid)).unwrap_or(0);
+        if upload.next != ballot_count {
+            return Err(ContractError::ShareUploadIncomplete);
+        }
+        if upload.chain != upload.digest {
+            return Err(ContractError::ShareDigestMismatch);
+        }
+
+        upload.committed = true;
+        store(&env, &params, &DataKey::ShareUpload(eid, kh_idx), &upload);
+        Ok(record_share(&env, &params, eid, kh_idx, upload.digest))
+    }
+
+    /// Withdraw a key holder's counted shares before the tally, e.g. to
+    /// replace a batch they know to be wrong.  `sig` signs
+    /// `withdraw_msg(eid, nonce, shares_hash)` with the roster key, where
+    /// `nonce` is `get_withdraw_nonce(eid, kh_idx)` and `shares_hash` the
+    /// digest the `share` event carried.  Returns the new share count.
+    pub fn withdraw_shares(
+        env: Env,
+        eid: u64,
+        kh_idx: u32,
+        sig: BytesN<64>,
+    ) -> Result<u32, ContractError> {
+        let params = load_for_shares(&env, eid)?;
+        let kh_pk = kh_key(&env, eid, kh_idx)?;
+        let shares_hash = posted_digest(&env, eid, kh_idx).ok_or(ContractError::SharesNotPosted)?;
+        let nonce: u32 = env
+            .storage().persistent().get(&DataKey::WithdrawNonce(eid, kh_idx)).unwrap_or(0);
+        let msg_bytes: Bytes = withdraw_msg(&env, eid, nonce, &shares_hash).into();
         env.crypto().ed25519_verify(&kh_pk, &msg_bytes, &sig);
 
-        env.storage().persistent()
-            .set(&DataKey::KhShare(eid, kh_idx), &shares_blob);
-
-        let share_count: u32 = env
-            .storage().persistent().get(&DataKey::ShareCount(eid)).unwrap_or(0u32);
-        let new_count = share_count + 1;
-        env.storage().persistent().set(&DataKey::ShareCount(eid), &new_count);
+        store(&env, &params, &DataKey::WithdrawNonce(eid, kh_idx), &(nonce + 1));
+        let share_count = remove_shares(&env, &params, eid, kh_idx);
+        env.events().publish(
+            (symbol_short!("withdrawn"), eid),
+            events::SharesWithdrawn { version: EVENT_VERSION, kh_idx, shares_hash, share_count },
+        );
+        Ok(share_count)
+    }
 
+    /// Strike a key holder's counted shares by proving the share for
+    /// `ballot_index` invalid.  Anyone may call it before the tally when the
+    /// election has a dispute window.  A struck key holder stops counting
+    /// towards `ShareCount` and may post again.  Returns the new share count.
+    ///
+    /// `BadProof` evidence carries the DKG commitment matrix, which must hash
+    /// to the window's `vss_digest`; the proof is then checked on-chain
+    /// against the key holder's public share.  A share posted without a
+    /// proof cannot be checked and is struck.
+    pub fn dispute_share(
+        env: Env,
+        eid: u64,
+        kh_idx: u32,
+        ballot_index: u32,
+        evidence: ShareEvidence,
+    ) -> Result<u32, ContractError> {
+        let params = load_for_shares(&env, eid)?;
+        let DisputePolicy::Window(window) = &params.config.disputes else {
+            return Err(ContractError::DisputesDisabled);
+        };
+        kh_key(&env, eid, kh_idx)?;
+        let shares_hash = posted_digest(&env, eid, kh_idx).ok_or(ContractError::SharesNotPosted)?;
+        let share = posted_share(&env, eid, kh_idx, ballot_index)
+            .ok_or(ContractError::ShareBatchMismatch)?;
+
+        let ShareEvidence::BadProof(commitments) = &evidence;
+        // One row of `kh_threshold` coefficients per dealer: a ragged matrix
+        // would derive a different public share
         let kh_threshold: u32 = env
             .storage().persistent().get(&DataKey::KhThreshold(eid)).unwrap();
-        if new_count >= kh_threshold {
-            env.events().publish((symbol_short!("threshold"), eid), new_count);
+        if commitments.iter().any(|row| row.len() != kh_threshold)
+            || cp::vss_digest(&env, commitments) != window.vss_digest
+        {
+            return Err(ContractError::VssMismatch);
+        }
+        let pk_j = cp::public_share(commitments, kh_idx + 1).ok_or(ContractError::EvidenceRejected)?;
+        let invalid = match &share.proof {
+            ShareProof::None => true,
+            ShareProof::Cp(proof) => cp::verify(&env, &pk_j, &share.c1, &share.d, proof) == Some(false),
+        };
+        if !invalid {
+            return Err(ContractError::EvidenceRejected);
         }
 
-        Ok(new_count)
+        let share_count = remove_shares(&env, &params, eid, kh_idx);
+        env.events().publish(
+            (symbol_short!("struck"), eid),
+            events::SharesStruck {
+                version: EVENT_VERSION,
+                kh_idx,
+                shares_hash,
+                ballot_index,
+                share_count,
+            },
+        );
+        Ok(share_count)
     }
 
     // ── Stage 3b: Finalize Tally ──────────────────────────────────────────────
 
-    /// Finalise the tally after ≥ kh_threshold shares have been posted.
+    /// Finalise the tally after ≥ kh_threshold shares have been posted and,
+    /// with a dispute window, once that window has passed since the
+    /// threshold was (last) reached.
     /// Tally computation happens off-chain; this stores and verifies the result shape.
     pub fn finalize_tally(
         env: Env,
@@ -357,6 +1809,7 @@ impl ElectionContract {
         tally: Vec<u32>,
     ) -> Result<(), ContractError> {
         let mut params = load_election(&env, eid)?;
+        ensure_active(&params)?;
 
         if params.tallied {
             return Err(ContractError::AlreadyTallied);
@@ -374,25 +1827,405 @@ impl ElectionContract {
         if share_count < kh_threshold {
             return Err(ContractError::InsufficientShares);
         }
+        if let DisputePolicy::Window(window) = &params.config.disputes {
+            // Without the time the threshold was met, the window cannot be
+            // shown to have closed
+            let threshold_at: u64 = env
+                .storage().persistent().get(&DataKey::ThresholdAt(eid))
+                .ok_or(ContractError::DisputeWindowOpen)?;
+            if now < threshold_at.saturating_add(window.secs) {
+                return Err(ContractError::DisputeWindowOpen);
+            }
+        }
 
         if tally.len() != params.options_count {
             return Err(ContractError::InvalidTally);
         }
 
-        env.storage().persistent().set(&DataKey::Tally(eid), &tally);
+        let head = board_head(&env, eid);
+        let ballot_root = merkle::root(&env, &ballot_tree(&env, eid));
+        let record = TallyRecord {
+            tally,
+            ballot_count: env
+                .storage().persistent().get(&DataKey::BallotCount(eid)).unwrap_or(0),
+            board_head: head.clone(),
+            ballot_root: ballot_root.clone(),
+            share_count,
+            tallied_at: now,
+        };
+        store(&env, &params, &DataKey::Tally(eid), &record);
 
         params.tallied = true;
-        env.storage().persistent().set(&DataKey::Election(eid), &params);
+        save_election(&env, &params);
+
+        env.events().publish(
+            (symbol_short!("tallied"), eid),
+            events::Tallied {
+                version: EVENT_VERSION,
+                share_count,
+                board_head: head,
+                ballot_root,
+                tally: record.tally,
+                tallied_at: now,
+            },
+        );
+        return_stakes(&env, &params);
+
+        Ok(())
+    }
+
+    /// Forfeit the stake of every bonded roster slot without counted shares
+    /// once the bond's `tally_deadline` has passed, sending it to the bond's
+    /// `recipient`.  Anyone may call it, paused or not, until the tally
+    /// returns the remaining stakes.  Returns the number of slots slashed.
+    pub fn slash_absent(env: Env, eid: u64) -> Result<u32, ContractError> {
+        let params = load_election(&env, eid)?;
+        if params.status == ElectionStatus::Cancelled {
+            return Err(ContractError::ElectionCancelled);
+        }
+        if params.tallied {
+            return Err(ContractError::AlreadyTallied);
+        }
+        let StakePolicy::Bond(bond) = &params.config.staking else {
+            return Err(ContractError::StakingDisabled);
+        };
+        if env.ledger().timestamp() < bond.tally_deadline {
+            return Err(ContractError::TallyDeadlineNotReached);
+        }
+        Ok(slash_stakes(&env, eid, bond))
+    }
+
+    /// Token mode: return the balance `holder`'s issuance escrowed, once the
+    /// election is tallied or cancelled.  Anyone may call it; the tokens only
+    /// ever go back to `holder`.  Archiving or deleting the election releases
+    /// whatever is left.  Returns the amount released.
+    pub fn release_tokens(env: Env, eid: u64, holder: Address) -> Result<i128, ContractError> {
+        let params = load_election(&env, eid)?;
+        if !params.tallied && params.status != ElectionStatus::Cancelled {
+            return Err(ContractError::NotTallied);
+        }
+        match release_escrow(&env, eid, &holder) {
+            0 => Err(ContractError::NotFound),
+            amount => Ok(amount),
+        }
+    }
+
+    // ── Stage 4: Archive ──────────────────────────────────────────────────────
+
+    /// Compact a tallied election into a single `ArchivedElection` record and
+    /// free the per-ballot, per-share and per-account entries.
+    ///
+    /// The record keeps everything a third party needs to check the result
+    /// against event logs or off-chain copies: the parameters and rosters, the
+    /// KH commitments, the `shares_msg` digest each KH signed, the tally, and
+    /// the final ballot-board head.
+    ///
+    /// Paged like `bump_election`.  The first call (cursor 0, organizer only)
+    /// writes the record, publishes `archived` and frees the first page; from
+    /// then on every other entry point returns `NotFound` for `eid`.  Anyone
+    /// may free the rest by passing the returned `next` back until it is
+    /// `None`.
+    pub fn archive_election(env: Env, eid: u64, cursor: u32, limit: u32) -> Result<WalkPage, ContractError> {
+        if cursor > 0 {
+            return purge_page(&env, eid, cursor, limit);
+        }
+        let params = load_election(&env, eid)?;
+        params.organizer.require_auth();
+        if !params.tallied {
+            return Err(ContractError::NotTallied);
+        }
+
+        let storage = env.storage().persistent();
+
+        let ballot_count: u32 = storage.get(&DataKey::BallotCount(eid)).unwrap_or(0);
+        let board_hash = board_head(&env, eid);
+        let ballot_root = merkle::root(&env, &ballot_tree(&env, eid));
+        storage.remove(&DataKey::BoardHead(eid));
+
+        // KH commitments and share batches; chunks are freed by the walk
+        let kh_roster: Vec<BytesN<32>> = storage.get(&DataKey::KhRoster(eid)).unwrap();
+        let mut kh_commitments: Vec<Bytes> = Vec::new(&env);
+        let mut share_hashes: Vec<(u32, BytesN<32>)> = Vec::new(&env);
+        for i in 0..kh_roster.len() {
+            let commitment: Bytes = storage
+                .get(&DataKey::KhCommitment(eid, i))
+                .unwrap_or(Bytes::new(&env));
+            kh_commitments.push_back(commitment);
+            if let Some(blob) = storage.get::<_, Bytes>(&DataKey::KhShare(eid, i)) {
+                share_hashes.push_back((i, shares_msg(&env, eid, &blob)));
+            }
+            if let Some(upload) = load_upload(&env, eid, i) {
+                if upload.committed {
+                    share_hashes.push_back((i, upload.digest));
+                }
+            }
+            storage.remove(&DataKey::KhCommitment(eid, i));
+            storage.remove(&DataKey::KhShare(eid, i));
+        }
+        storage.remove(&DataKey::ShareCount(eid));
+        storage.remove(&DataKey::ThresholdAt(eid));
+
+        let record = ArchivedElection {
+            eligibility_root: storage.get(&DataKey::EligibleRoot(eid)).unwrap(),
+            revocation_root: storage.get(&DataKey::RevocationRoot(eid)),
+            dist: Committee {
+                members: storage.get(&DataKey::DistRoster(eid)).unwrap(),
+                threshold: storage.get(&DataKey::DistThreshold(eid)).unwrap(),
+            },
+            kh: Committee {
+                members: kh_roster,
+                threshold: storage.get(&DataKey::KhThreshold(eid)).unwrap(),
+            },
+            kh_commitments,
+            schedule_history: storage
+                .get(&DataKey::ScheduleHistory(eid))
+                .unwrap_or(Vec::new(&env)),
+            issued_count: storage.get(&DataKey::IssuedCount(eid)).unwrap_or(0),
+            ballot_count,
+            board_hash: board_hash.clone(),
+            ballot_root,
+            share_hashes,
+            tally: storage.get::<_, TallyRecord>(&DataKey::Tally(eid)).unwrap().tally,
+            archived_at: env.ledger().timestamp(),
+            params,
+        };
+
+        storage.remove(&DataKey::RevocationRoot(eid));
+        storage.remove(&DataKey::Tally(eid));
+        storage.remove(&DataKey::ScheduleHistory(eid));
+        storage.remove(&DataKey::EligibleRoot(eid));
+        storage.remove(&DataKey::DistRoster(eid));
+        storage.remove(&DataKey::DistThreshold(eid));
+        storage.remove(&DataKey::KhThreshold(eid));
+        storage.remove(&DataKey::Election(eid));
+
+        store(&env, &record.params, &DataKey::Archive(eid), &record);
+        store(&env, &record.params, &DataKey::Purge(eid), &0u32);
+
+        env.events().publish(
+            (symbol_short!("archived"), eid),
+            events::Archived { version: EVENT_VERSION, board_hash, ballot_count },
+        );
+
+        purge_page(&env, eid, 0, limit)
+    }
+
+    // ── Organizer controls ────────────────────────────────────────────────────
+
+    /// Halt issuance, casting and share posting, e.g. while a suspected key
+    /// compromise is investigated.  Organizer only.
+    pub fn pause(env: Env, eid: u64) -> Result<(), ContractError> {
+        let mut params = load_election(&env, eid)?;
+        params.organizer.require_auth();
+        ensure_active(&params)?;
+        if params.tallied {
+            return Err(ContractError::AlreadyTallied);
+        }
+
+        params.status = ElectionStatus::Paused;
+        save_election(&env, &params);
+
+        env.events().publish(
+            (symbol_short!("paused"), eid),
+            events::StatusChanged { version: EVENT_VERSION, changed_at: env.ledger().timestamp() },
+        );
+
+        Ok(())
+    }
+
+    /// Lift a pause.  The voting window is unchanged.  Organizer only.
+    pub fn resume(env: Env, eid: u64) -> Result<(), ContractError> {
+        let mut params = load_election(&env, eid)?;
+        params.organizer.require_auth();
+        if params.status != ElectionStatus::Paused {
+            return Err(ContractError::NotPaused);
+        }
+
+        params.status = ElectionStatus::Active;
+        save_election(&env, &params);
+
+        env.events().publish(
+            (symbol_short!("resumed"), eid),
+            events::StatusChanged { version: EVENT_VERSION, changed_at: env.ledger().timestamp() },
+        );
+
+        Ok(())
+    }
+
+    /// Permanently halt the election.  All data is retained read-only together
+    /// with the organizer's reason; the election can no longer be tallied or
+    /// deleted.  Bonded stakes go back to their stakers, except that past
+    /// `tally_deadline` slots without counted shares are slashed first.
+    /// Organizer only.
+    pub fn cancel(env: Env, eid: u64, reason: Bytes) -> Result<(), ContractError> {
+        let mut params = load_election(&env, eid)?;
+        params.organizer.require_auth();
+        if params.status == ElectionStatus::Cancelled {
+            return Err(ContractError::ElectionCancelled);
+        }
+        if params.tallied {
+            return Err(ContractError::AlreadyTallied);
+        }
+
+        params.status = ElectionStatus::Cancelled;
+        save_election(&env, &params);
+        store(
+            &env,
+            &params,
+            &DataKey::Cancellation(eid),
+            &Cancellation {
+                cancelled_at: env.ledger().timestamp(),
+                reason: reason.clone(),
+            },
+        );
+
+        env.events().publish(
+            (symbol_short!("cancelled"), eid),
+            events::Cancelled {
+                version: EVENT_VERSION,
+                cancelled_at: env.ledger().timestamp(),
+                reason,
+            },
+        );
+        // Past the deadline absent KHs have failed; only the rest get their
+        // stakes back
+        if let StakePolicy::Bond(bond) = &params.config.staking {
+            if env.ledger().timestamp() >= bond.tally_deadline {
+                slash_stakes(&env, eid, bond);
+            }
+        }
+        return_stakes(&env, &params);
+
+        Ok(())
+    }
+
+    /// Move the voting deadline forward, e.g. after a network outage.
+    ///
+    /// Only allowed while the current deadline has not passed, and only up to
+    /// `config.max_end_time` fixed at deploy.  Every change is appended to
+    /// `ScheduleHistory(eid)`.  Organizer only.
+    pub fn extend_voting(env: Env, eid: u64, new_end_time: u64) -> Result<(), ContractError> {
+        let mut params = load_election(&env, eid)?;
+        params.organizer.require_auth();
+        if params.status == ElectionStatus::Cancelled {
+            return Err(ContractError::ElectionCancelled);
+        }
 
-        env.events().publish((symbol_short!("tallied"), eid), share_count);
+        let now = env.ledger().timestamp();
+        if now >= params.end_time {
+            return Err(ContractError::OutsideVotingWindow);
+        }
+        if new_end_time <= params.end_time || new_end_time > params.config.max_end_time {
+            return Err(ContractError::InvalidSchedule);
+        }
+
+        let old_end_time = params.end_time;
+        params.end_time = new_end_time;
+        save_election(&env, &params);
+
+        let mut history: Vec<ScheduleChange> = env
+            .storage().persistent().get(&DataKey::ScheduleHistory(eid))
+            .unwrap_or(Vec::new(&env));
+        history.push_back(ScheduleChange {
+            changed_at: now,
+            old_end_time,
+            new_end_time,
+        });
+        store(&env, &params, &DataKey::ScheduleHistory(eid), &history);
+
+        env.events().publish(
+            (symbol_short!("extended"), eid),
+            events::VotingExtended { version: EVENT_VERSION, old_end_time, new_end_time },
+        );
 
         Ok(())
     }
 
+    // ── Maintenance ───────────────────────────────────────────────────────────
+
+    /// Extend the TTL of every ledger entry belonging to `eid` (and of the
+    /// contract instance), so nothing expires before the election has been
+    /// tallied and audited.  Permissionless: anyone may pay to keep an
+    /// election alive.
+    ///
+    /// Paged: each call renews the election's fixed entries plus up to
+    /// `limit` positions of the `walk_entries` walk from `cursor` (start at
+    /// 0).  Call again with the returned `next` until it is `None`; the
+    /// `bumped` event is published by the last page.
+    pub fn bump_election(env: Env, eid: u64, cursor: u32, limit: u32) -> Result<WalkPage, ContractError> {
+        let storage = env.storage().persistent();
+
+        if let Some(record) = load_archive(&env, eid) {
+            let ttl = election_ttl(&env, &record.params);
+            storage.extend_ttl(&DataKey::Archive(eid), ttl, ttl);
+            bump_instance(&env);
+            env.events().publish(
+                (symbol_short!("bumped"), eid),
+                events::TtlBumped { version: EVENT_VERSION, ttl },
+            );
+            return Ok(WalkPage { next: None });
+        }
+
+        let params = load_election(&env, eid)?;
+        let ttl = election_ttl(&env, &params);
+        let bump = |key: &DataKey| {
+            if storage.has(key) {
+                storage.extend_ttl(key, ttl, ttl);
+            }
+        };
+
+        bump(&DataKey::Election(eid));
+        bump(&DataKey::EligibleRoot(eid));
+        bump(&DataKey::RevocationRoot(eid));
+        bump(&DataKey::RegistryVersion(eid));
+        bump(&DataKey::DistRoster(eid));
+        bump(&DataKey::DistThreshold(eid));
+        bump(&DataKey::KhRoster(eid));
+        bump(&DataKey::KhThreshold(eid));
+        bump(&DataKey::Cancellation(eid));
+        bump(&DataKey::ScheduleHistory(eid));
+        bump(&DataKey::Tally(eid));
+        bump(&DataKey::IssuedCount(eid));
+        bump(&DataKey::BallotCount(eid));
+        bump(&DataKey::BoardHead(eid));
+        bump(&DataKey::BallotTree(eid));
+        bump(&DataKey::CastCount(eid));
+        bump(&DataKey::ShareCount(eid));
+        bump(&DataKey::ThresholdAt(eid));
+
+        // Per-KH entries (the committee is small)
+        let kh_roster: Vec<BytesN<32>> = storage.get(&DataKey::KhRoster(eid)).unwrap();
+        for i in 0..kh_roster.len() {
+            bump(&DataKey::KhCommitment(eid, i));
+            bump(&DataKey::KhStake(eid, i));
+            bump(&DataKey::KhShare(eid, i));
+            bump(&DataKey::ShareUpload(eid, i));
+            bump(&DataKey::WithdrawNonce(eid, i));
+        }
+
+        let next = walk_entries(&env, eid, cursor, limit, &mut |key| bump(&key));
+
+        bump_instance(&env);
+
+        if next.is_none() {
+            env.events().publish(
+                (symbol_short!("bumped"), eid),
+                events::TtlBumped { version: EVENT_VERSION, ttl },
+            );
+        }
+
+        Ok(WalkPage { next })
+    }
+
     // ── Read-only views ───────────────────────────────────────────────────────
 
     pub fn get_election(env: Env, eid: u64) -> Option<ElectionParams> {
-        env.storage().persistent().get(&DataKey::Election(eid))
+        load_election(&env, eid).ok()
+    }
+
+    pub fn get_phase(env: Env, eid: u64) -> Result<ElectionPhase, ContractError> {
+        let params = load_election(&env, eid)?;
+        Ok(election_phase(&env, &params))
     }
 
     pub fn get_ballot_count(env: Env, eid: u64) -> u32 {
@@ -400,29 +2233,212 @@ impl ElectionContract {
     }
 
     pub fn get_ballot(env: Env, eid: u64, index: u32) -> Option<EncryptedBallot> {
-        env.storage().persistent().get(&DataKey::Ballot(eid, index))
+        load_ballot(&env, eid, index)
     }
 
     pub fn get_share_count(env: Env, eid: u64) -> u32 {
         env.storage().persistent().get(&DataKey::ShareCount(eid)).unwrap_or(0)
     }
 
+    /// Earliest time `finalize_tally` may run under a dispute window, or
+    /// `None` without one or while the KH threshold is unmet.
+    pub fn get_dispute_deadline(env: Env, eid: u64) -> Option<u64> {
+        let params = load_election(&env, eid).ok()?;
+        let DisputePolicy::Window(window) = params.config.disputes else {
+            return None;
+        };
+        let threshold_at: u64 = env.storage().persistent().get(&DataKey::ThresholdAt(eid))?;
+        Some(threshold_at.saturating_add(window.secs))
+    }
+
+    /// Account that bonded roster slot `kh_idx`, while its stake is held.
+    pub fn get_kh_stake(env: Env, eid: u64, kh_idx: u32) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::KhStake(eid, kh_idx))
+    }
+
+    /// Share blob posted whole by `post_share`; chunked uploads are read
+    /// with `get_share_upload` and `get_share_chunk`.
     pub fn get_kh_shares(env: Env, eid: u64, kh_idx: u32) -> Option<Bytes> {
         env.storage().persistent().get(&DataKey::KhShare(eid, kh_idx))
     }
 
+    /// Nonce the key holder's next `withdraw_msg` must bind: its number of
+    /// withdrawals so far.
+    pub fn get_withdraw_nonce(env: Env, eid: u64, kh_idx: u32) -> u32 {
+        env.storage().persistent().get(&DataKey::WithdrawNonce(eid, kh_idx)).unwrap_or(0)
+    }
+
+    /// Progress of a key holder's chunked upload, if one was begun.
+    pub fn get_share_upload(env: Env, eid: u64, kh_idx: u32) -> Option<ShareUpload> {
+        load_upload(&env, eid, kh_idx)
+    }
+
+    /// Chunk `chunk` of a key holder's upload, as posted: a serialised
+    /// `ShareBatch` whose first share is for ballot `start` of that chunk.
+    pub fn get_share_chunk(env: Env, eid: u64, kh_idx: u32, chunk: u32) -> Option<Bytes> {
+        env.storage().persistent().get(&DataKey::ShareChunk(eid, kh_idx, chunk))
+    }
+
+    /// Entry counts of each protocol stage, plus the current phase.
+    pub fn get_summary(env: Env, eid: u64) -> Result<ElectionSummary, ContractError> {
+        let params = load_election(&env, eid)?;
+        let storage = env.storage().persistent();
+        Ok(ElectionSummary {
+            phase: election_phase(&env, &params),
+            issued_count: storage.get(&DataKey::IssuedCount(eid)).unwrap_or(0),
+            ballot_count: storage.get(&DataKey::BallotCount(eid)).unwrap_or(0),
+            cast_count: storage.get(&DataKey::CastCount(eid)).unwrap_or(0),
+            share_count: storage.get(&DataKey::ShareCount(eid)).unwrap_or(0),
+            kh_threshold: storage.get(&DataKey::KhThreshold(eid)).unwrap_or(0),
+        })
+    }
+
+    // ── Paginated views ───────────────────────────────────────────────────────
+    //
+    // Each returns at most `MAX_PAGE` entries so a page always fits in one
+    // simulation's read budget; larger `limit`s are clamped.
+
+    /// Ballots `start .. start + limit`, in board order.
+    pub fn get_ballots(env: Env, eid: u64, start: u32, limit: u32) -> Vec<EncryptedBallot> {
+        let ballot_count: u32 = env
+            .storage().persistent().get(&DataKey::BallotCount(eid)).unwrap_or(0);
+        let mut ballots = Vec::new(&env);
+        for i in page_range(start, limit, ballot_count) {
+            ballots.push_back(load_ballot(&env, eid, i).unwrap());
+        }
+        ballots
+    }
+
+    /// `(kh_idx, shares)` for the KHs in roster slots `start .. start + limit`
+    /// that have posted with `post_share` (chunked uploads are not listed).
+    pub fn get_kh_shares_range(
+        env: Env,
+        eid: u64,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<(u32, Bytes)>, ContractError> {
+        let kh_roster: Vec<BytesN<32>> = env
+            .storage().persistent().get(&DataKey::KhRoster(eid))
+            .ok_or(ContractError::NotFound)?;
+        let mut shares = Vec::new(&env);
+        for i in page_range(start, limit, kh_roster.len()) {
+            if let Some(blob) = env.storage().persistent().get::<_, Bytes>(&DataKey::KhShare(eid, i)) {
+                shares.push_back((i, blob));
+            }
+        }
+        Ok(shares)
+    }
+
+    /// Casting accounts in issuance order, starting at `cursor` (0 for the
+    /// first page).
+    pub fn list_casting_accounts(env: Env, eid: u64, cursor: u32) -> AccountPage {
+        let storage = env.storage().persistent();
+        let issued_count: u32 = storage.get(&DataKey::IssuedCount(eid)).unwrap_or(0);
+        let range = page_range(cursor, MAX_PAGE, issued_count);
+        let mut accounts = Vec::new(&env);
+        for i in range.clone() {
+            let pk_cast: BytesN<32> = storage.get(&DataKey::Issued(eid, i)).unwrap();
+            let account: CastingAccount = storage
+                .get(&DataKey::CastingAccount(eid, pk_cast.clone()))
+                .unwrap();
+            accounts.push_back((pk_cast, account));
+        }
+        AccountPage { accounts, next: (range.end < issued_count).then_some(range.end) }
+    }
+
+    /// Consumed cast nullifiers in casting order, starting at `cursor`.
+    pub fn list_cast_nullifiers(env: Env, eid: u64, cursor: u32) -> NullifierPage {
+        let storage = env.storage().persistent();
+        let cast_count: u32 = storage.get(&DataKey::CastCount(eid)).unwrap_or(0);
+        let range = page_range(cursor, MAX_PAGE, cast_count);
+        let mut nullifiers = Vec::new(&env);
+        for i in range.clone() {
+            nullifiers.push_back(storage.get(&DataKey::Cast(eid, i)).unwrap());
+        }
+        NullifierPage { nullifiers, next: (range.end < cast_count).then_some(range.end) }
+    }
+
+    /// Live elections among eids `cursor .. cursor + limit`.  A page may hold
+    /// fewer than `limit` entries when eids were archived or deleted; keep
+    /// following `next` until it is `None`.
+    pub fn list_elections(env: Env, cursor: u64, limit: u32) -> ElectionPage {
+        let next_eid: u64 = env.storage().instance().get(&DataKey::NextElectionId).unwrap_or(0);
+        let end = cursor.saturating_add(limit.min(MAX_PAGE) as u64).min(next_eid);
+        let mut elections = Vec::new(&env);
+        for eid in cursor..end {
+            if let Ok(params) = load_election(&env, eid) {
+                elections.push_back(params);
+            }
+        }
+        ElectionPage { elections, next: (end < next_eid).then_some(end) }
+    }
+
     pub fn get_tally(env: Env, eid: u64) -> Option<Vec<u32>> {
+        Self::get_tally_record(env, eid).map(|record| record.tally)
+    }
+
+    pub fn get_tally_record(env: Env, eid: u64) -> Option<TallyRecord> {
         env.storage().persistent().get(&DataKey::Tally(eid))
     }
 
+    /// Current head of the ballot-board hash chain (32 zero bytes before the
+    /// first ballot).
+    pub fn get_board_head(env: Env, eid: u64) -> BytesN<32> {
+        board_head(&env, eid)
+    }
+
+    /// Current root of the ballot Merkle tree.  Leaf i is
+    /// `leaf_hash(cast_msg)` of the i-th accepted ballot (revotes included).
+    pub fn get_ballot_root(env: Env, eid: u64) -> BytesN<32> {
+        merkle::root(&env, &ballot_tree(&env, eid))
+    }
+
+    /// Inclusion proof for the ballot at `index` against `get_ballot_root`
+    /// (verify with `merkle::verify` over the ballot's `cast_msg`).  After a
+    /// revote the proof is for the replacing ballot's leaf.
+    ///
+    /// Returns `None` when `index` is out of range, or for an election cast
+    /// before `BallotLeaf` was stored whose revotes left the tree impossible
+    /// to rebuild from the board; those proofs must be built from the
+    /// `cast`/`recast` events.
+    pub fn get_ballot_proof(env: Env, eid: u64, index: u32) -> Option<Vec<(BytesN<32>, bool)>> {
+        let ballot = load_ballot(&env, eid, index)?;
+        let target = ballot_leaf(&env, &cast_msg(&env, eid, &ballot.nf_cast, &ballot.c1, &ballot.c2));
+        let ballot_count: u32 = env
+            .storage().persistent().get(&DataKey::BallotCount(eid)).unwrap_or(0);
+        let size = ballot_tree(&env, eid).size;
+
+        let mut leaves: Vec<BytesN<32>> = Vec::new(&env);
+        let mut position = None;
+        for i in 0..size {
+            let leaf = match env.storage().persistent().get(&DataKey::BallotLeaf(eid, i)) {
+                Some(leaf) => leaf,
+                None if size == ballot_count => {
+                    let ballot = load_ballot(&env, eid, i).unwrap();
+                    ballot_leaf(&env, &cast_msg(&env, eid, &ballot.nf_cast, &ballot.c1, &ballot.c2))
+                }
+                None => return None,
+            };
+            if leaf == target {
+                position = Some(i);
+            }
+            leaves.push_back(leaf);
+        }
+        Some(merkle::prove(&env, &leaves, position?))
+    }
+
     pub fn get_next_election_id(env: Env) -> u64 {
-        env.storage().persistent().get(&DataKey::NextElectionId).unwrap_or(0)
+        env.storage().instance().get(&DataKey::NextElectionId).unwrap_or(0)
     }
 
     pub fn is_cast_nullifier_used(env: Env, eid: u64, nf: BytesN<32>) -> bool {
         env.storage().persistent().has(&DataKey::CastNullifier(eid, nf))
     }
 
+    pub fn get_casting_account(env: Env, eid: u64, pk_cast: BytesN<32>) -> Option<CastingAccount> {
+        env.storage().persistent().get(&DataKey::CastingAccount(eid, pk_cast))
+    }
+
     pub fn is_issue_nullifier_used(env: Env, eid: u64, nf: BytesN<32>) -> bool {
         env.storage().persistent().has(&DataKey::IssueNullifier(eid, nf))
     }
@@ -431,6 +2447,20 @@ impl ElectionContract {
         env.storage().persistent().get(&DataKey::EligibleRoot(eid))
     }
 
+    pub fn get_revocation_root(env: Env, eid: u64) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&DataKey::RevocationRoot(eid))
+    }
+
+    /// Registry mode: number of root changes since deploy (version 0 is the
+    /// deploy-time root).
+    pub fn get_registry_version(env: Env, eid: u64) -> Option<u32> {
+        env.storage().persistent().get(&DataKey::RegistryVersion(eid))
+    }
+
+    pub fn get_registry_root(env: Env, eid: u64, version: u32) -> Option<RegistryRoot> {
+        env.storage().persistent().get(&DataKey::RegistryRoot(eid, version))
+    }
+
     pub fn get_kh_roster(env: Env, eid: u64) -> Option<Vec<BytesN<32>>> {
         env.storage().persistent().get(&DataKey::KhRoster(eid))
     }
@@ -439,12 +2469,189 @@ impl ElectionContract {
         env.storage().persistent().get(&DataKey::KhCommitment(eid, kh_idx))
     }
 
+    pub fn get_cancellation(env: Env, eid: u64) -> Option<Cancellation> {
+        env.storage().persistent().get(&DataKey::Cancellation(eid))
+    }
+
+    pub fn get_archive(env: Env, eid: u64) -> Option<ArchivedElection> {
+        load_archive(&env, eid)
+    }
+
+    pub fn get_schedule_history(env: Env, eid: u64) -> Vec<ScheduleChange> {
+        env.storage().persistent()
+            .get(&DataKey::ScheduleHistory(eid))
+            .unwrap_or(Vec::new(&env))
+    }
+
     // ── Admin ─────────────────────────────────────────────────────────────────
 
-    /// Upgrade the contract WASM in-place.
-    /// (PoC: no auth — testnet only)
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    /// * admin           — may propose and execute upgrades and run `migrate`
+    /// * single_election — the instance holds exactly one election (eid 0),
+    ///                     which only `admin` can deploy; used by the factory.
+    ///                     Calls still take `eid`, so both kinds of instance
+    ///                     share one interface
+    pub fn __constructor(env: Env, admin: Address, single_election: bool) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+        if single_election {
+            env.storage().instance().set(&DataKey::SingleElection, &true);
+        }
+        bump_instance(&env);
+    }
+
+    /// Propose replacing the contract WASM with `new_wasm_hash`.  It can be
+    /// executed after `UPGRADE_DELAY_SECS`; a new proposal replaces the
+    /// pending one and restarts the delay.  Admin only.
+    pub fn propose_upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<PendingUpgrade, ContractError> {
+        require_admin(&env)?;
+
+        let now = env.ledger().timestamp();
+        let pending = PendingUpgrade {
+            wasm_hash: new_wasm_hash,
+            proposed_at: now,
+            executable_at: now + UPGRADE_DELAY_SECS,
+        };
+        env.storage().instance().set(&DataKey::PendingUpgrade, &pending);
+        env.storage().instance().remove(&DataKey::UpgradeCheck);
+        bump_instance(&env);
+
+        env.events().publish(
+            (symbol_short!("upg_prop"),),
+            events::UpgradeEvent {
+                version: EVENT_VERSION,
+                wasm_hash: pending.wasm_hash.clone(),
+                executable_at: pending.executable_at,
+            },
+        );
+
+        Ok(pending)
+    }
+
+    /// Withdraw the pending upgrade.  Admin only.
+    pub fn cancel_upgrade(env: Env) -> Result<(), ContractError> {
+        require_admin(&env)?;
+        let pending: PendingUpgrade = env
+            .storage().instance().get(&DataKey::PendingUpgrade)
+            .ok_or(ContractError::NoPendingUpgrade)?;
+        env.storage().instance().remove(&DataKey::PendingUpgrade);
+        env.storage().instance().remove(&DataKey::UpgradeCheck);
+
+        env.events().publish(
+            (symbol_short!("upg_cancl"),),
+            events::UpgradeEvent {
+                version: EVENT_VERSION,
+                wasm_hash: pending.wasm_hash,
+                executable_at: 0,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Swap in the pending WASM once its timelock has elapsed.  Refused while
+    /// any election is voting or awaiting its tally.  Call `migrate` right
+    /// after, so the new code can rewrite stored data.  Admin only.
+    ///
+    /// Elections are checked a page of `limit` eids at a time, starting at
+    /// cursor 0: pass the returned cursor back until the call returns `None`,
+    /// which is when the code is swapped.  If an election checked on an
+    /// earlier page has since opened for voting, the walk restarts at 0.
+    pub fn execute_upgrade(env: Env, cursor: u64, limit: u32) -> Result<Option<u64>, ContractError> {
+        require_admin(&env)?;
+        let pending: PendingUpgrade = env
+            .storage().instance().get(&DataKey::PendingUpgrade)
+            .ok_or(ContractError::NoPendingUpgrade)?;
+        let now = env.ledger().timestamp();
+        if now < pending.executable_at {
+            return Err(ContractError::UpgradeTimelocked);
+        }
+
+        let check = env
+            .storage().instance().get::<_, UpgradeCheck>(&DataKey::UpgradeCheck)
+            .filter(|check| now < check.next_start)
+            .unwrap_or(UpgradeCheck { checked: 0, next_start: u64::MAX });
+        if cursor != check.checked {
+            return Err(ContractError::InvalidCursor);
+        }
+        let next_eid: u64 = env.storage().instance().get(&DataKey::NextElectionId).unwrap_or(0);
+        let end = cursor.saturating_add(limit.clamp(1, MAX_PAGE) as u64).min(next_eid);
+        let mut next_start = check.next_start;
+        for eid in cursor..end {
+            let Ok(params) = load_election(&env, eid) else {
+                continue; // deleted or archived
+            };
+            match election_phase(&env, &params) {
+                ElectionPhase::Voting | ElectionPhase::Tallying => {
+                    return Err(ContractError::ElectionInProgress)
+                }
+                ElectionPhase::Registration => next_start = next_start.min(params.start_time),
+                ElectionPhase::Tallied | ElectionPhase::Cancelled => {}
+            }
+        }
+        if end < next_eid {
+            env.storage().instance().set(&DataKey::UpgradeCheck, &UpgradeCheck { checked: end, next_start });
+            return Ok(Some(end));
+        }
+
+        env.storage().instance().remove(&DataKey::UpgradeCheck);
+        env.storage().instance().remove(&DataKey::PendingUpgrade);
+        env.deployer().update_current_contract_wasm(pending.wasm_hash.clone());
+
+        env.events().publish(
+            (symbol_short!("upgraded"),),
+            events::UpgradeEvent {
+                version: EVENT_VERSION,
+                wasm_hash: pending.wasm_hash,
+                executable_at: 0,
+            },
+        );
+
+        Ok(None)
+    }
+
+    /// Bring stored data up to this code's `SCHEMA_VERSION`, one step at a
+    /// time.  A no-op when already current.  Returns the schema version, or
+    /// `UnknownSchema` for a stored version this code cannot migrate from.
+    ///
+    /// Admin only.  A deployment upgraded from schema 1 has no admin yet:
+    /// `admin` authorizes and becomes it, so call this right after the
+    /// upgrade.  Otherwise `admin` is ignored.
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, ContractError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            require_admin(&env)?;
+        } else {
+            admin.require_auth();
+        }
+
+        let stored: u32 = env.storage().instance().get(&DataKey::SchemaVersion).unwrap_or(1);
+        if stored > SCHEMA_VERSION {
+            return Err(ContractError::UnknownSchema);
+        }
+        for from in stored..SCHEMA_VERSION {
+            migrate_step(&env, from, &admin)?;
+        }
+        if stored != SCHEMA_VERSION {
+            env.storage().instance().set(&DataKey::SchemaVersion, &SCHEMA_VERSION);
+            env.events().publish(
+                (symbol_short!("migrated"),),
+                events::Migrated { version: EVENT_VERSION, from: stored, to: SCHEMA_VERSION },
+            );
+        }
+        bump_instance(&env);
+
+        Ok(SCHEMA_VERSION)
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Admin).unwrap()
+    }
+
+    pub fn get_pending_upgrade(env: Env) -> Option<PendingUpgrade> {
+        env.storage().instance().get(&DataKey::PendingUpgrade)
+    }
+
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::SchemaVersion).unwrap_or(1)
     }
 
     /// Delete an election and all its associated data.
@@ -453,10 +2660,17 @@ impl ElectionContract {
     ///   - the election exists
     ///   - voting window has closed (`now >= end_time`)
     ///   - the election has not been tallied
+    ///   - the election has not been cancelled (cancelled elections are retained)
+    ///   - with key-holder stakes, the tally deadline has passed; absent key
+    ///     holders are slashed and the other stakes returned first
     ///
-    /// Note: individual nullifier / casting-account ledger entries are not
-    /// removed (they will expire naturally via Soroban TTL).
-    pub fn delete_election(env: Env, eid: u64) -> Result<(), ContractError> {
+    /// Paged like `archive_election`: the first call (cursor 0) removes the
+    /// election, publishes `deleted` and frees the first page; pass the
+    /// returned `next` back until it is `None` to free the rest.
+    pub fn delete_election(env: Env, eid: u64, cursor: u32, limit: u32) -> Result<WalkPage, ContractError> {
+        if cursor > 0 {
+            return purge_page(&env, eid, cursor, limit);
+        }
         let params = load_election(&env, eid)?;
 
         let now = env.ledger().timestamp();
@@ -466,34 +2680,44 @@ impl ElectionContract {
         if params.tallied {
             return Err(ContractError::AlreadyTallied);
         }
-
-        // Ballots
-        let ballot_count: u32 = env
-            .storage().persistent().get(&DataKey::BallotCount(eid)).unwrap_or(0);
-        for i in 0..ballot_count {
-            env.storage().persistent().remove(&DataKey::Ballot(eid, i));
+        if params.status == ElectionStatus::Cancelled {
+            return Err(ContractError::ElectionCancelled);
+        }
+        if let StakePolicy::Bond(bond) = &params.config.staking {
+            if now < bond.tally_deadline {
+                return Err(ContractError::TallyDeadlineNotReached);
+            }
+            slash_stakes(&env, eid, bond);
+            return_stakes(&env, &params);
         }
-        env.storage().persistent().remove(&DataKey::BallotCount(eid));
 
-        // KH shares and per-KH commitments
-        let kh_roster: Vec<BytesN<32>> = env
-            .storage().persistent().get(&DataKey::KhRoster(eid)).unwrap();
+        let storage = env.storage().persistent();
+
+        // KH shares and per-KH commitments; chunks are freed by the walk
+        let kh_roster: Vec<BytesN<32>> = storage.get(&DataKey::KhRoster(eid)).unwrap();
         for i in 0..kh_roster.len() {
-            env.storage().persistent().remove(&DataKey::KhCommitment(eid, i));
-            env.storage().persistent().remove(&DataKey::KhShare(eid, i));
+            storage.remove(&DataKey::KhCommitment(eid, i));
+            storage.remove(&DataKey::KhShare(eid, i));
         }
-        env.storage().persistent().remove(&DataKey::ShareCount(eid));
+        storage.remove(&DataKey::ShareCount(eid));
+        storage.remove(&DataKey::ThresholdAt(eid));
+        storage.remove(&DataKey::BoardHead(eid));
 
         // Election metadata
-        env.storage().persistent().remove(&DataKey::EligibleRoot(eid));
-        env.storage().persistent().remove(&DataKey::DistRoster(eid));
-        env.storage().persistent().remove(&DataKey::DistThreshold(eid));
-        env.storage().persistent().remove(&DataKey::KhRoster(eid));
-        env.storage().persistent().remove(&DataKey::KhThreshold(eid));
-        env.storage().persistent().remove(&DataKey::Election(eid));
+        storage.remove(&DataKey::RevocationRoot(eid));
+        storage.remove(&DataKey::ScheduleHistory(eid));
+        storage.remove(&DataKey::EligibleRoot(eid));
+        storage.remove(&DataKey::DistRoster(eid));
+        storage.remove(&DataKey::DistThreshold(eid));
+        storage.remove(&DataKey::KhThreshold(eid));
+        storage.remove(&DataKey::Election(eid));
+        store(&env, &params, &DataKey::Purge(eid), &0u32);
 
-        env.events().publish((symbol_short!("deleted"), eid), ());
+        env.events().publish(
+            (symbol_short!("deleted"), eid),
+            events::Deleted { version: EVENT_VERSION },
+        );
 
-        Ok(())
+        purge_page(&env, eid, 0, limit)
     }
 }
@@ -7,6 +7,8 @@
 /// could be confused with a leaf.
 use soroban_sdk::{Bytes, BytesN, Env, Vec};
 
+use crate::types::MerkleFrontier;
+
 const LEAF_PREFIX: &[u8] = b"stellot:leaf";
 const NODE_PREFIX: &[u8] = b"stellot:node";
 
@@ -29,6 +31,7 @@ pub fn node_hash(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32>
     env.crypto().sha256(&data).into()
 }
 
+#[allow(clippy::doc_overindented_list_items)]
 /// Verify a Merkle inclusion proof.
 ///
 /// # Arguments
@@ -44,7 +47,17 @@ pub fn verify(
     leaf_bytes: &Bytes,
     proof: &Vec<(BytesN<32>, bool)>,
 ) -> bool {
-    let mut current = leaf_hash(env, leaf_bytes);
+    verify_hashed(env, root, &leaf_hash(env, leaf_bytes), proof)
+}
+
+/// Verify an inclusion proof for a leaf that is already a `leaf_hash`.
+pub fn verify_hashed(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: &BytesN<32>,
+    proof: &Vec<(BytesN<32>, bool)>,
+) -> bool {
+    let mut current = leaf.clone();
 
     for i in 0..proof.len() {
         let (sibling, is_right) = proof.get(i).unwrap();
@@ -59,3 +72,158 @@ pub fn verify(
 
     current == *root
 }
+
+// ── Multiproofs ───────────────────────────────────────────────────────────────
+//
+// One proof for several leaves of a fixed-depth tree.  Nodes the verifier can
+// compute itself (because both children are covered by the batch) are never
+// sent, so siblings shared between paths appear once.
+
+/// Verify a multiproof for `leaves` — `(index, leaf_hash)` pairs with strictly
+/// increasing indices — in a tree of `depth` levels.
+///
+/// `siblings` are the missing nodes in the order the verifier consumes them:
+/// bottom-up, and left to right within a level.  The proof must be used in
+/// full.
+pub fn verify_multi(
+    env: &Env,
+    root: &BytesN<32>,
+    depth: u32,
+    leaves: &Vec<(u32, BytesN<32>)>,
+    siblings: &Vec<BytesN<32>>,
+) -> bool {
+    if leaves.is_empty() || depth > 32 {
+        return false;
+    }
+    let mut prev: Option<u32> = None;
+    for (index, _) in leaves.iter() {
+        if (index as u64) >> depth != 0 || prev.is_some_and(|p| p >= index) {
+            return false;
+        }
+        prev = Some(index);
+    }
+
+    let mut level = leaves.clone();
+    let mut next_sibling = 0u32;
+    for _ in 0..depth {
+        let mut parents = Vec::new(env);
+        let mut i = 0u32;
+        while i < level.len() {
+            let (index, node) = level.get(i).unwrap();
+            let pair = level.get(i + 1).filter(|(j, _)| index & 1 == 0 && *j == index + 1);
+            let parent = if let Some((_, right)) = pair {
+                i += 2;
+                node_hash(env, &node, &right)
+            } else {
+                let Some(sibling) = siblings.get(next_sibling) else {
+                    return false;
+                };
+                next_sibling += 1;
+                i += 1;
+                if index & 1 == 0 {
+                    node_hash(env, &node, &sibling)
+                } else {
+                    node_hash(env, &sibling, &node)
+                }
+            };
+            parents.push_back((index >> 1, parent));
+        }
+        level = parents;
+    }
+
+    next_sibling == siblings.len() && level.get(0).unwrap().1 == *root
+}
+
+// ── Incremental append-only tree ──────────────────────────────────────────────
+//
+// Fixed-depth tree filled left to right, as in the Ethereum deposit contract.
+// Only the frontier (one node per level) is stored; empty subtrees hash to
+// `zero_hashes`, with an empty leaf being 32 zero bytes.
+
+/// Depth of the incremental tree: up to 2^20 - 1 (~1M) leaves.
+pub const TREE_DEPTH: u32 = 20;
+
+/// An empty tree: size 0 and `TREE_DEPTH` zero frontier nodes.
+pub fn empty_frontier(env: &Env) -> MerkleFrontier {
+    empty_frontier_at(env, TREE_DEPTH)
+}
+
+/// An empty tree of `depth` levels.  `append` and `root` take the depth from
+/// the frontier's length, so shallow trees can exercise the boundaries.
+pub(crate) fn empty_frontier_at(env: &Env, depth: u32) -> MerkleFrontier {
+    let mut branch = Vec::new(env);
+    for _ in 0..depth {
+        branch.push_back(BytesN::from_array(env, &[0u8; 32]));
+    }
+    MerkleFrontier { size: 0, branch }
+}
+
+/// Append `leaf` (already a `leaf_hash`).  Returns `false` if the tree is full.
+///
+/// A tree of depth d takes 2^d - 1 leaves: the frontier of a completely full
+/// tree would need a node above the root, so the last slot stays empty.
+pub fn append(env: &Env, frontier: &mut MerkleFrontier, leaf: &BytesN<32>) -> bool {
+    let depth = frontier.branch.len();
+    if frontier.size as u64 >= (1u64 << depth) - 1 {
+        return false;
+    }
+    frontier.size += 1;
+    let mut node = leaf.clone();
+    let mut s = frontier.size;
+    for h in 0..depth {
+        if s & 1 == 1 {
+            frontier.branch.set(h, node);
+            break;
+        }
+        node = node_hash(env, &frontier.branch.get(h).unwrap(), &node);
+        s >>= 1;
+    }
+    true
+}
+
+/// Root of the tree described by `frontier`.
+pub fn root(env: &Env, frontier: &MerkleFrontier) -> BytesN<32> {
+    let mut node = BytesN::from_array(env, &[0u8; 32]);
+    let mut zero = node.clone();
+    let mut s = frontier.size;
+    for h in 0..frontier.branch.len() {
+        node = if s & 1 == 1 {
+            node_hash(env, &frontier.branch.get(h).unwrap(), &node)
+        } else {
+            node_hash(env, &node, &zero)
+        };
+        zero = node_hash(env, &zero, &zero);
+        s >>= 1;
+    }
+    node
+}
+
+/// Inclusion proof for `leaves[index]` in the incremental tree built from
+/// `leaves` (each already a `leaf_hash`), in the bottom-up
+/// `(sibling, is_right_sibling)` format consumed by `verify`.
+pub fn prove(env: &Env, leaves: &Vec<BytesN<32>>, index: u32) -> Vec<(BytesN<32>, bool)> {
+    let mut proof = Vec::new(env);
+    let mut layer = leaves.clone();
+    let mut zero = BytesN::from_array(env, &[0u8; 32]);
+    let mut idx = index;
+    for _ in 0..TREE_DEPTH {
+        let is_right = idx & 1 == 0;
+        let sibling_idx = if is_right { idx + 1 } else { idx - 1 };
+        let sibling = layer.get(sibling_idx).unwrap_or(zero.clone());
+        proof.push_back((sibling, is_right));
+
+        let mut next = Vec::new(env);
+        let mut i = 0;
+        while i < layer.len() {
+            let left = layer.get(i).unwrap();
+            let right = layer.get(i + 1).unwrap_or(zero.clone());
+            next.push_back(node_hash(env, &left, &right));
+            i += 2;
+        }
+        layer = next;
+        zero = node_hash(env, &zero, &zero);
+        idx /= 2;
+    }
+    proof
+}
+
@@ -0,0 +1,1373 @@
+/// SHA256 sparse Merkle tree over 256-bit keys (no_std compatible).
+///
+/// A key's position is given by the bits of SHA256(key_bytes), most
+/// significant bit first from the root.  The leaf at a member's position is
+/// `leaf_hash(key_bytes)`; every other leaf is `EMPTY_LEAF` (32 zero bytes).
+/// Internal nodes use `node_hash`, so the `stellot:leaf` / `stellot:node`
+/// domain separation of the dense tree in `merkle.rs` carries over.
+///
+/// An empty subtree of height h hashes to E_h, with E_0 = EMPTY_LEAF and
+/// E_{h+1} = node_hash(E_h, E_h).  Proofs are compressed: bit h of
+/// `SmtProof::bitmap` (bottom-up, bit 0 = LSB of the last byte) is set when
+/// the sibling at height h differs from E_h, in which case it is the next
+/// entry of `SmtProof::siblings`.
+use soroban_sdk::{Bytes, BytesN, Env};
+
+use crate::merkle::{leaf_hash, node_hash};
+use crate::types::SmtProof;
+
+pub const SMT_DEPTH: u32 = 256;
+
+/// Value of an unoccupied leaf.
+pub const EMPTY_LEAF: [u8; 32] = [0u8; 32];
+
+/// `EMPTY_SUBTREES[h]` = E_h for h = 0..=256, precomputed so a proof check
+/// hashes only along the key's path.
+pub const EMPTY_SUBTREES: [[u8; 32]; SMT_DEPTH as usize + 1] = [
+    // E_0
+    [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ],
+    // E_1
+    [
+        0x1b, 0x19, 0x09, 0x4f, 0xfb, 0x9e, 0xfa, 0x26, 0x49, 0x43, 0x12, 0xd5, 0xa3, 0xfe, 0xa5, 0x10,
+        0x76, 0xf2, 0x96, 0x96, 0xcf, 0x17, 0x55, 0x74, 0xdc, 0x54, 0x5e, 0xe8, 0x8a, 0xcd, 0x40, 0xd5,
+    ],
+    // E_2
+    [
+        0x36, 0x83, 0xf5, 0x2a, 0xc2, 0xab, 0x47, 0xd4, 0xab, 0xac, 0xa4, 0x29, 0x4c, 0x87, 0xa1, 0x28,
+        0x0c, 0xec, 0xe6, 0x3b, 0xf6, 0x1a, 0x60, 0xbf, 0x17, 0x49, 0x36, 0x4e, 0x4b, 0x6b, 0x8d, 0xca,
+    ],
+    // E_3
+    [
+        0xb6, 0xd0, 0x40, 0xa5, 0x6e, 0xa4, 0x59, 0x98, 0x21, 0x9f, 0xc4, 0xfc, 0x2a, 0x28, 0xcc, 0xd1,
+        0xf1, 0x4d, 0x1f, 0xdc, 0x34, 0x5c, 0x08, 0x70, 0x0d, 0x2f, 0x4e, 0x78, 0xc1, 0x7f, 0x10, 0x37,
+    ],
+    // E_4
+    [
+        0x9f, 0x62, 0xbf, 0x00, 0x21, 0xbe, 0xdb, 0x94, 0xc0, 0xa7, 0x1d, 0xaf, 0xd8, 0x59, 0xdd, 0xcd,
+        0x91, 0xbe, 0xe9, 0x62, 0xf8, 0xfb, 0x30, 0xd7, 0x12, 0xa2, 0x7c, 0x3a, 0x18, 0x1d, 0xd3, 0x5e,
+    ],
+    // E_5
+    [
+        0x52, 0xc5, 0x05, 0xdd, 0x16, 0xb4, 0x73, 0x3f, 0x9c, 0xa6, 0xdf, 0x4f, 0x15, 0x69, 0xe1, 0x2b,
+        0x59, 0xfa, 0x55, 0x4a, 0xee, 0x4d, 0xee, 0x6a, 0x68, 0x60, 0xf1, 0xb7, 0x48, 0x6c, 0xf8, 0x01,
+    ],
+    // E_6
+    [
+        0x17, 0x5d, 0x59, 0x7c, 0x79, 0xeb, 0x1d, 0x80, 0x10, 0xa1, 0x49, 0x69, 0xa3, 0x51, 0xd0, 0x66,
+        0xac, 0xcf, 0x5c, 0x56, 0xe5, 0x1e, 0x45, 0xec, 0x33, 0xe1, 0xaf, 0x0a, 0xa6, 0x7d, 0x48, 0xe0,
+    ],
+    // E_7
+    [
+        0xbc, 0x99, 0x77, 0x0a, 0x37, 0x74, 0x4c, 0x8f, 0xd1, 0x03, 0xad, 0x45, 0x02, 0x94, 0x4a, 0x73,
+        0xcd, 0x2b, 0x33, 0x4e, 0x20, 0x74, 0x65, 0xd7, 0x83, 0xd1, 0x14, 0x52, 0xf5, 0xd5, 0x56, 0x6c,
+    ],
+    // E_8
+    [
+        0xbd, 0xce, 0x44, 0x9d, 0xba, 0x0e, 0x16, 0x44, 0x4d, 0xb2, 0xc6, 0x4f, 0x95, 0xf8, 0x45, 0x20,
+        0x6a, 0xe2, 0xe1, 0x67, 0x11, 0xb5, 0x30, 0x40, 0x05, 0x1b, 0xdf, 0x26, 0x03, 0x37, 0x5b, 0xaf,
+    ],
+    // E_9
+    [
+        0x3c, 0x2d, 0x62, 0x33, 0x31, 0x49, 0xaf, 0x5f, 0x02, 0x0d, 0x24, 0xce, 0xa1, 0x0d, 0xb7, 0x9d,
+        0x70, 0xe0, 0xd8, 0x5d, 0x9e, 0xee, 0x3b, 0x1d, 0xc4, 0x0c, 0xf7, 0x58, 0x53, 0x8d, 0xd1, 0xcd,
+    ],
+    // E_10
+    [
+        0x96, 0xa5, 0x02, 0x86, 0xe6, 0xd4, 0xef, 0x96, 0x10, 0x45, 0x81, 0x6c, 0xb6, 0x00, 0x5b, 0xc1,
+        0x7a, 0xed, 0x24, 0x37, 0x2f, 0x8f, 0xb1, 0xf0, 0x99, 0x6f, 0x67, 0x54, 0x7f, 0x36, 0x1b, 0xe3,
+    ],
+    // E_11
+    [
+        0x1f, 0x88, 0xe6, 0xca, 0x16, 0x5f, 0x14, 0x22, 0x05, 0x5c, 0x31, 0x11, 0xa7, 0x28, 0xbc, 0xfa,
+        0xa1, 0xed, 0xd8, 0x5a, 0x6f, 0xae, 0xfa, 0xf6, 0x77, 0x7c, 0x42, 0xaa, 0xf1, 0xbe, 0xb3, 0xf8,
+    ],
+    // E_12
+    [
+        0x4e, 0xfa, 0x9b, 0x79, 0x35, 0xd8, 0x96, 0x57, 0xd8, 0x5c, 0xb3, 0xcd, 0xb6, 0x3e, 0x0c, 0x63,
+        0x82, 0xac, 0xaf, 0xcc, 0x6b, 0x33, 0x68, 0x04, 0x9f, 0xa0, 0x5b, 0xd9, 0xdd, 0x72, 0x1b, 0xaf,
+    ],
+    // E_13
+    [
+        0x1b, 0x6e, 0xec, 0xe1, 0x6b, 0xa3, 0xa7, 0x2a, 0x0e, 0x1d, 0xc6, 0x70, 0x6a, 0x5e, 0x66, 0x60,
+        0x70, 0x97, 0x1c, 0xec, 0x59, 0xdc, 0x48, 0xb2, 0x7f, 0x30, 0x89, 0x64, 0xc4, 0x9c, 0x6b, 0x28,
+    ],
+    // E_14
+    [
+        0x30, 0xa1, 0x10, 0x41, 0x1e, 0xf5, 0x03, 0x31, 0x55, 0x65, 0xf2, 0xf5, 0xef, 0xcd, 0x7e, 0xb4,
+        0x04, 0xe4, 0x19, 0x09, 0x56, 0xda, 0x91, 0xb3, 0x0a, 0x17, 0x50, 0xb6, 0xee, 0xea, 0x4f, 0x60,
+    ],
+    // E_15
+    [
+        0x50, 0x98, 0xd0, 0xd2, 0x99, 0xd8, 0x20, 0xa6, 0x4c, 0x27, 0x06, 0x80, 0x20, 0x95, 0x4c, 0xbc,
+        0xbb, 0x2f, 0x55, 0xa9, 0x8a, 0x6b, 0xbe, 0xfb, 0xd6, 0x80, 0x84, 0x30, 0xab, 0x35, 0x43, 0xb7,
+    ],
+    // E_16
+    [
+        0xf3, 0x5d, 0x50, 0x04, 0xb7, 0xd8, 0x08, 0x41, 0x44, 0x62, 0x23, 0x5c, 0x38, 0x6d, 0xc1, 0x1a,
+        0x7f, 0x3f, 0x2e, 0x3f, 0x25, 0x3d, 0x39, 0x0b, 0x81, 0x81, 0x37, 0xfe, 0xb5, 0x4b, 0x62, 0x9e,
+    ],
+    // E_17
+    [
+        0x59, 0x0d, 0xdb, 0x96, 0x00, 0x9b, 0x16, 0x55, 0x3f, 0xfd, 0x98, 0x64, 0xda, 0x49, 0x00, 0xd0,
+        0xb5, 0x35, 0xf4, 0x91, 0x9d, 0x3e, 0x16, 0xe7, 0xdc, 0x0b, 0xf6, 0x8d, 0x42, 0x2f, 0x08, 0x77,
+    ],
+    // E_18
+    [
+        0x65, 0xae, 0xd5, 0x77, 0x1c, 0xa5, 0xd9, 0xff, 0x7e, 0x7c, 0x2a, 0x6b, 0x3d, 0x43, 0x68, 0xb6,
+        0x29, 0x1f, 0x7f, 0xb1, 0x51, 0x04, 0x92, 0x82, 0x17, 0x1c, 0x06, 0x10, 0xac, 0xf9, 0x6a, 0xf7,
+    ],
+    // E_19
+    [
+        0x64, 0xed, 0xed, 0x00, 0xf1, 0xf8, 0xa7, 0xf2, 0x9d, 0x0f, 0x2f, 0x90, 0xcf, 0x44, 0xd3, 0xde,
+        0x8d, 0xf2, 0xb2, 0xed, 0x05, 0xdd, 0x44, 0xbb, 0x22, 0x41, 0xe2, 0x4d, 0xd1, 0x1a, 0x42, 0x20,
+    ],
+    // E_20
+    [
+        0x3f, 0x41, 0x34, 0xe5, 0x1c, 0x41, 0x00, 0x3e, 0x63, 0x51, 0x06, 0x86, 0x9e, 0xc7, 0x64, 0x7b,
+        0x06, 0xdb, 0x47, 0x89, 0x57, 0xf5, 0x0a, 0x40, 0xe1, 0x22, 0xd3, 0x20, 0x04, 0xb2, 0x46, 0x4d,
+    ],
+    // E_21
+    [
+        0xba, 0x13, 0x9b, 0x7f, 0x78, 0x91, 0x11, 0x2b, 0xbb, 0x6e, 0x5b, 0x68, 0x96, 0x67, 0xc6, 0x3c,
+        0xb9, 0x70, 0xdb, 0x18, 0x1a, 0x69, 0x03, 0xb9, 0xb1, 0x8e, 0xd6, 0x54, 0x39, 0xb9, 0x8d, 0x82,
+    ],
+    // E_22
+    [
+        0x7d, 0x3b, 0x91, 0xaa, 0x5b, 0x15, 0x00, 0x97, 0xd0, 0xf7, 0xf0, 0x0a, 0xe4, 0xd7, 0xdf, 0x20,
+        0x65, 0x9d, 0x4d, 0x0d, 0xfe, 0x06, 0x04, 0xe7, 0xc3, 0xe9, 0x35, 0x3f, 0x29, 0xc1, 0x5a, 0xbc,
+    ],
+    // E_23
+    [
+        0xf4, 0xb2, 0x98, 0xf6, 0x96, 0xf2, 0x00, 0xd5, 0x79, 0x6b, 0x63, 0x42, 0xcd, 0xe0, 0x6b, 0xd3,
+        0xc6, 0x80, 0x57, 0xc3, 0x07, 0x31, 0x3e, 0x84, 0x0e, 0x30, 0xe3, 0x22, 0xe9, 0x44, 0xb1, 0x9a,
+    ],
+    // E_24
+    [
+        0x16, 0x6e, 0xb3, 0x0c, 0x54, 0xc8, 0xa4, 0x1b, 0xb0, 0x73, 0xc9, 0xc1, 0xa7, 0x7f, 0xde, 0x29,
+        0xbe, 0xb5, 0x7a, 0x35, 0xb5, 0x0d, 0x28, 0x1b, 0xc9, 0x87, 0x0f, 0x3c, 0x24, 0x61, 0x1a, 0x8b,
+    ],
+    // E_25
+    [
+        0xc0, 0xcf, 0x81, 0x98, 0x11, 0x31, 0x73, 0x0a, 0xeb, 0x94, 0xc1, 0x3b, 0x64, 0x27, 0x46, 0x44,
+        0xc8, 0xaf, 0x41, 0x2f, 0x94, 0x8f, 0xe4, 0xa7, 0x6f, 0x2e, 0x5f, 0x1e, 0xc4, 0xdc, 0x08, 0x83,
+    ],
+    // E_26
+    [
+        0xca, 0x38, 0x1b, 0x72, 0xac, 0x1e, 0xc2, 0x07, 0x37, 0x3e, 0x67, 0x50, 0xf5, 0xfe, 0x09, 0x0f,
+        0x18, 0xf4, 0xc8, 0x9a, 0x5f, 0x83, 0x23, 0x0c, 0x32, 0xd7, 0x8d, 0x95, 0x37, 0xc3, 0x3a, 0x7c,
+    ],
+    // E_27
+    [
+        0xce, 0x8e, 0x51, 0x7e, 0xbb, 0xf1, 0x7a, 0xd1, 0xc1, 0x0f, 0x49, 0xf9, 0x42, 0xb6, 0x22, 0xad,
+        0x24, 0xfc, 0x5e, 0x22, 0x97, 0x53, 0xa7, 0xc9, 0x1c, 0x79, 0x97, 0x49, 0xe7, 0x3f, 0x9f, 0xe3,
+    ],
+    // E_28
+    [
+        0xc8, 0xcb, 0x19, 0xd6, 0xd8, 0x8f, 0x84, 0x9f, 0x10, 0xa4, 0xf2, 0x6d, 0x80, 0x9e, 0x26, 0xa7,
+        0xd6, 0x7a, 0xf1, 0xbf, 0x96, 0xdf, 0x9d, 0x17, 0x0a, 0x02, 0x7b, 0xf7, 0xc5, 0x57, 0x43, 0x56,
+    ],
+    // E_29
+    [
+        0xd9, 0xa8, 0x7b, 0x24, 0x5a, 0xf2, 0xc0, 0x2d, 0x26, 0x3c, 0x52, 0x27, 0x36, 0xcd, 0x60, 0x0a,
+        0x34, 0x85, 0xe3, 0x06, 0xca, 0x50, 0x02, 0x39, 0x44, 0x47, 0xe0, 0x5e, 0x09, 0x47, 0x4c, 0x8c,
+    ],
+    // E_30
+    [
+        0x62, 0x12, 0xe4, 0x02, 0xf2, 0xb0, 0x96, 0x0e, 0x96, 0xa4, 0xb3, 0xac, 0xc3, 0xec, 0x63, 0x58,
+        0x90, 0xdd, 0x74, 0xe2, 0x8a, 0x63, 0xa5, 0xab, 0xf6, 0x99, 0x26, 0xd8, 0xd4, 0xb0, 0xe3, 0x62,
+    ],
+    // E_31
+    [
+        0x37, 0x11, 0x62, 0x1a, 0x2e, 0xbe, 0xb4, 0x2c, 0xe3, 0x3b, 0x64, 0xf3, 0x6b, 0x82, 0xc8, 0x04,
+        0xe1, 0xc2, 0xdb, 0x1f, 0x28, 0x78, 0x5d, 0xf5, 0x5a, 0x73, 0x35, 0x30, 0x87, 0x71, 0x0f, 0xb9,
+    ],
+    // E_32
+    [
+        0x1a, 0xfe, 0x26, 0xd8, 0xdd, 0x02, 0x5e, 0x61, 0x75, 0x90, 0x59, 0x80, 0xe4, 0xa6, 0xfc, 0x7c,
+        0x9f, 0xf0, 0xd9, 0x0b, 0xd1, 0x01, 0x25, 0xc6, 0xd7, 0xc7, 0xa3, 0x71, 0xd2, 0xc4, 0x6c, 0xf4,
+    ],
+    // E_33
+    [
+        0x04, 0x5d, 0xc0, 0x8f, 0x88, 0x07, 0x12, 0x2a, 0x4a, 0x12, 0x1e, 0x5c, 0x18, 0xcd, 0x57, 0x89,
+        0x25, 0x22, 0x51, 0xfe, 0x58, 0x4f, 0xc1, 0x18, 0x49, 0x03, 0x57, 0xc3, 0x42, 0xa6, 0x9e, 0xba,
+    ],
+    // E_34
+    [
+        0x88, 0xce, 0xca, 0xf3, 0x91, 0x3c, 0x6a, 0xac, 0x68, 0xd3, 0xe8, 0x6d, 0xe6, 0x4e, 0x56, 0x17,
+        0x7d, 0x4a, 0x50, 0x98, 0x94, 0x3c, 0xf1, 0x61, 0xf9, 0x0b, 0x55, 0x2a, 0x61, 0xa6, 0xe5, 0xaf,
+    ],
+    // E_35
+    [
+        0x66, 0x0f, 0x73, 0xbe, 0xa0, 0x1a, 0x3c, 0x06, 0x58, 0x87, 0xbe, 0x5d, 0xcd, 0x92, 0x38, 0x6c,
+        0x9f, 0x28, 0x93, 0xbd, 0x45, 0xf4, 0xd3, 0xfd, 0xd6, 0x75, 0xcf, 0x80, 0x5c, 0xe4, 0x5d, 0x40,
+    ],
+    // E_36
+    [
+        0xdd, 0xda, 0x1b, 0x56, 0x5f, 0xed, 0xdd, 0x1c, 0xd7, 0x36, 0x1c, 0xff, 0xed, 0x08, 0x28, 0x77,
+        0x89, 0x9a, 0xc4, 0xc4, 0x68, 0xc2, 0x5a, 0xf9, 0x49, 0xc4, 0xf7, 0xfe, 0x20, 0x7c, 0x62, 0xe7,
+    ],
+    // E_37
+    [
+        0xeb, 0xd6, 0x6c, 0x9c, 0x16, 0x8e, 0xb3, 0x9b, 0x50, 0x48, 0xca, 0xa9, 0xcb, 0x03, 0x13, 0x3d,
+        0xb1, 0xdb, 0x28, 0xf4, 0x65, 0xb9, 0x7e, 0x17, 0x65, 0x01, 0xf3, 0x06, 0xb5, 0xe7, 0xb0, 0x7f,
+    ],
+    // E_38
+    [
+        0x73, 0xb8, 0x5b, 0x4d, 0x7b, 0xfc, 0xd8, 0x9d, 0x6b, 0x84, 0x79, 0x19, 0x61, 0xd5, 0xe9, 0xae,
+        0x04, 0x5f, 0x08, 0xe9, 0x96, 0x53, 0xab, 0x51, 0xeb, 0xbd, 0x3b, 0x98, 0x86, 0x4d, 0x74, 0x7d,
+    ],
+    // E_39
+    [
+        0xed, 0xa9, 0x92, 0xf1, 0xb0, 0xa9, 0x4d, 0x5a, 0x7e, 0x8c, 0x9a, 0x9d, 0xd5, 0x91, 0xb5, 0x4e,
+        0x15, 0xc9, 0x0f, 0xec, 0xa4, 0x9e, 0xe0, 0x4e, 0x00, 0xcd, 0x0c, 0xd4, 0x41, 0x25, 0xff, 0x89,
+    ],
+    // E_40
+    [
+        0xc1, 0xf1, 0xae, 0xd7, 0x37, 0xb2, 0xef, 0x11, 0xf5, 0x08, 0xdb, 0xd3, 0xd1, 0xdb, 0x4a, 0x6b,
+        0x35, 0x03, 0x00, 0x6c, 0xf0, 0x9d, 0xd6, 0xd5, 0xb8, 0x3c, 0xaf, 0xd0, 0xbc, 0xf1, 0x2e, 0x8b,
+    ],
+    // E_41
+    [
+        0x5c, 0x46, 0x4c, 0xc0, 0x8f, 0xf0, 0xa8, 0x61, 0x77, 0x7b, 0x94, 0xf9, 0x99, 0xf0, 0x91, 0xda,
+        0x0b, 0x4c, 0x5b, 0xb6, 0x10, 0x3c, 0xf3, 0x5e, 0x52, 0xdd, 0x30, 0xbe, 0xbc, 0xda, 0x02, 0x06,
+    ],
+    // E_42
+    [
+        0x1b, 0x4c, 0xe0, 0xf2, 0x0e, 0x46, 0xe8, 0x6e, 0xd6, 0x13, 0x96, 0x0d, 0x51, 0x04, 0x80, 0x42,
+        0x55, 0x80, 0x02, 0xd0, 0x21, 0x9c, 0x0a, 0x46, 0x1a, 0x89, 0xc7, 0x3d, 0x0c, 0xbd, 0xea, 0x59,
+    ],
+    // E_43
+    [
+        0xe5, 0xcb, 0x7b, 0x76, 0x4c, 0x82, 0xe8, 0xd0, 0x44, 0x2b, 0x61, 0x63, 0x8a, 0x60, 0x18, 0x35,
+        0x88, 0xe0, 0xf7, 0x10, 0x6e, 0x57, 0x88, 0xdf, 0x9c, 0x76, 0xad, 0x43, 0x83, 0x01, 0x2e, 0x3a,
+    ],
+    // E_44
+    [
+        0x1d, 0xca, 0xb0, 0xcd, 0xb9, 0x41, 0x0f, 0x92, 0x72, 0x4c, 0x30, 0xeb, 0x6a, 0xe0, 0xb4, 0x24,
+        0x18, 0xd0, 0x9f, 0xb7, 0x9c, 0x06, 0xb8, 0x09, 0x22, 0xf1, 0x2d, 0x80, 0xad, 0xc4, 0xe7, 0x25,
+    ],
+    // E_45
+    [
+        0xa5, 0xd8, 0x08, 0xfc, 0x3a, 0xc3, 0xdd, 0xb9, 0x51, 0xd2, 0x13, 0xd3, 0x28, 0x6e, 0x04, 0x94,
+        0x5b, 0x93, 0xef, 0x12, 0xb0, 0xba, 0xa3, 0x78, 0x2b, 0x10, 0xca, 0x12, 0xf3, 0x8d, 0xfe, 0x16,
+    ],
+    // E_46
+    [
+        0x86, 0xcf, 0x04, 0x70, 0x9d, 0x62, 0xa4, 0x58, 0x2a, 0x04, 0x4b, 0xdc, 0x43, 0x45, 0x7c, 0x42,
+        0xff, 0xec, 0xc7, 0x58, 0x30, 0xdd, 0x2c, 0x3a, 0x6f, 0x1b, 0x1b, 0x54, 0xb5, 0x57, 0x4b, 0xdf,
+    ],
+    // E_47
+    [
+        0xe4, 0xad, 0x8a, 0x4e, 0xee, 0x3f, 0xf3, 0xd5, 0x19, 0x45, 0xf3, 0x26, 0xd9, 0x16, 0x53, 0xe7,
+        0xdd, 0x21, 0xa6, 0x00, 0x48, 0x99, 0xf4, 0x09, 0x13, 0x29, 0x81, 0x4d, 0x47, 0xfe, 0x15, 0x34,
+    ],
+    // E_48
+    [
+        0x8d, 0x6e, 0x53, 0xb7, 0xeb, 0xf7, 0x80, 0x8b, 0xf9, 0x15, 0xfe, 0x3e, 0x8b, 0x19, 0x5b, 0x0b,
+        0x63, 0x13, 0xc2, 0x63, 0x5b, 0xb8, 0x42, 0xcf, 0x30, 0xcd, 0x79, 0xcb, 0x84, 0x06, 0x62, 0xc3,
+    ],
+    // E_49
+    [
+        0x91, 0x74, 0x87, 0x7d, 0x9d, 0xe0, 0x85, 0x00, 0x01, 0x1d, 0x47, 0xeb, 0x98, 0x26, 0x8a, 0xe6,
+        0x82, 0x57, 0xc6, 0x0b, 0x03, 0xd9, 0xfe, 0x8c, 0x45, 0x40, 0x65, 0xba, 0xa7, 0xd1, 0xe3, 0x42,
+    ],
+    // E_50
+    [
+        0xef, 0xda, 0xbc, 0x46, 0xc3, 0xf1, 0x74, 0x20, 0x47, 0x0e, 0x7e, 0xbc, 0xa4, 0x7e, 0xd8, 0x84,
+        0x9f, 0x4b, 0x91, 0x6e, 0x38, 0x2e, 0x78, 0x56, 0xdd, 0x4f, 0xdb, 0x5d, 0x13, 0x50, 0x81, 0x04,
+    ],
+    // E_51
+    [
+        0xa2, 0x8f, 0xe5, 0x2f, 0x25, 0x4c, 0x88, 0xfc, 0x46, 0xa8, 0xbd, 0xc8, 0x0f, 0x29, 0xa7, 0xd4,
+        0x19, 0xb2, 0x80, 0xc8, 0xe4, 0xad, 0xf7, 0xe6, 0xd9, 0x81, 0x62, 0x90, 0x57, 0x14, 0x22, 0xc8,
+    ],
+    // E_52
+    [
+        0x25, 0xee, 0xb5, 0x78, 0xd0, 0x8e, 0xba, 0xa0, 0x32, 0xd0, 0x6e, 0x71, 0xa1, 0xf2, 0x47, 0x10,
+        0x8c, 0xf3, 0xcd, 0x1a, 0x1e, 0x72, 0x0e, 0x3a, 0xa2, 0xbb, 0x7b, 0x3b, 0x30, 0x35, 0xaa, 0x3b,
+    ],
+    // E_53
+    [
+        0x50, 0xd7, 0x5d, 0x68, 0xbe, 0x97, 0x42, 0x91, 0x25, 0xde, 0x57, 0x5d, 0xc1, 0xa6, 0xc5, 0xb3,
+        0xe1, 0xcb, 0x31, 0xb5, 0xf2, 0x67, 0x98, 0xb2, 0x02, 0x10, 0xa2, 0xaf, 0x76, 0x1a, 0x65, 0x63,
+    ],
+    // E_54
+    [
+        0xa8, 0x9d, 0x0a, 0x79, 0x84, 0x60, 0x94, 0x68, 0x65, 0x1e, 0xbf, 0x2f, 0x9e, 0x58, 0x3f, 0xb2,
+        0xa9, 0xe9, 0x37, 0x87, 0x1f, 0x2c, 0x5a, 0xc3, 0x7c, 0x81, 0x8c, 0x60, 0xaf, 0x23, 0x65, 0x8c,
+    ],
+    // E_55
+    [
+        0x67, 0xe0, 0xbf, 0xa0, 0xe2, 0xaa, 0x18, 0x84, 0x14, 0xb4, 0x32, 0xbc, 0xf8, 0xe0, 0x7a, 0x21,
+        0xf4, 0x65, 0x24, 0x0c, 0xad, 0xe2, 0x6c, 0x3c, 0x75, 0x72, 0xb6, 0x9a, 0x1a, 0xd6, 0xab, 0x5f,
+    ],
+    // E_56
+    [
+        0x68, 0x38, 0xf2, 0x49, 0xa4, 0xee, 0x95, 0xf8, 0xc9, 0x0f, 0x0c, 0x8c, 0x3e, 0x49, 0x82, 0x5b,
+        0x53, 0xd7, 0x98, 0x6c, 0x67, 0x4c, 0x61, 0x75, 0x5f, 0x8c, 0xcd, 0x0e, 0x3d, 0xc0, 0x9e, 0xd1,
+    ],
+    // E_57
+    [
+        0xe3, 0x35, 0x7e, 0x8d, 0x38, 0xfa, 0x11, 0x1b, 0x73, 0xd3, 0xa2, 0x9b, 0xb7, 0x7e, 0x37, 0xa0,
+        0x5d, 0xe6, 0xd4, 0x7f, 0xc8, 0x4e, 0x90, 0xae, 0x6d, 0x97, 0x3d, 0x6d, 0x60, 0x52, 0x7b, 0x20,
+    ],
+    // E_58
+    [
+        0x5b, 0x48, 0x90, 0x25, 0x14, 0x0e, 0xf3, 0x20, 0xbb, 0x58, 0x91, 0xf0, 0x4c, 0xc1, 0x20, 0x7c,
+        0x5b, 0xb9, 0x2b, 0xa7, 0xfb, 0x98, 0x04, 0x7e, 0x91, 0xfd, 0xfd, 0x89, 0x77, 0x02, 0x63, 0x65,
+    ],
+    // E_59
+    [
+        0x07, 0x0e, 0x85, 0x3b, 0x91, 0xb9, 0x57, 0x55, 0x4a, 0xb6, 0x1e, 0x32, 0xbc, 0x39, 0xb1, 0x76,
+        0xcf, 0x84, 0x59, 0x08, 0xa0, 0xe7, 0x0b, 0x39, 0x45, 0xd3, 0x15, 0x98, 0xbd, 0x1a, 0xeb, 0xa5,
+    ],
+    // E_60
+    [
+        0x74, 0xf8, 0x53, 0xa6, 0xc4, 0xd1, 0xda, 0x85, 0xaa, 0x58, 0xc2, 0xc5, 0x38, 0x0d, 0x73, 0x65,
+        0xc1, 0x41, 0x68, 0xac, 0xb9, 0x89, 0x8b, 0xff, 0x3b, 0xa8, 0x08, 0xcb, 0xdb, 0x14, 0x66, 0x16,
+    ],
+    // E_61
+    [
+        0xc6, 0x42, 0x9c, 0xb2, 0xdb, 0xa7, 0x36, 0x32, 0x3e, 0xd5, 0xf9, 0x98, 0xe7, 0xca, 0x5b, 0xcf,
+        0x10, 0x1a, 0x37, 0xc9, 0x82, 0xde, 0x42, 0xc2, 0x83, 0x03, 0x96, 0xb2, 0x87, 0x9f, 0xe0, 0x44,
+    ],
+    // E_62
+    [
+        0xbd, 0xfb, 0x4e, 0x44, 0xc2, 0xf8, 0x64, 0x30, 0x87, 0xa1, 0xf2, 0xe7, 0xa8, 0x43, 0x3e, 0x8d,
+        0xda, 0xdb, 0xfb, 0xae, 0x82, 0x3f, 0x1a, 0x3d, 0xde, 0x44, 0x72, 0x04, 0xbc, 0xda, 0x86, 0xf9,
+    ],
+    // E_63
+    [
+        0xa7, 0x97, 0x54, 0xba, 0x58, 0xfa, 0x1d, 0xf6, 0xfd, 0x7f, 0x5d, 0x9b, 0xaa, 0xc0, 0x46, 0x63,
+        0x45, 0x01, 0x22, 0x3d, 0x24, 0xc2, 0xc3, 0xd7, 0x3f, 0x89, 0x35, 0xc4, 0xb2, 0xf4, 0x01, 0x24,
+    ],
+    // E_64
+    [
+        0x6e, 0x57, 0x67, 0xa5, 0x4e, 0xef, 0x4a, 0x62, 0xb1, 0x4b, 0xd0, 0xca, 0x29, 0xbe, 0xac, 0xe9,
+        0xbc, 0xbf, 0x47, 0x5e, 0xbf, 0xc2, 0x85, 0x4b, 0x6d, 0x90, 0x7a, 0xd1, 0x99, 0x6d, 0x20, 0xa6,
+    ],
+    // E_65
+    [
+        0xa4, 0x26, 0x0a, 0xfe, 0x0a, 0x0e, 0xba, 0x4b, 0xe4, 0x7e, 0x27, 0xb8, 0x33, 0x84, 0xe1, 0x0d,
+        0x12, 0xdb, 0x5a, 0x37, 0x05, 0x18, 0x4d, 0xac, 0x8d, 0x1f, 0xfd, 0x79, 0xa5, 0xd0, 0xdf, 0x12,
+    ],
+    // E_66
+    [
+        0x98, 0x5b, 0x76, 0x1a, 0x76, 0x0b, 0x69, 0xf8, 0x2d, 0x6a, 0x7f, 0x4a, 0xf4, 0xda, 0x94, 0x5d,
+        0xb7, 0x6e, 0x39, 0xf3, 0xbe, 0x6a, 0xd9, 0x7c, 0xed, 0xa0, 0x80, 0x44, 0x39, 0x4b, 0xf2, 0x95,
+    ],
+    // E_67
+    [
+        0x81, 0x9c, 0xbe, 0x98, 0x3f, 0x2e, 0xbb, 0xa9, 0xb9, 0x36, 0x5e, 0x81, 0x60, 0xec, 0x4d, 0x3b,
+        0xd9, 0x7b, 0xb0, 0xb4, 0x7a, 0x1a, 0x57, 0xe7, 0xa8, 0x20, 0x9f, 0x9c, 0xd1, 0x48, 0x46, 0xbf,
+    ],
+    // E_68
+    [
+        0x41, 0xb8, 0x3f, 0xbe, 0x96, 0x81, 0x8e, 0xba, 0xf6, 0x9a, 0x79, 0x84, 0xff, 0x47, 0xde, 0x93,
+        0xf0, 0x31, 0x68, 0xb9, 0xc7, 0xd8, 0x7c, 0xc1, 0x7b, 0x48, 0x31, 0x2a, 0x5a, 0x6f, 0x54, 0x50,
+    ],
+    // E_69
+    [
+        0x65, 0xbd, 0x4b, 0xec, 0xf8, 0xaa, 0x57, 0x92, 0x37, 0xa7, 0x28, 0x83, 0x43, 0xcb, 0x86, 0xf5,
+        0x9e, 0x07, 0x16, 0x79, 0x2b, 0x5c, 0x60, 0x81, 0xc8, 0x54, 0x78, 0x50, 0xc0, 0xde, 0xb6, 0xb1,
+    ],
+    // E_70
+    [
+        0x5c, 0xe4, 0x29, 0x30, 0xa9, 0x43, 0xb4, 0x83, 0xc4, 0x90, 0x5a, 0x21, 0x6e, 0xe9, 0x81, 0xc7,
+        0xde, 0x2a, 0x7e, 0x47, 0xbb, 0xff, 0x07, 0x4b, 0xa1, 0x37, 0x7b, 0x0b, 0x55, 0x32, 0x73, 0xa9,
+    ],
+    // E_71
+    [
+        0xa2, 0x29, 0xd1, 0x55, 0xfc, 0x46, 0xb5, 0x2b, 0xf8, 0x19, 0xda, 0xdc, 0x74, 0x96, 0x29, 0x3e,
+        0x98, 0x6a, 0xdc, 0x5f, 0x41, 0xe7, 0xa5, 0xeb, 0x9d, 0x28, 0x51, 0xfa, 0xb6, 0x20, 0x16, 0x10,
+    ],
+    // E_72
+    [
+        0x2d, 0x26, 0xf8, 0xf5, 0x5a, 0xa0, 0x66, 0xae, 0xea, 0x30, 0x4f, 0xf8, 0x7d, 0xc9, 0x06, 0xaa,
+        0xdc, 0x27, 0x96, 0x77, 0xa5, 0x0c, 0x72, 0xd2, 0x0a, 0xed, 0xe4, 0x26, 0x9d, 0x92, 0xfd, 0x5d,
+    ],
+    // E_73
+    [
+        0xb8, 0x01, 0xd7, 0x86, 0x4f, 0x78, 0x45, 0x8f, 0x76, 0xaa, 0xfe, 0xf6, 0xfb, 0x2c, 0xa1, 0x26,
+        0xf3, 0x75, 0xa5, 0x90, 0xad, 0x59, 0x04, 0x29, 0x49, 0x29, 0x83, 0xb1, 0x60, 0xd5, 0x92, 0x3f,
+    ],
+    // E_74
+    [
+        0xf3, 0xd0, 0x4e, 0xc8, 0xfd, 0x22, 0xe4, 0xe7, 0x28, 0xa5, 0x5e, 0x32, 0x51, 0x90, 0x28, 0x53,
+        0x3d, 0xff, 0xfa, 0xfb, 0x73, 0x03, 0xde, 0xcd, 0x6a, 0x49, 0x68, 0x9d, 0x23, 0x52, 0x99, 0x01,
+    ],
+    // E_75
+    [
+        0x13, 0xd6, 0x7c, 0x2a, 0x58, 0xfe, 0xbb, 0xaa, 0x85, 0xce, 0xe1, 0xf7, 0x15, 0xe3, 0x1e, 0xb3,
+        0x20, 0x5b, 0xd2, 0xff, 0x69, 0x9e, 0x83, 0x05, 0x9f, 0xc7, 0x74, 0xbf, 0xbd, 0xbf, 0xd9, 0x81,
+    ],
+    // E_76
+    [
+        0x58, 0x88, 0xd5, 0xf7, 0xed, 0xc0, 0x4f, 0x60, 0xda, 0x9e, 0x06, 0xdc, 0x6d, 0x44, 0xd3, 0x84,
+        0x60, 0xd3, 0x99, 0xae, 0xe9, 0x34, 0x54, 0x04, 0x35, 0x75, 0x83, 0x83, 0x34, 0x32, 0x13, 0x13,
+    ],
+    // E_77
+    [
+        0x82, 0xb8, 0x1e, 0x18, 0xb6, 0xf0, 0xdd, 0x95, 0x55, 0xbb, 0xbe, 0x5d, 0x5c, 0x0b, 0x3a, 0x83,
+        0x7b, 0xcc, 0x11, 0x79, 0xbd, 0x66, 0xea, 0xc2, 0xc4, 0xa3, 0x17, 0x39, 0x9a, 0x13, 0x4a, 0xf9,
+    ],
+    // E_78
+    [
+        0xd0, 0xb5, 0x3f, 0x81, 0x1f, 0x16, 0x9a, 0xa4, 0xee, 0xf4, 0x61, 0x77, 0xf8, 0xae, 0x98, 0xc7,
+        0x34, 0xd6, 0x6f, 0x54, 0x9f, 0xb6, 0x13, 0xf3, 0xb7, 0x0d, 0xa3, 0xda, 0x77, 0xa9, 0x18, 0x9f,
+    ],
+    // E_79
+    [
+        0x1c, 0x11, 0xc8, 0x1d, 0xa6, 0xaf, 0xeb, 0x6f, 0xed, 0x18, 0x3b, 0x01, 0x2f, 0x09, 0xf1, 0x01,
+        0x0f, 0xc9, 0x3a, 0x36, 0xd5, 0x58, 0xd0, 0x7d, 0x3d, 0xa1, 0xaf, 0xfc, 0xee, 0xa4, 0xec, 0xdd,
+    ],
+    // E_80
+    [
+        0xa8, 0xb2, 0x9d, 0x90, 0x03, 0x42, 0x20, 0xd6, 0xb4, 0x21, 0x2f, 0xd0, 0xda, 0xab, 0x54, 0x7f,
+        0x74, 0x6f, 0x9f, 0xf2, 0xc8, 0xf7, 0x63, 0x42, 0xfa, 0x84, 0x7a, 0xbf, 0xa2, 0xac, 0x71, 0xda,
+    ],
+    // E_81
+    [
+        0x02, 0x25, 0x1f, 0xbe, 0x4b, 0xc0, 0xa3, 0x06, 0xb5, 0x5f, 0x47, 0x65, 0xf0, 0x19, 0xff, 0xc3,
+        0xee, 0x7a, 0x10, 0xbb, 0x4d, 0x10, 0x91, 0x49, 0xa5, 0x20, 0x5b, 0x6e, 0x16, 0x35, 0xfd, 0x32,
+    ],
+    // E_82
+    [
+        0x5f, 0x73, 0xb3, 0xdc, 0x01, 0x24, 0x22, 0x92, 0x3e, 0x4f, 0x11, 0x73, 0x2d, 0x36, 0x6d, 0x13,
+        0x7b, 0x60, 0x25, 0x51, 0x49, 0xfe, 0x6a, 0x79, 0xe6, 0x19, 0xb7, 0xb3, 0x4c, 0xe4, 0x13, 0x94,
+    ],
+    // E_83
+    [
+        0x25, 0x65, 0x85, 0x85, 0xf7, 0xfd, 0xd3, 0xb5, 0x1e, 0xfe, 0xba, 0x1e, 0xa6, 0xf8, 0x1d, 0xeb,
+        0x21, 0x44, 0x92, 0x39, 0x33, 0xc1, 0x47, 0xfe, 0xbe, 0xf1, 0xe0, 0x70, 0xd1, 0xe6, 0xb2, 0x36,
+    ],
+    // E_84
+    [
+        0x6c, 0xdc, 0x27, 0x20, 0x3a, 0x49, 0x50, 0x9b, 0x64, 0x03, 0x45, 0x31, 0xdb, 0x59, 0x06, 0xc5,
+        0xf5, 0x63, 0xfe, 0x24, 0x39, 0x25, 0xdc, 0xa1, 0x9a, 0x12, 0x3c, 0x8f, 0x40, 0xb4, 0x25, 0x5b,
+    ],
+    // E_85
+    [
+        0xc0, 0x5a, 0xd0, 0x23, 0x38, 0x50, 0x7e, 0x33, 0xf9, 0x81, 0xe0, 0xb6, 0x0b, 0x28, 0xaf, 0xc5,
+        0x17, 0x76, 0xec, 0x4b, 0x3a, 0x0c, 0x01, 0xd2, 0xed, 0x89, 0x28, 0x9b, 0x62, 0x3b, 0xf5, 0xea,
+    ],
+    // E_86
+    [
+        0xfb, 0x74, 0x84, 0x5c, 0xee, 0xbc, 0x9c, 0x64, 0x5e, 0x54, 0xbe, 0x49, 0x6d, 0x1c, 0x4a, 0x56,
+        0xa2, 0xcd, 0x80, 0x8c, 0x0b, 0x7f, 0x4f, 0xc8, 0x2e, 0xae, 0x85, 0x67, 0xad, 0x3c, 0x3f, 0xc5,
+    ],
+    // E_87
+    [
+        0x4b, 0x56, 0x1d, 0x41, 0xc2, 0xc4, 0xb8, 0xb6, 0xf5, 0x3a, 0xaf, 0x52, 0x8d, 0x6b, 0xb1, 0x88,
+        0x84, 0x9c, 0xc6, 0xff, 0x1d, 0x87, 0xe8, 0x6d, 0x3a, 0x9d, 0x45, 0xca, 0xfa, 0xaa, 0x81, 0xa8,
+    ],
+    // E_88
+    [
+        0xa4, 0x23, 0x77, 0x68, 0x8d, 0x36, 0x7c, 0x9f, 0x99, 0x83, 0x6a, 0xbc, 0x9f, 0x0f, 0x06, 0xcc,
+        0x0f, 0x38, 0x29, 0x53, 0xe5, 0x5d, 0x08, 0x4c, 0x53, 0x77, 0x16, 0x93, 0xcc, 0x27, 0xe7, 0xb0,
+    ],
+    // E_89
+    [
+        0x03, 0xc2, 0xab, 0x40, 0xe9, 0x1c, 0xaf, 0xcc, 0xc6, 0xc7, 0x54, 0x89, 0x4b, 0x85, 0x3d, 0x01,
+        0x63, 0xe6, 0x0c, 0xa0, 0xd5, 0x22, 0x8a, 0x4b, 0x90, 0x1a, 0xcd, 0x4c, 0xef, 0x32, 0x44, 0xaa,
+    ],
+    // E_90
+    [
+        0xee, 0x0e, 0xf2, 0xc4, 0xe5, 0x21, 0x7e, 0xa5, 0x75, 0x7f, 0xbb, 0x3c, 0x7a, 0x38, 0xab, 0x5b,
+        0xd6, 0x17, 0xda, 0xbd, 0xc0, 0x65, 0x75, 0x0f, 0xc2, 0xad, 0x30, 0x5e, 0x7b, 0x4a, 0x36, 0x3c,
+    ],
+    // E_91
+    [
+        0x00, 0x9c, 0x2d, 0x4a, 0x9c, 0x2f, 0x60, 0x03, 0x28, 0xb3, 0x7d, 0xcd, 0x89, 0x3d, 0x9f, 0x8c,
+        0x62, 0xba, 0xa1, 0xed, 0x0a, 0xcb, 0xa3, 0x45, 0x83, 0xef, 0x92, 0xd7, 0x92, 0x16, 0xe8, 0x64,
+    ],
+    // E_92
+    [
+        0xa2, 0x91, 0x19, 0x0a, 0x0a, 0x9c, 0xa0, 0x1c, 0xd2, 0x25, 0x7c, 0xb0, 0x36, 0x92, 0x89, 0x09,
+        0x50, 0x88, 0x35, 0xfe, 0xf9, 0xe4, 0x7f, 0xda, 0x8c, 0xbb, 0x61, 0x8a, 0xb4, 0xf4, 0x72, 0xd7,
+    ],
+    // E_93
+    [
+        0x3e, 0x52, 0xda, 0xd5, 0xc2, 0xee, 0x1b, 0xb1, 0xe9, 0x10, 0x6d, 0x72, 0x5f, 0xa1, 0x79, 0xf6,
+        0x84, 0xcb, 0xf0, 0xb2, 0xc6, 0x02, 0x97, 0xf0, 0x1d, 0x4e, 0x28, 0x23, 0x8d, 0xf2, 0x2a, 0x35,
+    ],
+    // E_94
+    [
+        0xf9, 0xf9, 0x3b, 0xcb, 0x00, 0x23, 0xb5, 0xc1, 0xb5, 0xb5, 0x55, 0x5b, 0x3e, 0x3f, 0xfc, 0x44,
+        0x5d, 0xb3, 0x98, 0x1c, 0x43, 0xee, 0x10, 0x87, 0x5e, 0x7d, 0x67, 0x4e, 0x11, 0xb9, 0x44, 0xda,
+    ],
+    // E_95
+    [
+        0xc2, 0x40, 0x48, 0x65, 0x65, 0x75, 0x4b, 0xb0, 0x18, 0x2b, 0xdd, 0x1f, 0xfe, 0xfe, 0x2a, 0x8b,
+        0xfe, 0x5c, 0xf7, 0xb6, 0xab, 0x96, 0x04, 0xd4, 0xf9, 0x32, 0x7b, 0x0d, 0x3a, 0x1a, 0xe5, 0x4c,
+    ],
+    // E_96
+    [
+        0xfd, 0xb5, 0x18, 0xb8, 0x46, 0xb5, 0x71, 0x6f, 0x3b, 0x71, 0xf8, 0xa6, 0x4f, 0x97, 0x14, 0xfb,
+        0x22, 0x33, 0x24, 0x6e, 0x4b, 0x05, 0x2e, 0x2f, 0xde, 0x22, 0x85, 0x96, 0x98, 0x35, 0x8d, 0xe1,
+    ],
+    // E_97
+    [
+        0xc7, 0xa4, 0xf7, 0x70, 0x34, 0x86, 0xfb, 0x62, 0x44, 0xc2, 0x31, 0xcd, 0x4c, 0x09, 0x6f, 0x91,
+        0xa7, 0x53, 0xd1, 0x26, 0x84, 0x42, 0x6a, 0xc9, 0x26, 0xc7, 0x94, 0x67, 0x0b, 0x90, 0x7e, 0x2e,
+    ],
+    // E_98
+    [
+        0xf1, 0x0f, 0xf0, 0x62, 0x58, 0x05, 0x3f, 0xe6, 0xde, 0xc2, 0xa8, 0x59, 0x4a, 0x7f, 0xe0, 0x03,
+        0x4d, 0x58, 0xb1, 0xf3, 0xd9, 0xcc, 0x72, 0xb5, 0xac, 0xba, 0xa9, 0xfe, 0x2a, 0xd8, 0x51, 0xa1,
+    ],
+    // E_99
+    [
+        0x18, 0x6c, 0xab, 0x68, 0xfe, 0x93, 0x3a, 0x3f, 0xce, 0x1f, 0xa7, 0x30, 0x3e, 0x75, 0xaf, 0x63,
+        0x88, 0x4e, 0x7c, 0xd8, 0x83, 0x03, 0x57, 0x8b, 0x55, 0x57, 0x3e, 0x57, 0x95, 0x70, 0xc4, 0xfb,
+    ],
+    // E_100
+    [
+        0xd7, 0x7f, 0xe3, 0xba, 0x8d, 0xa8, 0x3a, 0x49, 0x00, 0x74, 0x00, 0xdb, 0x6d, 0x4a, 0x0b, 0x0f,
+        0xc4, 0x9c, 0x2c, 0x5a, 0xfc, 0x5a, 0x95, 0xd1, 0x0a, 0x5c, 0x9a, 0x54, 0x21, 0x86, 0xe6, 0x32,
+    ],
+    // E_101
+    [
+        0xd7, 0x7d, 0x79, 0xd0, 0x77, 0xff, 0x51, 0xb6, 0xb3, 0xe3, 0xed, 0xfa, 0x7e, 0x78, 0x52, 0x1a,
+        0x58, 0xce, 0xb6, 0xf4, 0x40, 0xfa, 0x2d, 0xff, 0xb6, 0x1e, 0x1a, 0x09, 0x0d, 0xe4, 0x71, 0x8b,
+    ],
+    // E_102
+    [
+        0xe7, 0xd9, 0xd2, 0x04, 0x97, 0xb8, 0x2f, 0x3e, 0xaa, 0xf0, 0x55, 0xe9, 0xea, 0xc4, 0xe0, 0x1f,
+        0xda, 0xfa, 0x81, 0x6e, 0x71, 0x3f, 0xf8, 0x71, 0x0c, 0xed, 0xb1, 0x9b, 0xa6, 0xd0, 0x34, 0x76,
+    ],
+    // E_103
+    [
+        0x00, 0x2e, 0x42, 0xf1, 0x77, 0xe8, 0xf8, 0x95, 0xfa, 0x70, 0x9f, 0x95, 0xb7, 0xf7, 0xca, 0xab,
+        0x2a, 0x34, 0x0e, 0x06, 0xaa, 0x41, 0x80, 0x8c, 0x01, 0x74, 0x5d, 0x0f, 0xa0, 0x66, 0x2e, 0xfd,
+    ],
+    // E_104
+    [
+        0x44, 0x9a, 0x74, 0x37, 0xd9, 0xa9, 0x6d, 0x7b, 0xa7, 0x9c, 0x9a, 0x22, 0x2c, 0xba, 0x8c, 0xa2,
+        0x65, 0xc8, 0x2e, 0x1e, 0x32, 0xe1, 0x42, 0x63, 0xd0, 0x84, 0x94, 0xc0, 0xad, 0xcd, 0x24, 0x61,
+    ],
+    // E_105
+    [
+        0x64, 0xfa, 0xe9, 0x83, 0xf5, 0x5a, 0xca, 0xa2, 0x12, 0x9c, 0x36, 0x80, 0x2f, 0x63, 0x47, 0x80,
+        0xb5, 0xa8, 0x7f, 0x0b, 0x14, 0xca, 0x3a, 0x5b, 0x90, 0x38, 0x06, 0xc4, 0xa5, 0x95, 0xd5, 0x7e,
+    ],
+    // E_106
+    [
+        0x8c, 0x4c, 0x3d, 0x39, 0x93, 0xb0, 0x63, 0xd3, 0x02, 0x4a, 0x97, 0x41, 0xb0, 0x55, 0xfb, 0xad,
+        0xb8, 0xd5, 0xae, 0x50, 0x58, 0xb0, 0xb6, 0xd2, 0x95, 0x7c, 0xdb, 0xb0, 0x45, 0xf2, 0xa2, 0x0a,
+    ],
+    // E_107
+    [
+        0x95, 0xa2, 0xf1, 0xa1, 0xc3, 0xd6, 0x97, 0x11, 0x48, 0xaf, 0xc5, 0x68, 0xf8, 0xa7, 0x79, 0x17,
+        0x76, 0x06, 0x76, 0x6f, 0x63, 0x08, 0x0e, 0xb5, 0xf4, 0xd5, 0xce, 0x4a, 0x63, 0x98, 0x44, 0x40,
+    ],
+    // E_108
+    [
+        0xba, 0x57, 0x0c, 0xeb, 0x32, 0x88, 0x68, 0x06, 0xf0, 0xc7, 0xf5, 0x29, 0x02, 0x03, 0xa4, 0x7c,
+        0x45, 0x38, 0xad, 0xa1, 0x3b, 0x39, 0xe7, 0x76, 0x34, 0x54, 0x89, 0x7b, 0x33, 0xa6, 0xb6, 0x1d,
+    ],
+    // E_109
+    [
+        0x47, 0x55, 0x9d, 0x4b, 0x59, 0x2b, 0x49, 0x12, 0x0a, 0xf1, 0x9b, 0x64, 0x2a, 0x18, 0x99, 0xf9,
+        0x99, 0xb4, 0x3d, 0x39, 0x20, 0x6b, 0xf8, 0x3e, 0x2a, 0x67, 0x52, 0x23, 0x84, 0xdd, 0x21, 0xef,
+    ],
+    // E_110
+    [
+        0x1a, 0x01, 0x98, 0x09, 0x47, 0xe8, 0x6c, 0x43, 0x54, 0x6e, 0x0e, 0x5e, 0x73, 0x91, 0x4d, 0x81,
+        0xb7, 0x3b, 0x83, 0xee, 0xf1, 0x10, 0x36, 0x2b, 0xcf, 0x1d, 0x6b, 0x72, 0xaa, 0x1f, 0xee, 0xa9,
+    ],
+    // E_111
+    [
+        0x5a, 0xc5, 0x3b, 0x0f, 0xa7, 0x70, 0x85, 0x6d, 0xcc, 0x51, 0x46, 0x58, 0xbd, 0xbe, 0xee, 0x91,
+        0xf0, 0x78, 0x13, 0x85, 0xf6, 0xc3, 0xda, 0xf4, 0xa7, 0x93, 0x89, 0x7d, 0x43, 0x42, 0x76, 0x85,
+    ],
+    // E_112
+    [
+        0x00, 0xcd, 0x84, 0x0d, 0xc4, 0xd4, 0x5c, 0x31, 0xac, 0xd3, 0xcf, 0x5a, 0xe7, 0x5b, 0xb6, 0x76,
+        0x68, 0xf0, 0x3c, 0xe1, 0x6e, 0x94, 0x5a, 0x2b, 0xc1, 0xb0, 0x36, 0xc3, 0x35, 0x2a, 0xbc, 0x45,
+    ],
+    // E_113
+    [
+        0x05, 0x3d, 0x22, 0x45, 0xa7, 0x63, 0xd7, 0xcd, 0x88, 0x17, 0x50, 0xd9, 0xc0, 0x3f, 0x2a, 0x94,
+        0xf5, 0x6d, 0x92, 0x9d, 0xfa, 0x5a, 0xf0, 0x38, 0x1c, 0xa0, 0x9e, 0x63, 0x02, 0x40, 0x67, 0xc0,
+    ],
+    // E_114
+    [
+        0xb1, 0xd7, 0x34, 0xf8, 0xa5, 0x22, 0xdc, 0x1e, 0x26, 0x75, 0x3f, 0x8c, 0x8a, 0xe0, 0xe9, 0x72,
+        0xaa, 0x72, 0x69, 0x3f, 0x67, 0xc5, 0x06, 0x85, 0x86, 0x2a, 0x7a, 0x12, 0x06, 0x77, 0xab, 0x58,
+    ],
+    // E_115
+    [
+        0xe8, 0x5a, 0x06, 0xa2, 0x75, 0x81, 0xb7, 0x9a, 0xab, 0x27, 0x1d, 0x26, 0xdd, 0xf8, 0x80, 0x25,
+        0x75, 0x02, 0xaf, 0x1d, 0x7d, 0x97, 0x9d, 0xb9, 0x5f, 0x9a, 0x94, 0x18, 0xe4, 0xdb, 0x31, 0x7b,
+    ],
+    // E_116
+    [
+        0xad, 0x5c, 0xd2, 0xc5, 0x20, 0xcf, 0xff, 0x10, 0x4d, 0x9a, 0xa7, 0xab, 0x44, 0x52, 0xc7, 0x30,
+        0x37, 0xa6, 0xa4, 0xcb, 0xb5, 0x42, 0xe7, 0x7c, 0xd6, 0xd4, 0x10, 0xa0, 0x6d, 0xd3, 0x50, 0x3d,
+    ],
+    // E_117
+    [
+        0x16, 0xf4, 0xf6, 0x79, 0x97, 0x68, 0x48, 0x6b, 0x0f, 0x7e, 0x3a, 0x36, 0x56, 0xdf, 0x55, 0x38,
+        0x93, 0x93, 0x20, 0x31, 0x17, 0x2e, 0x53, 0xf8, 0x50, 0xd5, 0x38, 0xb0, 0x69, 0x32, 0x9c, 0xc1,
+    ],
+    // E_118
+    [
+        0xa5, 0xca, 0xa2, 0x6d, 0xd3, 0xbd, 0x24, 0x3a, 0x74, 0x4c, 0xcd, 0x34, 0x24, 0x2e, 0xfc, 0xea,
+        0x6a, 0x87, 0x23, 0xb2, 0x84, 0x52, 0x1e, 0x66, 0xe0, 0x4f, 0x0e, 0x08, 0xec, 0xae, 0xa3, 0x56,
+    ],
+    // E_119
+    [
+        0x06, 0x8a, 0xd7, 0x3f, 0x1f, 0x94, 0xbc, 0x7f, 0xde, 0x16, 0xdc, 0x3e, 0x0d, 0x57, 0xf8, 0xb5,
+        0x17, 0x84, 0xdb, 0x42, 0x7d, 0x28, 0xc9, 0xba, 0xfc, 0x48, 0x87, 0x1f, 0x29, 0xdf, 0xf8, 0x51,
+    ],
+    // E_120
+    [
+        0x89, 0x06, 0xdf, 0x40, 0x78, 0x1d, 0xe4, 0xb2, 0x25, 0xa7, 0xfe, 0x5b, 0x03, 0xa8, 0x21, 0xc2,
+        0x1d, 0x37, 0x0e, 0xd1, 0x53, 0xd2, 0x25, 0xc3, 0x38, 0x75, 0xe2, 0xd2, 0x71, 0xf9, 0x30, 0x8b,
+    ],
+    // E_121
+    [
+        0xe6, 0x96, 0x34, 0x5a, 0xf3, 0x77, 0x97, 0xeb, 0xc8, 0x48, 0x99, 0x96, 0x10, 0xbb, 0x99, 0x23,
+        0xd6, 0xba, 0x2e, 0x18, 0xf2, 0xe7, 0x13, 0x3e, 0x6d, 0x33, 0xe5, 0x45, 0x26, 0x29, 0xaf, 0xa3,
+    ],
+    // E_122
+    [
+        0x2e, 0xa8, 0xe1, 0xbb, 0x47, 0x25, 0xee, 0x10, 0x25, 0xe8, 0x7c, 0xa3, 0xb7, 0xc2, 0x92, 0x26,
+        0xf7, 0xd4, 0x79, 0x0f, 0x63, 0xb3, 0x78, 0xaa, 0xe5, 0xe4, 0xc9, 0x62, 0x86, 0x92, 0x6f, 0x9b,
+    ],
+    // E_123
+    [
+        0xe0, 0xe1, 0x6a, 0xaf, 0xb6, 0x67, 0xe3, 0x61, 0x5a, 0x75, 0xaa, 0x18, 0x17, 0x50, 0x43, 0xfd,
+        0x89, 0x6a, 0xcd, 0xae, 0xf2, 0xc1, 0xec, 0xa0, 0xe1, 0xe2, 0xe4, 0xda, 0xa9, 0x72, 0xee, 0x2d,
+    ],
+    // E_124
+    [
+        0x30, 0x00, 0x8d, 0x31, 0xc9, 0x75, 0x40, 0xc8, 0xa7, 0x14, 0x15, 0xa6, 0xac, 0x16, 0x8f, 0x51,
+        0xda, 0x54, 0x5b, 0x27, 0xe7, 0xd1, 0x0f, 0xf0, 0xf5, 0x93, 0xc1, 0x7f, 0x05, 0x73, 0xa3, 0xd1,
+    ],
+    // E_125
+    [
+        0x9f, 0x0f, 0x90, 0x5e, 0x40, 0x0a, 0xa8, 0x7a, 0xc2, 0x7e, 0xa8, 0x35, 0xb3, 0xc5, 0xf8, 0x12,
+        0xdd, 0xc5, 0xbb, 0xdb, 0x98, 0x57, 0x2e, 0x3d, 0x66, 0x95, 0xac, 0x19, 0x40, 0x41, 0x45, 0xe3,
+    ],
+    // E_126
+    [
+        0x3e, 0xa5, 0x85, 0x8d, 0xd4, 0x68, 0xa6, 0xcc, 0x9b, 0xcf, 0x1b, 0xc9, 0xbc, 0x63, 0xc8, 0x2c,
+        0x67, 0x9b, 0xd4, 0x7c, 0xf5, 0xd4, 0xb7, 0x01, 0xba, 0x95, 0x42, 0xdf, 0x78, 0x1d, 0xdb, 0x97,
+    ],
+    // E_127
+    [
+        0x6d, 0x8a, 0xe3, 0xb0, 0xb3, 0x9c, 0x37, 0x12, 0xd4, 0x1b, 0xf3, 0x52, 0x84, 0x73, 0xf8, 0xd9,
+        0xc8, 0x39, 0x87, 0x43, 0x8b, 0x8f, 0xc1, 0x87, 0xd6, 0x50, 0x23, 0xd5, 0x0c, 0x56, 0x23, 0xaa,
+    ],
+    // E_128
+    [
+        0x1a, 0xab, 0x08, 0xc9, 0x18, 0x67, 0xfb, 0xcb, 0x6d, 0x0b, 0xd7, 0x2c, 0xd3, 0x33, 0xcb, 0x4e,
+        0x99, 0xe0, 0x5d, 0x12, 0xb7, 0xa2, 0xa5, 0x67, 0x8e, 0x85, 0x53, 0xa3, 0xe2, 0x30, 0x1f, 0xee,
+    ],
+    // E_129
+    [
+        0x1f, 0xf1, 0xcb, 0x84, 0x63, 0x28, 0x6a, 0x4f, 0x48, 0x31, 0xd6, 0x2d, 0xc1, 0x21, 0xdb, 0xeb,
+        0x3e, 0x7c, 0x0a, 0xc5, 0xbf, 0xc8, 0x07, 0x82, 0xd5, 0xd6, 0x2d, 0x19, 0x7d, 0x52, 0x69, 0x67,
+    ],
+    // E_130
+    [
+        0xc3, 0x00, 0xf3, 0x3e, 0xae, 0x1c, 0xf1, 0x42, 0xed, 0xd1, 0x25, 0x5f, 0x7c, 0x85, 0xda, 0x4e,
+        0x77, 0xee, 0x4a, 0x73, 0x24, 0x36, 0xfc, 0xec, 0xd7, 0xf2, 0x95, 0xbd, 0xbb, 0x08, 0x56, 0x6e,
+    ],
+    // E_131
+    [
+        0xbc, 0x3f, 0xdb, 0x2e, 0x1e, 0xf3, 0x0f, 0x51, 0xc6, 0x74, 0x02, 0xec, 0x07, 0x80, 0x51, 0xda,
+        0x16, 0x38, 0x5b, 0x7e, 0xb1, 0x96, 0xff, 0x5e, 0x4b, 0xb2, 0x05, 0xde, 0x9c, 0x4c, 0x03, 0x82,
+    ],
+    // E_132
+    [
+        0xe8, 0x6a, 0xfb, 0x4b, 0xb6, 0xf4, 0x3f, 0x5f, 0xd3, 0xa5, 0x15, 0x6d, 0x5e, 0x23, 0xb0, 0x3a,
+        0xed, 0xcd, 0x34, 0x91, 0x3d, 0xd2, 0xcd, 0x6f, 0xd7, 0xca, 0xa3, 0x0c, 0xf7, 0xc0, 0xb2, 0x02,
+    ],
+    // E_133
+    [
+        0x97, 0xa1, 0xae, 0x6b, 0x85, 0x4f, 0x0e, 0x0a, 0xa3, 0xac, 0x34, 0xae, 0x60, 0x09, 0xa0, 0x39,
+        0xc0, 0x17, 0x05, 0xf2, 0xe9, 0x5c, 0x39, 0xc0, 0x9e, 0x39, 0x37, 0xb6, 0xa2, 0x8d, 0x5f, 0x72,
+    ],
+    // E_134
+    [
+        0xe4, 0xbb, 0xa9, 0x4a, 0x15, 0xed, 0x2c, 0xbf, 0xae, 0x6e, 0x15, 0x1f, 0xdd, 0xc8, 0x48, 0xd5,
+        0x05, 0x18, 0x00, 0x5a, 0x7d, 0x10, 0xf4, 0x17, 0x10, 0x5c, 0xe7, 0xb7, 0xf3, 0x9c, 0x6c, 0x29,
+    ],
+    // E_135
+    [
+        0x8f, 0xe3, 0x8e, 0x7a, 0x8c, 0xc1, 0x41, 0x6c, 0x99, 0x39, 0xa0, 0xba, 0x9e, 0x31, 0x90, 0x2e,
+        0x07, 0x46, 0xfd, 0x0d, 0xe5, 0xc5, 0x17, 0xee, 0x31, 0x34, 0x4c, 0x69, 0x54, 0xe0, 0xa6, 0x63,
+    ],
+    // E_136
+    [
+        0xb7, 0x9a, 0x0f, 0xd9, 0x3f, 0x62, 0xde, 0xfd, 0x31, 0xed, 0xed, 0x45, 0x3d, 0xea, 0xf1, 0xa1,
+        0xce, 0x88, 0xe6, 0x91, 0x67, 0x21, 0xd7, 0x20, 0xa0, 0x85, 0x24, 0x47, 0xfb, 0x54, 0xcb, 0x1f,
+    ],
+    // E_137
+    [
+        0x97, 0x82, 0x30, 0x18, 0xa2, 0xcf, 0x35, 0x98, 0x58, 0xa6, 0x85, 0x34, 0xf2, 0xc0, 0x29, 0x90,
+        0x96, 0x61, 0xe8, 0x9e, 0x6f, 0xad, 0xf5, 0x33, 0x33, 0x9a, 0xc1, 0x0f, 0xa5, 0x55, 0x6d, 0x40,
+    ],
+    // E_138
+    [
+        0x0c, 0xa7, 0x8c, 0xe0, 0xc7, 0x78, 0x41, 0x4b, 0x85, 0x0c, 0x50, 0x86, 0x7f, 0x0e, 0x3a, 0x1c,
+        0x31, 0xcb, 0xe2, 0xa3, 0x3c, 0xd6, 0x35, 0xd4, 0x64, 0x56, 0xe0, 0xa2, 0xa4, 0x90, 0xea, 0x58,
+    ],
+    // E_139
+    [
+        0xf7, 0x9a, 0x98, 0xc8, 0x9b, 0x55, 0x88, 0xfd, 0xd7, 0xa8, 0xf4, 0x84, 0x91, 0xf0, 0xa6, 0xc4,
+        0x31, 0x1e, 0xd2, 0xcd, 0xc4, 0xe8, 0x88, 0x38, 0xd8, 0xd5, 0xb8, 0xf8, 0x09, 0xd6, 0x52, 0xad,
+    ],
+    // E_140
+    [
+        0xdc, 0x58, 0xe0, 0x06, 0x7e, 0x34, 0x0a, 0x70, 0xb8, 0x72, 0x3e, 0x75, 0x94, 0xe7, 0xd2, 0xcb,
+        0xb7, 0x8b, 0x84, 0xcc, 0xeb, 0xc8, 0xc8, 0x55, 0xcc, 0xb7, 0xe4, 0x8a, 0x54, 0x5c, 0x5d, 0x51,
+    ],
+    // E_141
+    [
+        0x87, 0x15, 0xbd, 0xf4, 0x4c, 0xf4, 0xed, 0x93, 0x46, 0x59, 0xd4, 0x51, 0xb7, 0xcb, 0x27, 0x61,
+        0xa3, 0x4b, 0x23, 0x6d, 0xaf, 0x82, 0x2a, 0x1b, 0x41, 0xb6, 0xd0, 0xf4, 0x5d, 0xfa, 0xcb, 0xda,
+    ],
+    // E_142
+    [
+        0x62, 0x71, 0xf5, 0xf7, 0xee, 0xa2, 0x77, 0xfa, 0xed, 0xf6, 0x62, 0x4e, 0x8b, 0x00, 0x5f, 0x35,
+        0x0f, 0x8b, 0xc2, 0xea, 0x4a, 0x42, 0x9c, 0xf9, 0x53, 0xe3, 0x1c, 0x41, 0xa2, 0xbd, 0xa2, 0xbd,
+    ],
+    // E_143
+    [
+        0xbd, 0xfb, 0x3d, 0x5e, 0x44, 0x28, 0x3b, 0xc9, 0x05, 0x6e, 0x49, 0x01, 0x5c, 0x51, 0x8d, 0x14,
+        0x36, 0x1a, 0xfa, 0x82, 0x3a, 0xab, 0x85, 0x2f, 0xcc, 0x99, 0xeb, 0x4b, 0x2c, 0x6e, 0x77, 0xe0,
+    ],
+    // E_144
+    [
+        0xcb, 0x6f, 0x78, 0xfa, 0xc7, 0x1c, 0xf4, 0xd8, 0x41, 0x19, 0x56, 0x42, 0xab, 0x0a, 0xf8, 0x50,
+        0x13, 0x59, 0x40, 0xc7, 0x49, 0x8c, 0x67, 0x56, 0x3e, 0xd1, 0x6a, 0xbe, 0x40, 0xbb, 0x34, 0x5d,
+    ],
+    // E_145
+    [
+        0x77, 0x50, 0x3a, 0xbb, 0x91, 0x6f, 0xbf, 0x31, 0x76, 0xc8, 0xd8, 0x7d, 0xbc, 0x43, 0xf8, 0xef,
+        0x11, 0xad, 0xaf, 0x64, 0x24, 0x36, 0xa8, 0x54, 0xb5, 0x12, 0x63, 0x59, 0xee, 0x21, 0x5e, 0x24,
+    ],
+    // E_146
+    [
+        0xa4, 0xd2, 0x92, 0xb8, 0xd3, 0xf0, 0x25, 0x68, 0x44, 0x35, 0x28, 0x57, 0x1d, 0x7f, 0xe0, 0xd7,
+        0x71, 0xb1, 0x74, 0xdc, 0xb3, 0x1c, 0x52, 0xbe, 0xec, 0x39, 0x08, 0x6e, 0x55, 0x5c, 0x42, 0x67,
+    ],
+    // E_147
+    [
+        0x51, 0xd7, 0x98, 0xe8, 0xd9, 0x95, 0xd3, 0x7b, 0x5a, 0x80, 0xf3, 0x6a, 0x78, 0xa9, 0xad, 0xcb,
+        0xcb, 0x47, 0xf2, 0x9b, 0x93, 0x13, 0x65, 0x35, 0xba, 0x5e, 0xca, 0xf2, 0xec, 0x56, 0x72, 0xaa,
+    ],
+    // E_148
+    [
+        0xc6, 0xe0, 0x77, 0x81, 0x5e, 0x0b, 0x1b, 0x28, 0x77, 0x9e, 0x2e, 0x8e, 0x51, 0x09, 0x58, 0xec,
+        0x0c, 0x9e, 0xcf, 0xa1, 0x15, 0xa6, 0xc2, 0x64, 0xe3, 0xd4, 0xf5, 0x90, 0xb7, 0x58, 0xd7, 0x54,
+    ],
+    // E_149
+    [
+        0xc7, 0xb3, 0xaa, 0xc4, 0xc6, 0x85, 0xf7, 0x2a, 0xb9, 0x6e, 0x19, 0x68, 0xea, 0xfe, 0x26, 0x02,
+        0x4a, 0x5c, 0x77, 0x01, 0x17, 0x06, 0x81, 0x74, 0xa1, 0x97, 0xa3, 0x6d, 0xf6, 0x99, 0x86, 0xc8,
+    ],
+    // E_150
+    [
+        0x0a, 0x32, 0xe6, 0x0e, 0xbd, 0xa1, 0xd6, 0x4c, 0xd5, 0xa5, 0x54, 0xa3, 0x0b, 0x55, 0xa3, 0x79,
+        0x48, 0x45, 0x31, 0x34, 0x70, 0x39, 0xa5, 0xfb, 0x9a, 0x91, 0x40, 0xcb, 0x93, 0x7e, 0x30, 0x3a,
+    ],
+    // E_151
+    [
+        0x23, 0xc7, 0xce, 0x3f, 0xaf, 0x9d, 0xca, 0xe7, 0x40, 0x46, 0x9a, 0x6f, 0xf7, 0xc1, 0x00, 0xab,
+        0x06, 0x40, 0x88, 0xce, 0x1f, 0xfb, 0xb6, 0x5e, 0x9b, 0xa5, 0x9c, 0x57, 0x04, 0x94, 0x0c, 0x6a,
+    ],
+    // E_152
+    [
+        0xb9, 0x52, 0xe4, 0x81, 0x8d, 0xc7, 0x7e, 0x79, 0x36, 0x43, 0x5d, 0x65, 0x96, 0xb7, 0xd4, 0x4a,
+        0x50, 0x5c, 0x2d, 0xbd, 0x8b, 0x48, 0xba, 0x9b, 0xb5, 0x18, 0x91, 0x68, 0x8c, 0xb1, 0x2c, 0xe1,
+    ],
+    // E_153
+    [
+        0xf7, 0xf2, 0x34, 0xfa, 0x6d, 0x34, 0x94, 0xa7, 0xf8, 0x8d, 0x86, 0xa3, 0x16, 0x2d, 0x29, 0x13,
+        0x22, 0xe4, 0x4e, 0xf0, 0x53, 0x33, 0xce, 0x2b, 0x7c, 0x06, 0x9d, 0xb6, 0x59, 0x71, 0x0f, 0xa0,
+    ],
+    // E_154
+    [
+        0x56, 0x4d, 0x06, 0x30, 0xd7, 0x2a, 0x8e, 0x13, 0xc1, 0x25, 0xd5, 0x73, 0xba, 0xcc, 0x58, 0x03,
+        0x54, 0x20, 0x60, 0xe1, 0xbe, 0x6b, 0x1d, 0x5f, 0x8d, 0x4f, 0x43, 0x89, 0xe5, 0xa1, 0x50, 0xbc,
+    ],
+    // E_155
+    [
+        0x54, 0x91, 0x3f, 0xc8, 0xe9, 0x58, 0xc0, 0xc6, 0x75, 0x41, 0xe9, 0xfd, 0x18, 0xb0, 0x5a, 0x74,
+        0x08, 0xf5, 0xb8, 0xea, 0x2f, 0x99, 0x1e, 0xf5, 0xff, 0xf0, 0x7b, 0xd9, 0xc5, 0xc5, 0xfc, 0x68,
+    ],
+    // E_156
+    [
+        0x38, 0x7d, 0x9c, 0x37, 0x60, 0x67, 0x76, 0xab, 0xee, 0x40, 0xca, 0xd7, 0x56, 0x4f, 0x44, 0x55,
+        0x7f, 0x91, 0x72, 0x84, 0x12, 0xc8, 0x4f, 0xa0, 0xcd, 0x64, 0xff, 0xeb, 0x3c, 0xa8, 0xde, 0xfe,
+    ],
+    // E_157
+    [
+        0x33, 0x79, 0xf5, 0xaa, 0x8e, 0x23, 0x07, 0x86, 0x4b, 0x52, 0x9e, 0x06, 0x85, 0x2a, 0xfa, 0xf0,
+        0x1b, 0x39, 0x6e, 0x12, 0xe0, 0x6b, 0xf9, 0xcf, 0x4b, 0x1c, 0x4b, 0xf2, 0x7b, 0xa7, 0xac, 0xd2,
+    ],
+    // E_158
+    [
+        0x44, 0xc1, 0xec, 0xbd, 0xbd, 0x21, 0x40, 0x82, 0xb3, 0xfa, 0xad, 0x17, 0x38, 0x7a, 0x77, 0xd2,
+        0xf9, 0xa0, 0xad, 0x80, 0x64, 0x0b, 0x78, 0xbd, 0x3f, 0x92, 0xf2, 0x7f, 0x59, 0x71, 0x48, 0x87,
+    ],
+    // E_159
+    [
+        0xc2, 0x81, 0x17, 0x28, 0xa3, 0xc4, 0xda, 0x1b, 0xcd, 0xc4, 0x72, 0x67, 0xd5, 0x89, 0xdb, 0x7e,
+        0x01, 0xf3, 0x07, 0x4b, 0xe3, 0xfc, 0xe8, 0x49, 0xd7, 0xe2, 0x3b, 0x32, 0x96, 0xc3, 0xc0, 0x08,
+    ],
+    // E_160
+    [
+        0x17, 0x25, 0xc9, 0xc5, 0x83, 0xe3, 0x14, 0xb2, 0x6e, 0x8f, 0x7a, 0x9e, 0x29, 0x80, 0x67, 0x5b,
+        0x12, 0x43, 0x4a, 0xff, 0x86, 0xfa, 0xb7, 0x97, 0x48, 0x4d, 0x68, 0x13, 0xf3, 0x9e, 0x89, 0x76,
+    ],
+    // E_161
+    [
+        0x37, 0x33, 0xab, 0x35, 0x95, 0x2c, 0x3e, 0x5c, 0xa5, 0xf0, 0x4a, 0xae, 0x7e, 0x75, 0x73, 0x27,
+        0xa9, 0xe3, 0x6b, 0x54, 0x62, 0xb9, 0x78, 0x4b, 0x29, 0x3d, 0xf2, 0xb5, 0x29, 0x48, 0x58, 0x56,
+    ],
+    // E_162
+    [
+        0x86, 0xda, 0x6e, 0x23, 0x3c, 0x83, 0x6a, 0xb2, 0x94, 0xaa, 0x5a, 0xae, 0xdd, 0xd8, 0x57, 0xf9,
+        0x52, 0x11, 0x35, 0x0d, 0xc4, 0x9f, 0x1f, 0xda, 0x3d, 0x3c, 0x4f, 0x6b, 0xf1, 0x21, 0xe5, 0xcc,
+    ],
+    // E_163
+    [
+        0xbd, 0x5c, 0xcb, 0xd5, 0x17, 0x75, 0x53, 0x7b, 0xe2, 0xf4, 0xd7, 0x34, 0x40, 0x1c, 0xf9, 0x75,
+        0x10, 0xd4, 0xe1, 0x06, 0xe0, 0xd6, 0x3d, 0x39, 0x6e, 0xab, 0xa9, 0x76, 0xbd, 0x2f, 0x57, 0x1a,
+    ],
+    // E_164
+    [
+        0x0f, 0x0c, 0x5d, 0x6d, 0x80, 0x66, 0xbd, 0x1f, 0x95, 0x8e, 0xf7, 0x15, 0xde, 0x9d, 0x1c, 0x17,
+        0x9a, 0x0e, 0x39, 0x0e, 0xbf, 0x52, 0x48, 0x0e, 0x1b, 0x63, 0x8e, 0xf1, 0x7e, 0x54, 0xae, 0x4a,
+    ],
+    // E_165
+    [
+        0xa4, 0x45, 0xc0, 0x48, 0x81, 0xab, 0xf3, 0xef, 0x5a, 0x51, 0xe4, 0x0f, 0x86, 0xc0, 0xd8, 0x73,
+        0xa6, 0xb7, 0x83, 0xa0, 0x33, 0x9f, 0xb5, 0x18, 0x4e, 0xa2, 0x58, 0x10, 0x90, 0xff, 0xf3, 0xf3,
+    ],
+    // E_166
+    [
+        0x88, 0x8d, 0xba, 0xc2, 0x22, 0x93, 0xc0, 0xbf, 0xac, 0x81, 0x84, 0xa0, 0xf0, 0xd6, 0xcc, 0xe9,
+        0xd1, 0x93, 0x28, 0xbe, 0xc4, 0xff, 0x70, 0xfb, 0xb4, 0x1f, 0x16, 0xdc, 0x33, 0x64, 0x6b, 0xa4,
+    ],
+    // E_167
+    [
+        0xb3, 0xa9, 0xf6, 0x5c, 0x6d, 0x4c, 0xa3, 0x67, 0x45, 0x45, 0xca, 0xd1, 0xa0, 0x7a, 0x0e, 0xf4,
+        0x9b, 0x34, 0x4d, 0xd1, 0x3f, 0x65, 0xca, 0xe1, 0x84, 0x6e, 0xd2, 0xe2, 0x2c, 0xfe, 0x26, 0x21,
+    ],
+    // E_168
+    [
+        0xf6, 0xf7, 0x96, 0x1e, 0xc9, 0xd6, 0x36, 0x0d, 0x84, 0xa6, 0x57, 0xc0, 0xc8, 0x88, 0xe4, 0x8f,
+        0x7d, 0x42, 0xe6, 0x9e, 0x0d, 0xcd, 0x42, 0xcc, 0x20, 0xae, 0x7d, 0xfe, 0xee, 0x73, 0xc8, 0x77,
+    ],
+    // E_169
+    [
+        0x81, 0xd4, 0xa8, 0x73, 0x09, 0x6f, 0x6f, 0x9d, 0xcc, 0x1c, 0xcb, 0x79, 0xaa, 0x9e, 0x85, 0x81,
+        0xb1, 0x66, 0x64, 0xd6, 0x59, 0xad, 0xeb, 0x59, 0xa0, 0xc1, 0x59, 0xec, 0xc1, 0x6d, 0x95, 0x15,
+    ],
+    // E_170
+    [
+        0xec, 0x09, 0xd5, 0x60, 0x65, 0x58, 0x7f, 0xc3, 0xd6, 0xa2, 0x3c, 0x8c, 0xeb, 0xfa, 0x35, 0x21,
+        0xfa, 0x27, 0x81, 0xa4, 0x1f, 0xb7, 0x35, 0x25, 0xda, 0xa0, 0x51, 0x77, 0x04, 0x0a, 0x57, 0xe0,
+    ],
+    // E_171
+    [
+        0x9f, 0x29, 0x36, 0xd1, 0x59, 0x30, 0xf1, 0x75, 0x27, 0xa8, 0x00, 0x5c, 0x81, 0xc5, 0x56, 0xa7,
+        0x98, 0xd1, 0x0a, 0xcc, 0x1c, 0xe0, 0xdb, 0x06, 0xc1, 0x67, 0x37, 0xea, 0xc1, 0x17, 0xa5, 0x02,
+    ],
+    // E_172
+    [
+        0x37, 0x62, 0x73, 0x1f, 0x3a, 0x60, 0x16, 0x09, 0x15, 0x6b, 0xe4, 0xd5, 0xe1, 0x4e, 0x6f, 0x10,
+        0x28, 0x54, 0xdc, 0xe4, 0x7f, 0xfa, 0xb8, 0xf7, 0x52, 0x23, 0x99, 0xe9, 0x4b, 0xd3, 0x72, 0x29,
+    ],
+    // E_173
+    [
+        0xac, 0x36, 0x6c, 0x15, 0x91, 0x29, 0x80, 0xd0, 0x3f, 0x15, 0x3f, 0x57, 0x2c, 0xb9, 0x49, 0x0b,
+        0xe8, 0x66, 0xec, 0x17, 0xa6, 0xb3, 0x6e, 0x7d, 0x5f, 0xbf, 0xbb, 0xb5, 0x7a, 0x60, 0xe4, 0xca,
+    ],
+    // E_174
+    [
+        0xa6, 0x8e, 0x67, 0x55, 0x3b, 0x32, 0x9e, 0x1c, 0x56, 0x81, 0x5c, 0x4f, 0xa4, 0xd0, 0x19, 0x4d,
+        0xee, 0x77, 0x80, 0x6e, 0x88, 0x2f, 0x06, 0x14, 0x0a, 0xcd, 0x3e, 0xa4, 0x7e, 0x05, 0x0f, 0x6e,
+    ],
+    // E_175
+    [
+        0xa0, 0xe0, 0xc0, 0xaf, 0xef, 0x1e, 0x57, 0xab, 0x51, 0x73, 0x17, 0x0e, 0x1f, 0x87, 0xe7, 0x42,
+        0x05, 0xcf, 0xb2, 0xc8, 0xb1, 0x46, 0xa4, 0x8f, 0x8b, 0x4c, 0xbc, 0x3e, 0x99, 0x71, 0x91, 0x7c,
+    ],
+    // E_176
+    [
+        0xf0, 0x5f, 0xe7, 0x6e, 0x08, 0x1b, 0x85, 0xdc, 0xf4, 0x57, 0x65, 0x7d, 0xae, 0x89, 0x72, 0x77,
+        0x43, 0x7b, 0xed, 0xb6, 0xa5, 0x07, 0x6a, 0x0a, 0x07, 0xf5, 0xc1, 0x11, 0x74, 0xbb, 0x55, 0x5a,
+    ],
+    // E_177
+    [
+        0xc2, 0x8c, 0x8d, 0xa8, 0x2f, 0x36, 0x31, 0xed, 0x82, 0x72, 0x47, 0xd4, 0x7d, 0xf1, 0x98, 0xa0,
+        0xc8, 0x47, 0x65, 0xcc, 0x9f, 0xa9, 0x9d, 0x1a, 0x99, 0x6f, 0x11, 0x2b, 0xdd, 0x5b, 0x2e, 0xe2,
+    ],
+    // E_178
+    [
+        0xf0, 0xf7, 0xd2, 0x9a, 0x40, 0x36, 0x44, 0x47, 0x50, 0x68, 0xcd, 0xe3, 0xda, 0xd1, 0x59, 0x16,
+        0x5a, 0x8f, 0xf1, 0x5b, 0xd8, 0x4d, 0x9f, 0xdb, 0xd4, 0x48, 0xbc, 0xef, 0xca, 0x66, 0x30, 0xcc,
+    ],
+    // E_179
+    [
+        0x0c, 0x52, 0x92, 0xef, 0xa9, 0x4b, 0xfb, 0xd5, 0x5f, 0x02, 0xdc, 0x95, 0x33, 0x30, 0xc3, 0xd6,
+        0x3f, 0x36, 0xc2, 0x80, 0x81, 0xa2, 0x1f, 0x64, 0xbf, 0x68, 0x58, 0x7d, 0xfe, 0x96, 0xbe, 0xa6,
+    ],
+    // E_180
+    [
+        0x7f, 0xbc, 0x54, 0xa4, 0x6c, 0x7b, 0xfe, 0xdb, 0x88, 0xcd, 0xe4, 0xda, 0x58, 0x40, 0x26, 0xc7,
+        0xae, 0x3e, 0x14, 0x1e, 0x82, 0x80, 0xd9, 0xb8, 0x9f, 0x0b, 0x71, 0x33, 0xdf, 0xce, 0x1b, 0xb3,
+    ],
+    // E_181
+    [
+        0xe8, 0x4a, 0x18, 0x97, 0xdf, 0x1f, 0x93, 0x60, 0x17, 0x89, 0x22, 0xed, 0x1f, 0x78, 0xc5, 0xf4,
+        0xab, 0x31, 0xfd, 0xaa, 0xad, 0x34, 0x4a, 0x39, 0xb8, 0xa8, 0x11, 0x4b, 0x95, 0x19, 0x39, 0x0f,
+    ],
+    // E_182
+    [
+        0x5d, 0xe0, 0xd3, 0x94, 0xd4, 0xc9, 0x35, 0x64, 0x5b, 0x73, 0x4b, 0x9e, 0x3e, 0xb1, 0xb4, 0xe1,
+        0xcc, 0xb6, 0xf5, 0x00, 0x09, 0x30, 0x02, 0x29, 0x6e, 0x3c, 0xcd, 0xf4, 0xc4, 0x02, 0x9f, 0x6c,
+    ],
+    // E_183
+    [
+        0xac, 0x91, 0xe7, 0xdd, 0xfb, 0xc2, 0xe4, 0x2b, 0x2f, 0x5f, 0x7d, 0xf7, 0xce, 0xfb, 0x5b, 0xc0,
+        0x87, 0xba, 0xd6, 0x34, 0x04, 0x9b, 0xa3, 0x58, 0xd9, 0xd0, 0xba, 0x4a, 0xba, 0x90, 0x42, 0x16,
+    ],
+    // E_184
+    [
+        0x05, 0x79, 0x7a, 0xd8, 0x9d, 0x79, 0x6f, 0x30, 0xed, 0x99, 0x70, 0xe9, 0xfa, 0x04, 0x6b, 0xe6,
+        0x1b, 0x0c, 0x31, 0xa1, 0x7a, 0xb3, 0x6b, 0xc2, 0x14, 0x2e, 0x59, 0x6f, 0xd0, 0xe8, 0xce, 0x49,
+    ],
+    // E_185
+    [
+        0x28, 0xd3, 0x7f, 0xbd, 0xb2, 0x4f, 0xa3, 0x9d, 0x1c, 0x46, 0x13, 0x02, 0x0d, 0x75, 0x94, 0x88,
+        0xf2, 0x39, 0xb5, 0x0c, 0x5d, 0xba, 0xef, 0xd5, 0x86, 0x38, 0x55, 0xad, 0x04, 0x90, 0x96, 0xac,
+    ],
+    // E_186
+    [
+        0x43, 0xc9, 0xc1, 0xbb, 0xa1, 0x9d, 0xe6, 0x01, 0xc6, 0x63, 0x22, 0xa8, 0xb3, 0x58, 0xcb, 0xa7,
+        0x5e, 0xd5, 0xb5, 0x16, 0xb0, 0xf2, 0x98, 0x0d, 0xe5, 0x68, 0xa5, 0x0e, 0xfd, 0xa6, 0x7c, 0x0b,
+    ],
+    // E_187
+    [
+        0xea, 0x0a, 0x14, 0x84, 0x6e, 0x24, 0x68, 0xc4, 0xf1, 0x60, 0x5d, 0xe8, 0x98, 0x07, 0x31, 0x4a,
+        0x33, 0xce, 0x3b, 0xf7, 0xe0, 0xa2, 0x21, 0x37, 0x69, 0x65, 0xd2, 0xbd, 0xe3, 0x9f, 0x6f, 0x68,
+    ],
+    // E_188
+    [
+        0x75, 0x0c, 0xba, 0x5c, 0x93, 0x24, 0x09, 0x52, 0x6a, 0x88, 0x97, 0xdf, 0x16, 0xb2, 0xe1, 0xd1,
+        0x8d, 0x72, 0x4b, 0x9b, 0x1c, 0x1b, 0x4f, 0x68, 0x0d, 0xbc, 0x3f, 0x1e, 0x9f, 0x4d, 0xa6, 0x91,
+    ],
+    // E_189
+    [
+        0xca, 0x3d, 0xc9, 0x69, 0x6c, 0x03, 0x6c, 0xbb, 0x3c, 0x2f, 0x82, 0xb1, 0xef, 0x64, 0x44, 0xfe,
+        0xec, 0x01, 0xcc, 0xf9, 0x08, 0x86, 0x09, 0x5e, 0xd8, 0xb8, 0x42, 0xdc, 0xb2, 0x64, 0x62, 0x46,
+    ],
+    // E_190
+    [
+        0x2b, 0xdd, 0xe3, 0x76, 0xd7, 0xb2, 0xcb, 0x29, 0x01, 0xef, 0x6e, 0xb0, 0x4f, 0xb7, 0x97, 0x5f,
+        0xe6, 0xef, 0xa2, 0x55, 0xdd, 0xf7, 0x9e, 0x8d, 0x60, 0x84, 0x1d, 0xdd, 0x3e, 0x0e, 0x45, 0xae,
+    ],
+    // E_191
+    [
+        0x35, 0x55, 0xbf, 0x07, 0xb6, 0x2a, 0x6e, 0xfd, 0xf7, 0x33, 0x8a, 0x66, 0x85, 0x25, 0xfb, 0x3c,
+        0x29, 0xd6, 0x80, 0x1e, 0xb7, 0x14, 0xd6, 0xa9, 0x5b, 0x6b, 0x2e, 0xf2, 0x96, 0xd9, 0x75, 0xd2,
+    ],
+    // E_192
+    [
+        0xb6, 0x40, 0x83, 0x80, 0xad, 0x07, 0xdf, 0x5d, 0xc0, 0x72, 0x02, 0xb9, 0x46, 0x5a, 0x17, 0x68,
+        0xb3, 0xcb, 0x8f, 0x5a, 0x9b, 0x2c, 0xb4, 0xfa, 0x1d, 0x60, 0x4c, 0x2c, 0x9e, 0xa9, 0xaf, 0x9e,
+    ],
+    // E_193
+    [
+        0x60, 0x08, 0x50, 0xad, 0x3f, 0x95, 0x97, 0xea, 0x2e, 0xeb, 0x1d, 0x80, 0xfd, 0x08, 0x41, 0x41,
+        0xbe, 0xdb, 0x2f, 0xb7, 0x17, 0x06, 0x32, 0x66, 0xef, 0xdd, 0x51, 0x5c, 0x54, 0x11, 0xaa, 0x5a,
+    ],
+    // E_194
+    [
+        0x1d, 0x74, 0x00, 0x65, 0x45, 0x5d, 0x4f, 0x52, 0x10, 0xce, 0x15, 0xb6, 0x3c, 0x4c, 0x31, 0x93,
+        0x47, 0xed, 0xa5, 0xc6, 0x05, 0xb8, 0x88, 0x4e, 0xc5, 0x39, 0xa4, 0xd9, 0x04, 0xe1, 0x65, 0xaf,
+    ],
+    // E_195
+    [
+        0xc2, 0xdd, 0xf3, 0xe5, 0xf2, 0xbe, 0x42, 0xdf, 0x3d, 0x11, 0xd4, 0x57, 0x17, 0xa0, 0x21, 0xab,
+        0xc6, 0x2a, 0xe8, 0x17, 0xd2, 0x39, 0xac, 0xcf, 0x70, 0xc6, 0x3a, 0x31, 0xfd, 0x5c, 0x52, 0x04,
+    ],
+    // E_196
+    [
+        0x3c, 0x10, 0x56, 0x82, 0x6f, 0x7a, 0x21, 0xea, 0x07, 0x69, 0x2d, 0x3e, 0x60, 0xe1, 0x72, 0xe1,
+        0x55, 0x83, 0x19, 0x83, 0x8b, 0x20, 0xb2, 0x27, 0xea, 0x48, 0x51, 0x95, 0x61, 0xbd, 0x81, 0x2e,
+    ],
+    // E_197
+    [
+        0x9e, 0x47, 0x6e, 0xa3, 0x51, 0x97, 0x25, 0xb2, 0x6d, 0xd9, 0x6b, 0xfd, 0xe5, 0x33, 0x0a, 0x4e,
+        0x8f, 0xca, 0x16, 0x4f, 0x5c, 0x68, 0x91, 0x84, 0x55, 0xfc, 0xd1, 0x81, 0x87, 0x1a, 0x7f, 0x08,
+    ],
+    // E_198
+    [
+        0x6b, 0x4b, 0x17, 0x60, 0xde, 0x3d, 0x4d, 0x79, 0x56, 0x2e, 0xcd, 0xd0, 0x56, 0x5d, 0x79, 0xfd,
+        0x07, 0xde, 0x4c, 0xee, 0xf7, 0x4c, 0x4f, 0xb0, 0x25, 0x5a, 0x59, 0xad, 0xbf, 0xa1, 0x1b, 0x2c,
+    ],
+    // E_199
+    [
+        0xbc, 0x9b, 0x9c, 0x5e, 0xed, 0xe2, 0x67, 0x60, 0x97, 0x31, 0x3f, 0x31, 0x5e, 0x2d, 0x18, 0xab,
+        0xb9, 0x27, 0x7f, 0x02, 0xda, 0xcd, 0xaa, 0xe5, 0xb1, 0x16, 0x7e, 0x72, 0xa0, 0x14, 0xef, 0xaf,
+    ],
+    // E_200
+    [
+        0x63, 0xb3, 0xcf, 0x7e, 0xc2, 0x54, 0x13, 0x7f, 0xd4, 0x82, 0xae, 0xfe, 0x56, 0x66, 0xe4, 0x73,
+        0xb0, 0x83, 0x3c, 0xa9, 0xfc, 0xb7, 0x6b, 0x00, 0xb3, 0x38, 0x4b, 0xd6, 0xe7, 0x9b, 0x8f, 0x96,
+    ],
+    // E_201
+    [
+        0xa2, 0x71, 0xba, 0xaf, 0xb3, 0x19, 0xf2, 0x5b, 0xed, 0x85, 0x2a, 0xac, 0x1d, 0x28, 0xd9, 0x07,
+        0x84, 0x26, 0xa1, 0x40, 0x28, 0x9d, 0xd6, 0x8d, 0x70, 0xfe, 0x18, 0xe3, 0xb6, 0x7a, 0x16, 0x49,
+    ],
+    // E_202
+    [
+        0x40, 0x1d, 0xc8, 0x0c, 0xc4, 0x29, 0xf6, 0xa6, 0x7d, 0x2e, 0xb8, 0xe2, 0x94, 0xf4, 0xb7, 0xfd,
+        0xf6, 0x63, 0x57, 0x6a, 0x39, 0xb3, 0x99, 0xeb, 0x35, 0xcc, 0xaa, 0x58, 0x42, 0x77, 0x6a, 0xbf,
+    ],
+    // E_203
+    [
+        0xc4, 0x63, 0x15, 0xc7, 0x15, 0x69, 0xbe, 0x02, 0x3a, 0x5c, 0x73, 0xda, 0xce, 0x78, 0xa9, 0x1c,
+        0x20, 0x51, 0x42, 0xb6, 0x9a, 0xb6, 0x38, 0x7e, 0x3c, 0x80, 0xba, 0x0f, 0x68, 0x6c, 0xcb, 0x8e,
+    ],
+    // E_204
+    [
+        0x86, 0xb7, 0x65, 0x6f, 0x8f, 0xe1, 0xc9, 0x61, 0xb1, 0x65, 0x66, 0x46, 0x8b, 0xf6, 0xfc, 0x6b,
+        0x25, 0x43, 0xa1, 0x82, 0xe9, 0x02, 0x34, 0x75, 0xf0, 0xa1, 0xb6, 0x73, 0x70, 0x9e, 0x3d, 0x50,
+    ],
+    // E_205
+    [
+        0xa0, 0x99, 0xd5, 0x0b, 0x28, 0x49, 0xd8, 0x8b, 0xfb, 0xe1, 0x89, 0x0a, 0xcf, 0xf9, 0x5d, 0xee,
+        0xbe, 0xfa, 0x5c, 0xe0, 0x34, 0x2b, 0x62, 0x45, 0xce, 0xc2, 0x8f, 0x68, 0x04, 0x23, 0xd6, 0xfa,
+    ],
+    // E_206
+    [
+        0xc0, 0xd6, 0xb4, 0x28, 0xb3, 0xd5, 0x56, 0x0d, 0xda, 0x08, 0x74, 0xd1, 0x19, 0x8b, 0xaa, 0x9d,
+        0xa9, 0xb6, 0x4d, 0x79, 0x76, 0xf8, 0xff, 0xfc, 0x12, 0xe1, 0xb9, 0x96, 0xd2, 0x7e, 0xb7, 0x14,
+    ],
+    // E_207
+    [
+        0x2c, 0x15, 0x7e, 0xcd, 0xb2, 0x0e, 0xcb, 0xd5, 0x22, 0x63, 0x1a, 0x7a, 0xef, 0x67, 0xe3, 0x82,
+        0x15, 0x63, 0x32, 0xbd, 0xb1, 0xb3, 0x25, 0x34, 0xc1, 0x91, 0x7c, 0x68, 0x0a, 0xee, 0x41, 0x36,
+    ],
+    // E_208
+    [
+        0x14, 0xdd, 0x18, 0x3f, 0xc7, 0x1c, 0xd9, 0x98, 0xa6, 0x50, 0x53, 0x8b, 0x6d, 0xaa, 0x51, 0x85,
+        0xf2, 0xae, 0xea, 0xd7, 0x2c, 0x3f, 0x80, 0x73, 0xdb, 0xd7, 0x79, 0x3a, 0x31, 0x23, 0x9e, 0xbc,
+    ],
+    // E_209
+    [
+        0xe6, 0xfb, 0xd8, 0xb0, 0xfc, 0xdc, 0x67, 0x1b, 0xbe, 0xc5, 0x65, 0x87, 0xca, 0x7c, 0xf4, 0xf8,
+        0x28, 0xb0, 0x58, 0xd7, 0xdc, 0x26, 0x30, 0xe1, 0x80, 0x14, 0x1b, 0xf9, 0xd0, 0x74, 0x76, 0x49,
+    ],
+    // E_210
+    [
+        0xb5, 0x8a, 0x32, 0x08, 0xab, 0xda, 0x51, 0xb2, 0xd9, 0xc5, 0x3c, 0xba, 0x71, 0x70, 0x16, 0x9e,
+        0x08, 0x71, 0xfc, 0xa1, 0x72, 0xf9, 0x27, 0x1a, 0xf6, 0xfe, 0xfb, 0x0e, 0x7f, 0x3e, 0x2b, 0x2e,
+    ],
+    // E_211
+    [
+        0x42, 0xe5, 0x78, 0x24, 0x2f, 0x48, 0x99, 0x08, 0x15, 0x15, 0x59, 0x51, 0xbb, 0x92, 0x40, 0xa1,
+        0x72, 0xeb, 0xb2, 0xeb, 0x8e, 0x29, 0xdf, 0xc0, 0x7f, 0x47, 0x61, 0x40, 0xa0, 0x69, 0xce, 0x1f,
+    ],
+    // E_212
+    [
+        0x2f, 0xec, 0xab, 0x22, 0x4d, 0x91, 0xde, 0x0e, 0x2c, 0xe8, 0xd6, 0xce, 0xf2, 0xbc, 0xfd, 0xc3,
+        0x40, 0x2f, 0x36, 0x2b, 0x24, 0x1d, 0x27, 0xb9, 0xa9, 0xf2, 0x4e, 0x06, 0x7a, 0x3d, 0x30, 0x13,
+    ],
+    // E_213
+    [
+        0x50, 0x31, 0x04, 0x95, 0xd3, 0xcd, 0xab, 0xe9, 0x56, 0xa8, 0x76, 0x66, 0x19, 0xa7, 0x1d, 0xc4,
+        0x05, 0x19, 0x97, 0x27, 0xb3, 0x5d, 0x10, 0x70, 0x86, 0x91, 0xe4, 0xb7, 0x03, 0x84, 0x8f, 0xd2,
+    ],
+    // E_214
+    [
+        0x2d, 0x76, 0x85, 0x6c, 0x0b, 0x94, 0x3c, 0xd4, 0xe9, 0x14, 0xd3, 0xc9, 0x56, 0x66, 0x71, 0x05,
+        0x9c, 0xa9, 0xff, 0xdd, 0x09, 0x53, 0x9d, 0xae, 0xd9, 0x03, 0x29, 0x6a, 0x5b, 0xd3, 0xee, 0x12,
+    ],
+    // E_215
+    [
+        0xa3, 0x48, 0xbc, 0xe4, 0x48, 0x51, 0x5a, 0xc4, 0xad, 0xea, 0x89, 0x9e, 0x35, 0x63, 0xc7, 0x32,
+        0xa0, 0x01, 0xc4, 0xe8, 0xb1, 0x8a, 0x8c, 0x9c, 0xfd, 0x24, 0x55, 0x63, 0x9c, 0x32, 0x52, 0xe9,
+    ],
+    // E_216
+    [
+        0x29, 0x73, 0xe7, 0xdb, 0xf9, 0x95, 0x24, 0xb2, 0x99, 0xc1, 0x52, 0xda, 0xdc, 0x1c, 0xcc, 0x1c,
+        0x65, 0xec, 0xa2, 0x90, 0xde, 0x2e, 0xcd, 0xef, 0x5a, 0xf6, 0xcd, 0xb0, 0x4d, 0x27, 0xac, 0x72,
+    ],
+    // E_217
+    [
+        0x96, 0x81, 0xb5, 0xe1, 0x60, 0x59, 0xbb, 0x00, 0x0e, 0x96, 0x0a, 0x0c, 0x52, 0xa5, 0x6b, 0x6e,
+        0x6e, 0xdf, 0xaf, 0x4f, 0xdd, 0xf8, 0x69, 0x37, 0x31, 0x8f, 0x2d, 0x94, 0x15, 0x85, 0x01, 0x7c,
+    ],
+    // E_218
+    [
+        0x0f, 0x3d, 0x0d, 0x21, 0x72, 0x09, 0xc2, 0x5e, 0xaa, 0x73, 0x49, 0x3c, 0x65, 0xe3, 0x5d, 0xad,
+        0xe4, 0x7e, 0x79, 0xec, 0x4d, 0x3d, 0x41, 0xa8, 0x1a, 0x6d, 0x00, 0xc7, 0xc4, 0xd3, 0x93, 0x27,
+    ],
+    // E_219
+    [
+        0x53, 0x05, 0x89, 0x91, 0xf8, 0xd5, 0xe8, 0x64, 0x4b, 0xa9, 0x52, 0x32, 0x11, 0x31, 0xc2, 0x62,
+        0x51, 0x63, 0x0f, 0xcf, 0x47, 0x6b, 0xc0, 0x20, 0x60, 0x9f, 0xef, 0x32, 0xb6, 0xb7, 0x43, 0x9c,
+    ],
+    // E_220
+    [
+        0x9a, 0x23, 0xb7, 0x51, 0xaf, 0xe9, 0xf3, 0xd8, 0x5f, 0xc8, 0x06, 0x04, 0xdd, 0x07, 0x49, 0x67,
+        0x7b, 0x3e, 0xcb, 0x69, 0xda, 0x27, 0xd0, 0xc4, 0xd2, 0x3a, 0x13, 0x04, 0x92, 0x91, 0xde, 0x28,
+    ],
+    // E_221
+    [
+        0x62, 0xee, 0x6e, 0xf9, 0x8a, 0xea, 0x56, 0x17, 0xed, 0x58, 0x4d, 0x3f, 0xcd, 0x8f, 0x88, 0xa5,
+        0x4f, 0x65, 0xdc, 0xdc, 0xc9, 0xe1, 0x9e, 0x51, 0x15, 0xde, 0xfa, 0xd9, 0x0a, 0x1f, 0x3c, 0x32,
+    ],
+    // E_222
+    [
+        0x5b, 0xab, 0xc3, 0x9f, 0x3a, 0x24, 0x45, 0x95, 0xbe, 0x52, 0xcd, 0xdc, 0x25, 0x61, 0xb6, 0xa6,
+        0xa1, 0x17, 0x44, 0x06, 0xe3, 0xf9, 0x50, 0x76, 0x30, 0xfa, 0x96, 0x04, 0x3e, 0xe4, 0x2b, 0x34,
+    ],
+    // E_223
+    [
+        0xc7, 0x63, 0xfc, 0x33, 0x0f, 0xc0, 0x18, 0x34, 0xc5, 0x85, 0x16, 0x06, 0x02, 0xbf, 0x6f, 0xbb,
+        0x47, 0x63, 0x16, 0xab, 0x3b, 0xd9, 0xeb, 0x7c, 0xd4, 0x66, 0x3f, 0x1c, 0x39, 0x17, 0x8d, 0xc0,
+    ],
+    // E_224
+    [
+        0xfd, 0x2c, 0x0e, 0xae, 0xc5, 0xbd, 0xdc, 0xad, 0x23, 0x79, 0x00, 0x16, 0xe9, 0x87, 0x69, 0xb7,
+        0xfb, 0x09, 0xb3, 0x86, 0xbd, 0xc0, 0xde, 0x06, 0x68, 0x99, 0x9a, 0xaf, 0x2a, 0x4a, 0xcb, 0x5b,
+    ],
+    // E_225
+    [
+        0xc5, 0xfe, 0x9a, 0x34, 0x3e, 0x11, 0xe2, 0x74, 0x74, 0xd1, 0xc7, 0x0b, 0x40, 0x5c, 0xa5, 0x96,
+        0x3e, 0x54, 0x0a, 0x79, 0x15, 0x20, 0xe3, 0xa7, 0x1c, 0xb9, 0x9e, 0xd1, 0x79, 0xe9, 0x7e, 0xa2,
+    ],
+    // E_226
+    [
+        0x22, 0x5d, 0xc1, 0xe2, 0x52, 0x87, 0xda, 0x01, 0xd8, 0xef, 0x9f, 0x24, 0x38, 0xed, 0x70, 0xbc,
+        0x99, 0x2b, 0x56, 0x70, 0xd2, 0x7f, 0x78, 0xa3, 0x50, 0x36, 0xc7, 0x18, 0xe0, 0xeb, 0xac, 0xc2,
+    ],
+    // E_227
+    [
+        0x1b, 0x55, 0x15, 0x73, 0x6d, 0xea, 0xda, 0x4f, 0xfc, 0x41, 0xf3, 0x49, 0x93, 0xaf, 0x0a, 0x6e,
+        0xb8, 0xa9, 0x7b, 0x86, 0xa4, 0x4e, 0x63, 0x64, 0xd9, 0x4e, 0x2c, 0x2a, 0x4d, 0xcb, 0xc7, 0xf9,
+    ],
+    // E_228
+    [
+        0x33, 0x20, 0x26, 0x38, 0x91, 0x22, 0x74, 0x42, 0x05, 0xbd, 0x36, 0x15, 0xa5, 0x31, 0x54, 0x9a,
+        0xb2, 0x5e, 0x2d, 0xca, 0x52, 0x46, 0x63, 0x30, 0x61, 0xd7, 0xbb, 0xa8, 0x85, 0xa1, 0x7a, 0x77,
+    ],
+    // E_229
+    [
+        0x8f, 0x24, 0x72, 0xf0, 0x15, 0x40, 0xdd, 0xdf, 0xb2, 0x1f, 0xa4, 0x9d, 0x73, 0x97, 0x82, 0xdc,
+        0x6e, 0x26, 0x29, 0x92, 0xea, 0xe9, 0xbb, 0xdf, 0x42, 0xcd, 0xde, 0x21, 0xe9, 0x87, 0xea, 0x82,
+    ],
+    // E_230
+    [
+        0x8e, 0x53, 0xcf, 0x42, 0x79, 0x4a, 0xb4, 0xc8, 0xb3, 0x12, 0xd0, 0xf2, 0x05, 0x6e, 0x72, 0x73,
+        0xdf, 0xac, 0x00, 0x80, 0x05, 0x41, 0x99, 0x4c, 0x3c, 0x2e, 0x55, 0xbc, 0x54, 0xa6, 0x9b, 0x0e,
+    ],
+    // E_231
+    [
+        0xe8, 0x1b, 0x21, 0x84, 0x12, 0xce, 0x99, 0xd5, 0x8a, 0x82, 0x95, 0x86, 0xd0, 0x55, 0xec, 0x12,
+        0xc2, 0x2d, 0x4e, 0xf0, 0x17, 0xfd, 0xa8, 0xfa, 0xec, 0x1d, 0x9b, 0x01, 0xf0, 0x5c, 0x1d, 0x5e,
+    ],
+    // E_232
+    [
+        0x1d, 0x5e, 0x72, 0xbb, 0xae, 0xfa, 0x69, 0xe5, 0x14, 0xb5, 0x21, 0x0d, 0x65, 0xae, 0x1b, 0xab,
+        0xa1, 0x17, 0x16, 0x86, 0x58, 0xc7, 0xe3, 0x14, 0x48, 0x33, 0x58, 0x81, 0x99, 0xe5, 0x47, 0x14,
+    ],
+    // E_233
+    [
+        0xa7, 0xa8, 0xa4, 0xe1, 0xcf, 0x80, 0x5d, 0x24, 0x13, 0xf8, 0xac, 0xf5, 0x20, 0xd5, 0x4f, 0x58,
+        0x19, 0x7c, 0x57, 0x0e, 0xd3, 0xf6, 0x58, 0xd2, 0xf6, 0x9e, 0x80, 0x8a, 0x4c, 0x16, 0x8a, 0x32,
+    ],
+    // E_234
+    [
+        0x28, 0xc1, 0xc2, 0x35, 0x02, 0xd0, 0x01, 0x1c, 0x74, 0xff, 0xef, 0x98, 0x8d, 0xdc, 0x6a, 0x48,
+        0x70, 0x48, 0x99, 0xa3, 0x81, 0xd4, 0xa1, 0x15, 0x7f, 0xdd, 0xcf, 0x13, 0xe3, 0xa8, 0x3e, 0x90,
+    ],
+    // E_235
+    [
+        0xc8, 0x5a, 0x6e, 0x1b, 0x80, 0x74, 0xb6, 0x9e, 0x03, 0x8c, 0x13, 0xad, 0x21, 0x29, 0x90, 0x28,
+        0x27, 0x0b, 0x0d, 0xe8, 0xb1, 0xb5, 0xbe, 0x08, 0x88, 0xad, 0x55, 0xed, 0xa2, 0xf7, 0x3d, 0xae,
+    ],
+    // E_236
+    [
+        0x9f, 0x9b, 0x52, 0x12, 0x1b, 0xbb, 0x41, 0xe7, 0x78, 0x42, 0xf1, 0xef, 0xf3, 0xd1, 0x08, 0xf4,
+        0x06, 0x0f, 0x5f, 0x94, 0x16, 0x55, 0x86, 0xcd, 0x45, 0x3b, 0xda, 0xbd, 0x80, 0xf5, 0x40, 0x4d,
+    ],
+    // E_237
+    [
+        0x95, 0x32, 0xbe, 0x4c, 0x2d, 0x32, 0xe3, 0x24, 0xa8, 0x52, 0x73, 0xf9, 0xfb, 0x9f, 0xfc, 0x37,
+        0xc9, 0x54, 0xcb, 0x99, 0xb9, 0x2d, 0x1f, 0x22, 0xa3, 0x37, 0x9c, 0xf6, 0x44, 0x63, 0x22, 0xf5,
+    ],
+    // E_238
+    [
+        0x3c, 0xa2, 0xb0, 0x7a, 0x03, 0x6e, 0xe2, 0x47, 0x7e, 0x94, 0x46, 0x12, 0x8b, 0xae, 0xb2, 0x45,
+        0xe3, 0x4a, 0xcf, 0xf4, 0xd3, 0xbe, 0x4e, 0x05, 0x34, 0xe5, 0x25, 0x8a, 0xbf, 0x42, 0x90, 0xd2,
+    ],
+    // E_239
+    [
+        0xf4, 0x26, 0x68, 0x7d, 0x9e, 0x58, 0xde, 0xde, 0xc3, 0x8c, 0x38, 0x5c, 0x19, 0xc6, 0x99, 0xc1,
+        0xf2, 0x74, 0x8a, 0x3f, 0xeb, 0x81, 0x79, 0x55, 0xef, 0x63, 0x2b, 0x8c, 0x4b, 0xbd, 0xd7, 0x0c,
+    ],
+    // E_240
+    [
+        0x55, 0xca, 0x4c, 0xeb, 0x0b, 0x98, 0x91, 0x05, 0x21, 0xb8, 0x61, 0x1f, 0x7e, 0x3b, 0x8d, 0xf5,
+        0x6e, 0x86, 0x8f, 0xe0, 0x95, 0x2a, 0x0c, 0x81, 0x81, 0xcb, 0xd2, 0x5f, 0xc5, 0xf7, 0x3a, 0x09,
+    ],
+    // E_241
+    [
+        0x66, 0x62, 0xd5, 0x5c, 0xfc, 0xb8, 0x4f, 0x7d, 0x3a, 0xb2, 0x04, 0xe1, 0x35, 0xc1, 0xdc, 0x28,
+        0x06, 0x20, 0x46, 0x4e, 0x9e, 0xdf, 0x2d, 0x0f, 0xc0, 0xc7, 0xa8, 0x96, 0xd1, 0xc1, 0x3f, 0x54,
+    ],
+    // E_242
+    [
+        0xe6, 0x3e, 0xc5, 0xf8, 0x0d, 0x61, 0x4b, 0xd5, 0x70, 0x2e, 0x4f, 0x4a, 0x7e, 0xc9, 0x5b, 0xc2,
+        0xe9, 0x67, 0xdf, 0x5f, 0xae, 0xa7, 0x3e, 0xa8, 0x33, 0x97, 0xb6, 0x9f, 0xf2, 0xec, 0xf5, 0xfd,
+    ],
+    // E_243
+    [
+        0x55, 0xd2, 0x25, 0xec, 0x93, 0x04, 0x4e, 0x06, 0xd7, 0x0e, 0x32, 0xd5, 0x9f, 0x34, 0x67, 0x17,
+        0xbc, 0xfb, 0x44, 0x6a, 0xfb, 0x08, 0xa4, 0xe0, 0xa4, 0xcf, 0x6a, 0x9a, 0xd2, 0x7e, 0xd8, 0xce,
+    ],
+    // E_244
+    [
+        0xcd, 0xd5, 0x27, 0x34, 0x2a, 0x88, 0x9e, 0x53, 0x81, 0xe9, 0xde, 0x9b, 0x5e, 0xc8, 0x2f, 0x6b,
+        0x2b, 0xd7, 0x55, 0x23, 0xee, 0x4f, 0xec, 0x0e, 0xa2, 0xcd, 0x31, 0xb6, 0xa5, 0x1f, 0x54, 0x53,
+    ],
+    // E_245
+    [
+        0x3e, 0x24, 0xd4, 0xc6, 0x8e, 0x5f, 0xb3, 0xbf, 0x79, 0x1e, 0x43, 0x9c, 0xfd, 0xb1, 0xdf, 0x59,
+        0xd5, 0x04, 0xaa, 0x49, 0x9f, 0x3e, 0xc6, 0x99, 0x2c, 0x73, 0xe9, 0xda, 0xaf, 0xce, 0x54, 0xcd,
+    ],
+    // E_246
+    [
+        0xd1, 0x05, 0x62, 0x39, 0xf0, 0x65, 0x40, 0x97, 0xb1, 0x4e, 0xa5, 0x1f, 0xf9, 0x73, 0xd6, 0xbd,
+        0x6c, 0x21, 0xa3, 0x5e, 0xb2, 0x4c, 0x24, 0xad, 0x69, 0x93, 0xea, 0x3c, 0x5f, 0xab, 0x54, 0x83,
+    ],
+    // E_247
+    [
+        0xeb, 0xc9, 0xeb, 0xc5, 0x24, 0xc1, 0x89, 0x02, 0x3e, 0x67, 0xe1, 0xe2, 0x37, 0x10, 0x08, 0xeb,
+        0x07, 0xe3, 0x0b, 0x78, 0x12, 0xa6, 0x87, 0x0f, 0x5d, 0x57, 0x40, 0x2b, 0x7c, 0x21, 0x4b, 0x32,
+    ],
+    // E_248
+    [
+        0x74, 0x80, 0x64, 0xb1, 0x48, 0x15, 0xde, 0x64, 0xb6, 0xf9, 0x4b, 0x29, 0x60, 0x19, 0x55, 0xfb,
+        0xb5, 0xd7, 0xf0, 0x4e, 0xc0, 0xae, 0x3a, 0x0c, 0xe2, 0x33, 0x12, 0xaf, 0x5c, 0xec, 0xf8, 0x0e,
+    ],
+    // E_249
+    [
+        0x69, 0xe1, 0x4b, 0x0b, 0xf6, 0x5d, 0xd4, 0x6b, 0xdf, 0x55, 0x1f, 0x3e, 0xaf, 0xc8, 0x8d, 0x63,
+        0xa3, 0x3d, 0x79, 0x3b, 0x10, 0xb3, 0xb9, 0xe7, 0x16, 0x27, 0x47, 0xd8, 0x1a, 0x97, 0x29, 0x4c,
+    ],
+    // E_250
+    [
+        0xb0, 0x5a, 0xb8, 0xc4, 0x61, 0x13, 0x32, 0xed, 0x70, 0xba, 0x78, 0x73, 0x61, 0x2f, 0x08, 0x35,
+        0xeb, 0x8b, 0x8c, 0x39, 0x9a, 0xf9, 0xb7, 0x05, 0xbb, 0x64, 0x78, 0x75, 0xc6, 0x07, 0x6b, 0x1c,
+    ],
+    // E_251
+    [
+        0xe8, 0x7d, 0x5a, 0x63, 0xf0, 0xe6, 0x5c, 0x51, 0x00, 0x5a, 0x32, 0xb4, 0x92, 0x71, 0x85, 0x1f,
+        0xce, 0x1a, 0x40, 0xa0, 0x5d, 0x3b, 0x65, 0xc5, 0x0d, 0x81, 0x6d, 0xd2, 0x8f, 0x54, 0xd2, 0xe2,
+    ],
+    // E_252
+    [
+        0x4c, 0x59, 0xcb, 0x71, 0x8c, 0xbb, 0x55, 0xe7, 0xe2, 0x08, 0x8d, 0xb8, 0x38, 0xe8, 0xad, 0x73,
+        0xba, 0x09, 0x54, 0xee, 0x20, 0x17, 0x80, 0x38, 0xaf, 0xaa, 0x60, 0xe3, 0x50, 0x49, 0x36, 0x64,
+    ],
+    // E_253
+    [
+        0x95, 0xcd, 0x22, 0x31, 0xa9, 0x91, 0x5b, 0x37, 0x99, 0x3e, 0x8e, 0x7a, 0x41, 0xa9, 0xf6, 0xbc,
+        0xd0, 0xac, 0x29, 0x24, 0xc2, 0x7f, 0x93, 0x8c, 0x31, 0x96, 0x44, 0xec, 0xdb, 0xce, 0xee, 0x43,
+    ],
+    // E_254
+    [
+        0xee, 0x51, 0x05, 0x0f, 0xd8, 0x61, 0x4d, 0x8a, 0xb5, 0xc9, 0x19, 0x65, 0x51, 0xf2, 0x6a, 0x78,
+        0x40, 0x4f, 0x78, 0x27, 0xf4, 0xc6, 0x7c, 0xe0, 0x25, 0x95, 0xea, 0x39, 0xe3, 0xd9, 0x85, 0xff,
+    ],
+    // E_255
+    [
+        0x9e, 0x2e, 0xc1, 0x3c, 0x47, 0x54, 0x5e, 0xa1, 0x6f, 0x61, 0x8a, 0x27, 0xaf, 0x95, 0x83, 0x04,
+        0xeb, 0xa6, 0x07, 0x4e, 0xbb, 0xc9, 0xef, 0x8f, 0xde, 0x62, 0x17, 0x1e, 0x6c, 0x2c, 0xc3, 0x03,
+    ],
+    // E_256
+    [
+        0x44, 0x31, 0xd0, 0x77, 0x8b, 0x30, 0x8f, 0x6f, 0x51, 0x0a, 0xd4, 0x45, 0xa3, 0x64, 0x13, 0x61,
+        0x9d, 0xb8, 0x7b, 0xb4, 0x82, 0x4c, 0xb8, 0xea, 0x7f, 0x3d, 0x81, 0xb9, 0xf6, 0x64, 0x0b, 0xa7,
+    ],
+];
+
+/// Bit of `path` at height `h` (0 = leaf level): 0 → left child, 1 → right.
+fn path_bit(path: &[u8; 32], h: u32) -> bool {
+    let depth = SMT_DEPTH - 1 - h;
+    (path[(depth / 8) as usize] >> (7 - depth % 8)) & 1 == 1
+}
+
+fn bitmap_bit(bitmap: &[u8; 32], h: u32) -> bool {
+    (bitmap[31 - (h / 8) as usize] >> (h % 8)) & 1 == 1
+}
+
+/// Root of the tree obtained by placing `leaf` at `key`'s position, with the
+/// rest of the tree described by `proof`.  `None` if the proof is malformed.
+pub fn root_with(env: &Env, key: &Bytes, leaf: &BytesN<32>, proof: &SmtProof) -> Option<BytesN<32>> {
+    let path: [u8; 32] = env.crypto().sha256(key).to_array();
+    let bitmap = proof.bitmap.to_array();
+
+    let mut node = leaf.clone();
+    let mut next_sibling = 0u32;
+    for h in 0..SMT_DEPTH {
+        let sibling = if bitmap_bit(&bitmap, h) {
+            let s = proof.siblings.get(next_sibling)?;
+            next_sibling += 1;
+            s
+        } else {
+            BytesN::from_array(env, &EMPTY_SUBTREES[h as usize])
+        };
+        node = if path_bit(&path, h) {
+            node_hash(env, &sibling, &node)
+        } else {
+            node_hash(env, &node, &sibling)
+        };
+    }
+
+    if next_sibling != proof.siblings.len() {
+        return None;
+    }
+    Some(node)
+}
+
+/// Leaf value stored for a member key.
+pub fn member_leaf(env: &Env, key: &Bytes) -> BytesN<32> {
+    leaf_hash(env, key)
+}
+
+/// Root of the empty tree, E_256.
+pub fn empty_root(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &EMPTY_SUBTREES[SMT_DEPTH as usize])
+}
+
+/// `key` is a member of the tree with root `root`.
+pub fn verify_membership(env: &Env, root: &BytesN<32>, key: &Bytes, proof: &SmtProof) -> bool {
+    root_with(env, key, &member_leaf(env, key), proof).as_ref() == Some(root)
+}
+
+/// `key` is not a member of the tree with root `root`: its slot holds
+/// `EMPTY_LEAF`.
+pub fn verify_non_membership(env: &Env, root: &BytesN<32>, key: &Bytes, proof: &SmtProof) -> bool {
+    let empty = BytesN::from_array(env, &EMPTY_LEAF);
+    root_with(env, key, &empty, proof).as_ref() == Some(root)
+}
@@ -3,12 +3,49 @@
 extern crate std;
 
 use soroban_sdk::{
-    testutils::{Ledger, LedgerInfo},
-    Bytes, BytesN, Env, Vec,
+    contract, contractimpl,
+    testutils::{
+        storage::Persistent as _, Address as _, AuthorizedFunction, Events as _, Ledger,
+        LedgerInfo,
+    },
+    symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
 };
 
+use crate::error::ContractError;
+use crate::events;
+use crate::types::{
+    AccountStatus, Committee, CpProof, DataKey, DecryptionShare, DisputePolicy, DisputeWindow,
+    ElectionConfig, ElectionParams, ElectionParamsV1, ElectionPhase, ElectionStatus, EligibilityMode,
+    EligibilityProof, EncryptedBallotV1, IssueItem, KhBond, MerkleMultiProof, ShareBatch, ShareEvidence, ShareProof,
+    SmtProof, StakePolicy, TokenGate, VersionedBallot, VersionedElection, SHARE_BATCH_VERSION,
+};
 use crate::{ElectionContract, ElectionContractClient};
 
+// ── Mock SEP-41 token (balance and transfer) ──────────────────────────────────
+
+#[contract]
+struct MockToken;
+
+#[contractimpl]
+impl MockToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        let balance: i128 = env.storage().persistent().get(&to).unwrap_or(0);
+        env.storage().persistent().set(&to, &(balance + amount));
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage().persistent().get(&id).unwrap_or(0)
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        let balance: i128 = env.storage().persistent().get(&from).unwrap_or(0);
+        assert!(balance >= amount, "insufficient balance");
+        env.storage().persistent().set(&from, &(balance - amount));
+        Self::mint(env, to, amount);
+    }
+}
+
 // ── Signing helpers (std-only, uses ed25519-dalek) ────────────────────────────
 
 fn bytes_to_vec(b: &Bytes) -> std::vec::Vec<u8> {
@@ -57,12 +94,22 @@ fn fake_nf(env: &Env, seed: u8) -> BytesN<32> {
 
 // ── Message construction (mirrors contract logic) ─────────────────────────────
 
-fn mk_issue_msg(env: &Env, eid: u64, pk_cast: &BytesN<32>, nf_issue: &BytesN<32>) -> Bytes {
+fn mk_issue_msg(
+    env: &Env,
+    eid: u64,
+    pk_cast: &BytesN<32>,
+    nf_issue: &BytesN<32>,
+    voter: Option<&Bytes>,
+) -> Bytes {
     let mut data = Bytes::new(env);
     data.extend_from_slice(b"stellot:issue");
     data.extend_from_slice(&eid.to_le_bytes());
     data.append(&pk_cast.into());
     data.append(&nf_issue.into());
+    match voter {
+        Some(voter) => data.append(&crate::merkle::leaf_hash(env, voter).into()),
+        None => data.extend_from_slice(&[0u8; 32]),
+    }
     env.crypto().sha256(&data).into()
 }
 
@@ -84,6 +131,138 @@ fn mk_shares_msg(env: &Env, eid: u64, blob: &Bytes) -> Bytes {
     env.crypto().sha256(&data).into()
 }
 
+/// Mirrors `board_step`: SHA256("stellot:board" || head || cast_msg).
+fn mk_board_step(env: &Env, eid: u64, head: &BytesN<32>, nf: &BytesN<32>, c1: &Bytes, c2: &Bytes) -> BytesN<32> {
+    let mut data = Bytes::new(env);
+    data.extend_from_slice(b"stellot:board");
+    data.append(&head.into());
+    data.append(&mk_cast_msg(env, eid, nf, c1, c2));
+    env.crypto().sha256(&data).into()
+}
+
+fn zero_head(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[0u8; 32])
+}
+
+// ── Dense Merkle helpers (mirror `merkle.rs`) ───────────────────────────────
+
+/// All levels of a complete tree over `leaves` (power-of-two count), leaves first.
+fn dense_levels(env: &Env, leaves: &[Bytes]) -> std::vec::Vec<std::vec::Vec<BytesN<32>>> {
+    let mut levels = std::vec![leaves.iter().map(|l| crate::merkle::leaf_hash(env, l)).collect::<std::vec::Vec<_>>()];
+    while levels.last().unwrap().len() > 1 {
+        let prev = levels.last().unwrap();
+        let next = prev.chunks(2).map(|p| crate::merkle::node_hash(env, &p[0], &p[1])).collect();
+        levels.push(next);
+    }
+    levels
+}
+
+/// Multiproof siblings for sorted `indices`, in `verify_multi` order.
+fn dense_multiproof(env: &Env, levels: &[std::vec::Vec<BytesN<32>>], indices: &[u32]) -> Vec<BytesN<32>> {
+    let mut siblings = Vec::new(env);
+    let mut known: std::vec::Vec<u32> = indices.to_vec();
+    for level in &levels[..levels.len() - 1] {
+        for &i in &known {
+            if !known.contains(&(i ^ 1)) {
+                siblings.push_back(level[(i ^ 1) as usize].clone());
+            }
+        }
+        known = known.iter().map(|i| i >> 1).collect();
+        known.dedup();
+    }
+    siblings
+}
+
+// ── Sparse Merkle helpers (mirror `smt.rs`) ──────────────────────────────────
+
+/// E_h for h = 0..=256: hash of an empty subtree of height h.
+fn smt_empties(env: &Env) -> std::vec::Vec<BytesN<32>> {
+    let mut empties = std::vec![BytesN::from_array(env, &crate::smt::EMPTY_LEAF)];
+    for h in 0..crate::smt::SMT_DEPTH as usize {
+        empties.push(crate::merkle::node_hash(env, &empties[h], &empties[h]));
+    }
+    empties
+}
+
+fn smt_path(env: &Env, key: &Bytes) -> [u8; 32] {
+    env.crypto().sha256(key).to_array()
+}
+
+/// Bit of `path` at `depth` from the root (0 = first branch).
+fn smt_bit(path: &[u8; 32], depth: usize) -> bool {
+    (path[depth / 8] >> (7 - depth % 8)) & 1 == 1
+}
+
+/// Hash of the subtree at `depth` containing exactly `members` (paths).
+fn smt_subtree(env: &Env, empties: &[BytesN<32>], members: &[([u8; 32], Bytes)], depth: usize) -> BytesN<32> {
+    if members.is_empty() {
+        return empties[256 - depth].clone();
+    }
+    if depth == 256 {
+        return crate::merkle::leaf_hash(env, &members[0].1);
+    }
+    let (right, left): (std::vec::Vec<_>, std::vec::Vec<_>) =
+        members.iter().cloned().partition(|(p, _)| smt_bit(p, depth));
+    crate::merkle::node_hash(
+        env,
+        &smt_subtree(env, empties, &left, depth + 1),
+        &smt_subtree(env, empties, &right, depth + 1),
+    )
+}
+
+/// Off-chain registry: root of, and compressed proofs against, a member set.
+struct TestRegistry {
+    members: std::vec::Vec<([u8; 32], Bytes)>,
+    empties: std::vec::Vec<BytesN<32>>,
+}
+
+impl TestRegistry {
+    fn new(env: &Env) -> Self {
+        Self { members: std::vec::Vec::new(), empties: smt_empties(env) }
+    }
+
+    fn insert(&mut self, env: &Env, key: &Bytes) {
+        self.members.push((smt_path(env, key), key.clone()));
+    }
+
+    fn remove(&mut self, env: &Env, key: &Bytes) {
+        let path = smt_path(env, key);
+        self.members.retain(|(p, _)| *p != path);
+    }
+
+    fn root(&self, env: &Env) -> BytesN<32> {
+        smt_subtree(env, &self.empties, &self.members, 0)
+    }
+
+    /// Proof for `key`'s slot (membership or emptiness, per current set).
+    fn prove(&self, env: &Env, key: &Bytes) -> SmtProof {
+        let path = smt_path(env, key);
+        let mut bitmap = [0u8; 32];
+        let mut siblings: std::vec::Vec<BytesN<32>> = std::vec::Vec::new();
+        let mut rest: std::vec::Vec<_> = self.members.iter().filter(|(p, _)| *p != path).cloned().collect();
+        // Top-down: at each depth split off the members on the other side
+        let mut top_down = std::vec::Vec::new();
+        for depth in 0..256 {
+            let bit = smt_bit(&path, depth);
+            let (same, other): (std::vec::Vec<_>, std::vec::Vec<_>) =
+                rest.into_iter().partition(|(p, _)| smt_bit(p, depth) == bit);
+            top_down.push(smt_subtree(env, &self.empties, &other, depth + 1));
+            rest = same;
+        }
+        for (h, sibling) in top_down.into_iter().rev().enumerate() {
+            if sibling != self.empties[h] {
+                bitmap[31 - h / 8] |= 1 << (h % 8);
+                siblings.push(sibling);
+            }
+        }
+        let mut sv: Vec<BytesN<32>> = Vec::new(env);
+        for s in siblings {
+            sv.push_back(s);
+        }
+        SmtProof { bitmap: BytesN::from_array(env, &bitmap), siblings: sv }
+    }
+}
+
 // ── Ledger helpers ────────────────────────────────────────────────────────────
 
 fn advance_time(env: &Env, delta: u64) {
@@ -123,11 +302,56 @@ fn deploy_election(
     kh_threshold: u32,
     start_offset: u64,
     end_offset: u64,
+) -> u64 {
+    let config = ElectionConfig {
+        revoting: false,
+        max_end_time: env.ledger().timestamp() + end_offset,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    deploy_election_with_config(
+        env, client, kh_keys, dist_keys, dist_threshold, kh_threshold,
+        start_offset, end_offset, config,
+    )
+}
+
+fn deploy_election_with_config(
+    env: &Env,
+    client: &ElectionContractClient,
+    kh_keys: &[&TestKey],
+    dist_keys: &[&TestKey],
+    dist_threshold: u32,
+    kh_threshold: u32,
+    start_offset: u64,
+    end_offset: u64,
+    config: ElectionConfig,
+) -> u64 {
+    let eligibility_root = match config.eligibility {
+        EligibilityMode::Registry => smt_empties(env)[crate::smt::SMT_DEPTH as usize].clone(),
+        _ => fake_nf(env, 0x42),
+    };
+    deploy_election_with_root(
+        env, client, kh_keys, dist_keys, dist_threshold, kh_threshold,
+        start_offset, end_offset, config, eligibility_root,
+    )
+}
+
+fn deploy_election_with_root(
+    env: &Env,
+    client: &ElectionContractClient,
+    kh_keys: &[&TestKey],
+    dist_keys: &[&TestKey],
+    dist_threshold: u32,
+    kh_threshold: u32,
+    start_offset: u64,
+    end_offset: u64,
+    config: ElectionConfig,
+    eligibility_root: BytesN<32>,
 ) -> u64 {
     let now = env.ledger().timestamp();
     let title = Bytes::from_slice(env, b"Test Election");
     let enc_pubkey = fake_point(env, 0xAA);
-    let eligibility_root = fake_nf(env, 0x42);
 
     let mut dist_roster: Vec<BytesN<32>> = Vec::new(env);
     for k in dist_keys {
@@ -139,16 +363,16 @@ fn deploy_election(
     }
 
     let eid = client.deploy(
+        &Address::generate(env),
         &title,
         &2u32,
         &(now + start_offset),
         &(now + end_offset),
         &enc_pubkey,
         &eligibility_root,
-        &dist_roster,
-        &dist_threshold,
-        &kh_roster,
-        &kh_threshold,
+        &Committee { members: dist_roster, threshold: dist_threshold },
+        &Committee { members: kh_roster, threshold: kh_threshold },
+        &config,
     );
 
     for i in 0..kh_keys.len() {
@@ -166,12 +390,12 @@ fn issue_account(
     nf_issue: &BytesN<32>,
     dist_keys: &[&TestKey],
 ) {
-    let msg = mk_issue_msg(env, eid, pk_cast, nf_issue);
+    let msg = mk_issue_msg(env, eid, pk_cast, nf_issue, None);
     let mut sigs: Vec<(BytesN<32>, BytesN<64>)> = Vec::new(env);
     for k in dist_keys {
         sigs.push_back((k.pk_bytes_n(env), k.sign_bytes(env, &msg)));
     }
-    client.issue_account(&eid, pk_cast, nf_issue, &sigs);
+    client.issue_account(&eid, pk_cast, nf_issue, &None, &None, &sigs);
 }
 
 fn cast_ballot(
@@ -188,25 +412,44 @@ fn cast_ballot(
     client.cast(&eid, nf_cast, c1, c2, &cast_key.pk_bytes_n(env), &sig)
 }
 
+/// One fake share per ballot on the board, in order.
+fn share_batch(env: &Env, client: &ElectionContractClient, eid: u64) -> ShareBatch {
+    let mut shares = Vec::new(env);
+    for (i, ballot) in client.get_ballots(&eid, &0, &100).iter().enumerate() {
+        shares.push_back(DecryptionShare {
+            ballot_index: i as u32,
+            c1: ballot.c1,
+            d: fake_point(env, i as u8 + 10),
+            proof: ShareProof::None,
+        });
+    }
+    ShareBatch { version: SHARE_BATCH_VERSION, shares }
+}
+
+fn try_post_batch(
+    env: &Env,
+    client: &ElectionContractClient,
+    eid: u64,
+    kh_key: &TestKey,
+    kh_idx: u32,
+    batch: &ShareBatch,
+) -> Result<u32, ContractError> {
+    let blob = crate::serialise_share_batch(env, batch);
+    let sig = kh_key.sign_bytes(env, &mk_shares_msg(env, eid, &blob));
+    client
+        .try_post_share(&eid, &kh_idx, batch, &kh_key.pk_bytes_n(env), &sig)
+        .map(|count| count.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
 fn post_kh_share(
     env: &Env,
     client: &ElectionContractClient,
     eid: u64,
     kh_key: &TestKey,
     kh_idx: u32,
-    ballot_count: u32,
 ) {
-    let mut shares: Vec<(Bytes, Bytes)> = Vec::new(env);
-    for i in 0..ballot_count {
-        shares.push_back((
-            fake_point(env, i as u8 + 1),
-            fake_point(env, i as u8 + 10),
-        ));
-    }
-    let blob = crate::serialise_shares(env, &shares);
-    let msg = mk_shares_msg(env, eid, &blob);
-    let sig = kh_key.sign_bytes(env, &msg);
-    client.post_share(&eid, &kh_idx, &shares, &kh_key.pk_bytes_n(env), &sig);
+    try_post_batch(env, client, eid, kh_key, kh_idx, &share_batch(env, client, eid)).unwrap();
 }
 
 // ── Test 1: Full happy-path flow ──────────────────────────────────────────────
@@ -217,7 +460,7 @@ fn test_full_flow() {
     env.mock_all_auths();
     set_timestamp(&env, 1000);
 
-    let contract_id = env.register(ElectionContract, ());
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
     let client = ElectionContractClient::new(&env, &contract_id);
 
     let kh1 = TestKey::from_seed(1);
@@ -254,8 +497,8 @@ fn test_full_flow() {
     // Advance past end_time
     advance_time(&env, 300);
 
-    post_kh_share(&env, &client, eid, &kh1, 0, 2);
-    post_kh_share(&env, &client, eid, &kh2, 1, 2);
+    post_kh_share(&env, &client, eid, &kh1, 0);
+    post_kh_share(&env, &client, eid, &kh2, 1);
     assert_eq!(client.get_share_count(&eid), 2);
 
     let mut tally: Vec<u32> = Vec::new(&env);
@@ -277,7 +520,7 @@ fn test_duplicate_cast_rejected() {
     env.mock_all_auths();
     set_timestamp(&env, 1000);
 
-    let contract_id = env.register(ElectionContract, ());
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
     let client = ElectionContractClient::new(&env, &contract_id);
 
     let kh1 = TestKey::from_seed(1);
@@ -313,7 +556,7 @@ fn test_duplicate_issue_rejected() {
     env.mock_all_auths();
     set_timestamp(&env, 1000);
 
-    let contract_id = env.register(ElectionContract, ());
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
     let client = ElectionContractClient::new(&env, &contract_id);
 
     let kh1 = TestKey::from_seed(1);
@@ -326,10 +569,10 @@ fn test_duplicate_issue_rejected() {
     issue_account(&env, &client, eid, &voter1.pk_bytes_n(&env), &nf_issue, &[&dist1]);
 
     // Second issue with same nullifier → AlreadyIssued (#4)
-    let msg = mk_issue_msg(&env, eid, &voter1.pk_bytes_n(&env), &nf_issue);
+    let msg = mk_issue_msg(&env, eid, &voter1.pk_bytes_n(&env), &nf_issue, None);
     let mut sigs: Vec<(BytesN<32>, BytesN<64>)> = Vec::new(&env);
     sigs.push_back((dist1.pk_bytes_n(&env), dist1.sign_bytes(&env, &msg)));
-    let result = client.try_issue_account(&eid, &voter1.pk_bytes_n(&env), &nf_issue, &sigs);
+    let result = client.try_issue_account(&eid, &voter1.pk_bytes_n(&env), &nf_issue, &None, &None, &sigs);
     assert!(result.is_err() || result.unwrap().is_err(),
         "expected AlreadyIssued error");
 }
@@ -342,7 +585,7 @@ fn test_cast_outside_window() {
     env.mock_all_auths();
     set_timestamp(&env, 1000);
 
-    let contract_id = env.register(ElectionContract, ());
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
     let client = ElectionContractClient::new(&env, &contract_id);
 
     let kh1 = TestKey::from_seed(1);
@@ -378,7 +621,7 @@ fn test_unregistered_caster() {
     env.mock_all_auths();
     set_timestamp(&env, 1000);
 
-    let contract_id = env.register(ElectionContract, ());
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
     let client = ElectionContractClient::new(&env, &contract_id);
 
     let kh1 = TestKey::from_seed(1);
@@ -408,7 +651,7 @@ fn test_tally_before_threshold() {
     env.mock_all_auths();
     set_timestamp(&env, 1000);
 
-    let contract_id = env.register(ElectionContract, ());
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
     let client = ElectionContractClient::new(&env, &contract_id);
 
     let kh1 = TestKey::from_seed(1);
@@ -419,7 +662,7 @@ fn test_tally_before_threshold() {
     advance_time(&env, 200);
 
     // Only 1-of-2 shares posted
-    post_kh_share(&env, &client, eid, &kh1, 0, 0);
+    post_kh_share(&env, &client, eid, &kh1, 0);
 
     let mut tally: Vec<u32> = Vec::new(&env);
     tally.push_back(0u32);
@@ -437,7 +680,7 @@ fn test_tally_wrong_length() {
     env.mock_all_auths();
     set_timestamp(&env, 1000);
 
-    let contract_id = env.register(ElectionContract, ());
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
     let client = ElectionContractClient::new(&env, &contract_id);
 
     let kh1 = TestKey::from_seed(1);
@@ -446,7 +689,7 @@ fn test_tally_wrong_length() {
     let eid = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 120);
     advance_time(&env, 200);
 
-    post_kh_share(&env, &client, eid, &kh1, 0, 0);
+    post_kh_share(&env, &client, eid, &kh1, 0);
 
     // options_count = 2 but we pass 3 entries → InvalidTally (#12)
     let mut tally: Vec<u32> = Vec::new(&env);
@@ -457,3 +700,2071 @@ fn test_tally_wrong_length() {
     assert!(result.is_err() || result.unwrap().is_err(),
         "expected InvalidTally error");
 }
+
+// ── Test 8: One ballot per casting account ───────────────────────────────────
+
+#[test]
+fn test_second_ballot_same_account_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let voter1 = TestKey::from_seed(4);
+
+    let eid = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    advance_time(&env, 15);
+
+    let pk_cast = voter1.pk_bytes_n(&env);
+    issue_account(&env, &client, eid, &pk_cast, &fake_nf(&env, 0x01), &[&dist1]);
+
+    let account = client.get_casting_account(&eid, &pk_cast).unwrap();
+    assert_eq!(account.status, AccountStatus::Issued);
+    assert_eq!(account.issued_at, 1015);
+    assert_eq!(account.ballot_index, None);
+
+    let c1 = fake_point(&env, 0x01);
+    let c2 = fake_point(&env, 0x02);
+    cast_ballot(&env, &client, eid, &voter1, &fake_nf(&env, 0x10), &c1, &c2);
+
+    let account = client.get_casting_account(&eid, &pk_cast).unwrap();
+    assert_eq!(account.status, AccountStatus::Voted);
+    assert_eq!(account.ballot_index, Some(0));
+
+    // Fresh nf_cast from the same account → AlreadyVoted (#5)
+    let nf_cast2 = fake_nf(&env, 0x11);
+    let msg = mk_cast_msg(&env, eid, &nf_cast2, &c1, &c2);
+    let sig = voter1.sign_bytes(&env, &msg);
+    let result = client.try_cast(&eid, &nf_cast2, &c1, &c2, &pk_cast, &sig);
+    assert_eq!(result, Err(Ok(ContractError::AlreadyVoted)));
+    assert_eq!(client.get_ballot_count(&eid), 1);
+
+    // Re-issuing the same account under a fresh nf_issue must not reset it
+    let nf_issue2 = fake_nf(&env, 0x02);
+    let msg = mk_issue_msg(&env, eid, &pk_cast, &nf_issue2, None);
+    let sigs = Vec::from_array(&env, [(dist1.pk_bytes_n(&env), dist1.sign_bytes(&env, &msg))]);
+    let result = client.try_issue_account(&eid, &pk_cast, &nf_issue2, &None, &None, &sigs);
+    assert_eq!(result, Err(Ok(ContractError::CastingAccountExists)));
+    let account = client.get_casting_account(&eid, &pk_cast).unwrap();
+    assert_eq!(account.status, AccountStatus::Voted);
+    assert_eq!(account.ballot_index, Some(0));
+    assert_eq!(client.get_summary(&eid).issued_count, 1);
+}
+
+// ── Test 9: Revoting supersedes the previous ballot ──────────────────────────
+
+#[test]
+fn test_revote_supersedes_ballot() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let voter1 = TestKey::from_seed(4);
+    let voter2 = TestKey::from_seed(5);
+
+    let config = ElectionConfig {
+        revoting: true,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let eid = deploy_election_with_config(
+        &env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200, config,
+    );
+    advance_time(&env, 15);
+
+    issue_account(&env, &client, eid, &voter1.pk_bytes_n(&env), &fake_nf(&env, 0x01), &[&dist1]);
+    issue_account(&env, &client, eid, &voter2.pk_bytes_n(&env), &fake_nf(&env, 0x02), &[&dist1]);
+
+    let idx1 = cast_ballot(&env, &client, eid, &voter1, &fake_nf(&env, 0x10),
+        &fake_point(&env, 0x01), &fake_point(&env, 0x02));
+    let idx2 = cast_ballot(&env, &client, eid, &voter2, &fake_nf(&env, 0x20),
+        &fake_point(&env, 0x03), &fake_point(&env, 0x04));
+    assert_eq!((idx1, idx2), (0, 1));
+
+    // voter1 changes their mind: same slot, new contents
+    let c1 = fake_point(&env, 0x05);
+    let c2 = fake_point(&env, 0x06);
+    let idx = cast_ballot(&env, &client, eid, &voter1, &fake_nf(&env, 0x11), &c1, &c2);
+    assert_eq!(idx, 0);
+    assert_eq!(client.get_ballot_count(&eid), 2);
+
+    let ballot = client.get_ballot(&eid, &0).unwrap();
+    assert_eq!(ballot.nf_cast, fake_nf(&env, 0x11));
+    assert_eq!(ballot.c1, c1);
+    assert_eq!(ballot.c2, c2);
+}
+
+// ── Test 10: Pause blocks voting until resumed ───────────────────────────────
+
+#[test]
+fn test_pause_and_resume() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let voter1 = TestKey::from_seed(4);
+
+    let eid = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    advance_time(&env, 15);
+    issue_account(&env, &client, eid, &voter1.pk_bytes_n(&env), &fake_nf(&env, 0x01), &[&dist1]);
+
+    // pause requires the organizer's signature
+    let organizer = client.get_election(&eid).unwrap().organizer;
+    client.pause(&eid);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            organizer,
+            soroban_sdk::testutils::AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    contract_id.clone(),
+                    Symbol::new(&env, "pause"),
+                    (eid,).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            },
+        )]
+    );
+    assert_eq!(client.get_election(&eid).unwrap().status, ElectionStatus::Paused);
+    assert_eq!(client.try_pause(&eid), Err(Ok(ContractError::ElectionPaused)));
+
+    let nf_cast = fake_nf(&env, 0x10);
+    let c1 = fake_point(&env, 0x01);
+    let c2 = fake_point(&env, 0x02);
+    let msg = mk_cast_msg(&env, eid, &nf_cast, &c1, &c2);
+    let sig = voter1.sign_bytes(&env, &msg);
+    let result = client.try_cast(&eid, &nf_cast, &c1, &c2, &voter1.pk_bytes_n(&env), &sig);
+    assert_eq!(result, Err(Ok(ContractError::ElectionPaused)));
+
+    client.resume(&eid);
+    assert_eq!(client.try_resume(&eid), Err(Ok(ContractError::NotPaused)));
+
+    let idx = client.cast(&eid, &nf_cast, &c1, &c2, &voter1.pk_bytes_n(&env), &sig);
+    assert_eq!(idx, 0);
+}
+
+// ── Test 11: Cancelled election is retained read-only ────────────────────────
+
+#[test]
+fn test_cancel_retains_election() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let voter1 = TestKey::from_seed(4);
+
+    let eid = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    advance_time(&env, 15);
+    issue_account(&env, &client, eid, &voter1.pk_bytes_n(&env), &fake_nf(&env, 0x01), &[&dist1]);
+    cast_ballot(&env, &client, eid, &voter1, &fake_nf(&env, 0x10),
+        &fake_point(&env, 0x01), &fake_point(&env, 0x02));
+
+    client.pause(&eid);
+    let reason = Bytes::from_slice(&env, b"KH key compromised");
+    client.cancel(&eid, &reason);
+
+    let params = client.get_election(&eid).unwrap();
+    assert_eq!(params.status, ElectionStatus::Cancelled);
+    let cancellation = client.get_cancellation(&eid).unwrap();
+    assert_eq!(cancellation.reason, reason);
+    assert_eq!(cancellation.cancelled_at, 1015);
+    assert_eq!(client.try_resume(&eid), Err(Ok(ContractError::NotPaused)));
+
+    // No further issuance, shares, tally or deletion
+    let nf_issue = fake_nf(&env, 0x02);
+    let pk_cast = TestKey::from_seed(5).pk_bytes_n(&env);
+    let msg = mk_issue_msg(&env, eid, &pk_cast, &nf_issue, None);
+    let mut sigs: Vec<(BytesN<32>, BytesN<64>)> = Vec::new(&env);
+    sigs.push_back((dist1.pk_bytes_n(&env), dist1.sign_bytes(&env, &msg)));
+    assert_eq!(
+        client.try_issue_account(&eid, &pk_cast, &nf_issue, &None, &None, &sigs),
+        Err(Ok(ContractError::ElectionCancelled))
+    );
+
+    advance_time(&env, 300);
+    let batch = share_batch(&env, &client, eid);
+    assert_eq!(
+        try_post_batch(&env, &client, eid, &kh1, 0, &batch),
+        Err(ContractError::ElectionCancelled)
+    );
+    let mut tally: Vec<u32> = Vec::new(&env);
+    tally.push_back(1u32);
+    tally.push_back(0u32);
+    assert_eq!(client.try_finalize_tally(&eid, &tally), Err(Ok(ContractError::ElectionCancelled)));
+    assert_eq!(client.try_delete_election(&eid, &0, &20), Err(Ok(ContractError::ElectionCancelled)));
+
+    // Ballots stay readable
+    assert_eq!(client.get_ballot_count(&eid), 1);
+    assert!(client.get_ballot(&eid, &0).is_some());
+}
+
+// ── Test 12: Voting window extension ─────────────────────────────────────────
+
+#[test]
+fn test_extend_voting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+
+    // end = 1200, may be pushed out to 1500 at most
+    let config = ElectionConfig {
+        revoting: false,
+        max_end_time: 1500,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let eid = deploy_election_with_config(
+        &env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200, config,
+    );
+    advance_time(&env, 100);
+
+    // Backwards, unchanged and beyond the cap are all refused
+    assert_eq!(client.try_extend_voting(&eid, &1150), Err(Ok(ContractError::InvalidSchedule)));
+    assert_eq!(client.try_extend_voting(&eid, &1200), Err(Ok(ContractError::InvalidSchedule)));
+    assert_eq!(client.try_extend_voting(&eid, &1501), Err(Ok(ContractError::InvalidSchedule)));
+
+    client.extend_voting(&eid, &1300);
+    client.extend_voting(&eid, &1500);
+    assert_eq!(client.get_election(&eid).unwrap().end_time, 1500);
+
+    let history = client.get_schedule_history(&eid);
+    assert_eq!(history.len(), 2);
+    let first = history.get(0).unwrap();
+    assert_eq!((first.changed_at, first.old_end_time, first.new_end_time), (1100, 1200, 1300));
+    let second = history.get(1).unwrap();
+    assert_eq!((second.old_end_time, second.new_end_time), (1300, 1500));
+
+    // Once the deadline has passed it can no longer be moved
+    set_timestamp(&env, 1500);
+    assert_eq!(client.try_extend_voting(&eid, &1500), Err(Ok(ContractError::OutsideVotingWindow)));
+}
+
+// ── Test 13: Entries outlive the election and can be bumped ──────────────────
+
+#[test]
+fn test_ttl_covers_schedule_and_bump() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let voter1 = TestKey::from_seed(4);
+
+    // end = 1200, extendable to 1500
+    let config = ElectionConfig {
+        revoting: false,
+        max_end_time: 1500,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let eid = deploy_election_with_config(
+        &env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200, config,
+    );
+    advance_time(&env, 15);
+
+    let pk_cast = voter1.pk_bytes_n(&env);
+    let nf_issue = fake_nf(&env, 0x01);
+    let nf_cast = fake_nf(&env, 0x10);
+    issue_account(&env, &client, eid, &pk_cast, &nf_issue, &[&dist1]);
+    cast_ballot(&env, &client, eid, &voter1, &nf_cast, &fake_point(&env, 0x01), &fake_point(&env, 0x02));
+
+    let ttl_of = |key: DataKey| {
+        env.as_contract(&contract_id, || env.storage().persistent().get_ttl(&key))
+    };
+    let keys = [
+        DataKey::Election(eid),
+        DataKey::KhCommitment(eid, 0),
+        DataKey::IssueNullifier(eid, nf_issue.clone()),
+        DataKey::CastingAccount(eid, pk_cast.clone()),
+        DataKey::CastNullifier(eid, nf_cast.clone()),
+        DataKey::Ballot(eid, 0),
+    ];
+
+    // Written at t=1015: alive until max_end_time + retention
+    let expected = ((1500 + crate::RETENTION_SECS - 1015) / crate::LEDGER_SECS) as u32;
+    assert_eq!(ttl_of(DataKey::Ballot(eid, 0)), expected);
+    assert_eq!(ttl_of(DataKey::CastNullifier(eid, nf_cast.clone())), expected);
+    assert_eq!(ttl_of(DataKey::CastingAccount(eid, pk_cast.clone())), expected);
+    for key in keys.clone() {
+        assert!(ttl_of(key) >= expected - 10);
+    }
+
+    // Well after the election, anyone can renew every entry
+    let elapsed_ledgers = 1_000_000u32;
+    env.ledger().with_mut(|li| {
+        li.sequence_number += elapsed_ledgers;
+        li.timestamp += elapsed_ledgers as u64 * crate::LEDGER_SECS;
+    });
+    assert!(ttl_of(DataKey::Ballot(eid, 0)) < expected - elapsed_ledgers + 1);
+
+    assert_eq!(client.bump_election(&eid, &0, &20).next, None);
+    let renewed = (crate::RETENTION_SECS / crate::LEDGER_SECS) as u32;
+    for key in keys {
+        assert_eq!(ttl_of(key), renewed);
+    }
+    assert_eq!(client.get_next_election_id(), 1);
+}
+
+// ── Test 14: Archive a tallied election ──────────────────────────────────────
+
+#[test]
+fn test_archive_election() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let kh2 = TestKey::from_seed(2);
+    let dist1 = TestKey::from_seed(3);
+    let voter1 = TestKey::from_seed(4);
+    let voter2 = TestKey::from_seed(5);
+
+    let eid = deploy_election(&env, &client, &[&kh1, &kh2], &[&dist1], 1, 2, 10, 200);
+    advance_time(&env, 15);
+
+    issue_account(&env, &client, eid, &voter1.pk_bytes_n(&env), &fake_nf(&env, 0x01), &[&dist1]);
+    issue_account(&env, &client, eid, &voter2.pk_bytes_n(&env), &fake_nf(&env, 0x02), &[&dist1]);
+    let (nf_a, c1_a, c2_a) = (fake_nf(&env, 0x10), fake_point(&env, 0x01), fake_point(&env, 0x02));
+    let (nf_b, c1_b, c2_b) = (fake_nf(&env, 0x20), fake_point(&env, 0x03), fake_point(&env, 0x04));
+    cast_ballot(&env, &client, eid, &voter1, &nf_a, &c1_a, &c2_a);
+    cast_ballot(&env, &client, eid, &voter2, &nf_b, &c1_b, &c2_b);
+
+    advance_time(&env, 300);
+    post_kh_share(&env, &client, eid, &kh1, 0);
+    post_kh_share(&env, &client, eid, &kh2, 1);
+
+    // Not yet tallied → NotTallied (#21)
+    assert_eq!(client.try_archive_election(&eid, &0, &20), Err(Ok(ContractError::NotTallied)));
+
+    let mut tally: Vec<u32> = Vec::new(&env);
+    tally.push_back(1u32);
+    tally.push_back(1u32);
+    client.finalize_tally(&eid, &tally);
+
+    let blob = client.get_kh_shares(&eid, &0).unwrap();
+    assert_eq!(client.archive_election(&eid, &0, &20).next, None);
+    let record = client.get_archive(&eid).unwrap();
+
+    assert_eq!(record.params.eid, eid);
+    assert!(record.params.tallied);
+    assert_eq!(record.tally, tally);
+    assert_eq!(record.issued_count, 2);
+    assert_eq!(record.ballot_count, 2);
+    assert_eq!(record.kh.members.len(), 2);
+    assert_eq!(record.kh_commitments.get(1).unwrap(), fake_point(&env, 0x11));
+
+    let head = mk_board_step(&env, eid, &zero_head(&env), &nf_a, &c1_a, &c2_a);
+    let head = mk_board_step(&env, eid, &head, &nf_b, &c1_b, &c2_b);
+    assert_eq!(record.board_hash, head);
+
+    assert_eq!(record.share_hashes.len(), 2);
+    let expected_digest: BytesN<32> = mk_shares_msg(&env, eid, &blob).try_into().unwrap();
+    assert_eq!(record.share_hashes.get(0).unwrap(), (0u32, expected_digest));
+
+    // Bulky entries are gone; only the record remains
+    assert_eq!(client.get_archive(&eid), Some(record));
+    assert!(client.get_election(&eid).is_none());
+    assert!(client.get_ballot(&eid, &0).is_none());
+    assert!(client.get_kh_shares(&eid, &0).is_none());
+    assert!(client.get_tally(&eid).is_none());
+    assert_eq!(client.get_board_head(&eid), zero_head(&env));
+    assert!(client.get_casting_account(&eid, &voter1.pk_bytes_n(&env)).is_none());
+    assert!(!client.is_cast_nullifier_used(&eid, &nf_a));
+    assert_eq!(client.try_archive_election(&eid, &0, &20), Err(Ok(ContractError::NotFound)));
+
+    client.bump_election(&eid, &0, &20);
+}
+
+// ── Test 15: Ballot-board hash chain ─────────────────────────────────────────
+
+#[test]
+fn test_board_hash_chain() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let voter1 = TestKey::from_seed(4);
+    let voter2 = TestKey::from_seed(5);
+
+    let config = ElectionConfig {
+        revoting: true,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let eid = deploy_election_with_config(
+        &env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200, config,
+    );
+    advance_time(&env, 15);
+    assert_eq!(client.get_board_head(&eid), zero_head(&env));
+
+    issue_account(&env, &client, eid, &voter1.pk_bytes_n(&env), &fake_nf(&env, 0x01), &[&dist1]);
+    issue_account(&env, &client, eid, &voter2.pk_bytes_n(&env), &fake_nf(&env, 0x02), &[&dist1]);
+
+    let (nf_a, c1_a, c2_a) = (fake_nf(&env, 0x10), fake_point(&env, 0x01), fake_point(&env, 0x02));
+    cast_ballot(&env, &client, eid, &voter1, &nf_a, &c1_a, &c2_a);
+    let head1 = mk_board_step(&env, eid, &zero_head(&env), &nf_a, &c1_a, &c2_a);
+
+    // The cast event is the voter's receipt: (nf_cast, index, digest, head)
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("cast"), eid).into_val(&env));
+    let event = events::BallotCast::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.version, events::EVENT_VERSION);
+    assert_eq!((event.nf_cast, event.index, event.board_head), (nf_a.clone(), 0, head1.clone()));
+    let expected_digest: BytesN<32> = mk_cast_msg(&env, eid, &nf_a, &c1_a, &c2_a).try_into().unwrap();
+    assert_eq!(event.msg_hash, expected_digest);
+    // Since event version 2 it also carries the ballot itself
+    assert_eq!(event.pk_cast, voter1.pk_bytes_n(&env));
+    assert_eq!((event.c1, event.c2, event.weight), (c1_a.clone(), c2_a.clone(), 1));
+    assert_eq!(client.get_board_head(&eid), head1);
+
+    let (nf_b, c1_b, c2_b) = (fake_nf(&env, 0x20), fake_point(&env, 0x03), fake_point(&env, 0x04));
+    cast_ballot(&env, &client, eid, &voter2, &nf_b, &c1_b, &c2_b);
+    let head2 = mk_board_step(&env, eid, &head1, &nf_b, &c1_b, &c2_b);
+
+    // A revote replaces the slot but is still appended to the chain
+    let (nf_c, c1_c, c2_c) = (fake_nf(&env, 0x11), fake_point(&env, 0x05), fake_point(&env, 0x06));
+    cast_ballot(&env, &client, eid, &voter1, &nf_c, &c1_c, &c2_c);
+    let head3 = mk_board_step(&env, eid, &head2, &nf_c, &c1_c, &c2_c);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("recast"), eid).into_val(&env));
+    assert_eq!(client.get_board_head(&eid), head3);
+    // Three leaves, two stored ballots: slot 0 proves the replacing leaf
+    let root = client.get_ballot_root(&eid);
+    let proof = client.get_ballot_proof(&eid, &0).unwrap();
+    assert_eq!(proof.len(), 20);
+    assert!(crate::merkle::verify(&env, &root, &mk_cast_msg(&env, eid, &nf_c, &c1_c, &c2_c), &proof));
+    assert!(!crate::merkle::verify(&env, &root, &mk_cast_msg(&env, eid, &nf_a, &c1_a, &c2_a), &proof));
+    let proof = client.get_ballot_proof(&eid, &1).unwrap();
+    assert!(crate::merkle::verify(&env, &root, &mk_cast_msg(&env, eid, &nf_b, &c1_b, &c2_b), &proof));
+    assert!(client.get_ballot_proof(&eid, &2).is_none());
+
+    advance_time(&env, 300);
+    post_kh_share(&env, &client, eid, &kh1, 0);
+    let mut tally: Vec<u32> = Vec::new(&env);
+    tally.push_back(2u32);
+    tally.push_back(0u32);
+    client.finalize_tally(&eid, &tally);
+
+    let record = client.get_tally_record(&eid).unwrap();
+    assert_eq!(record.board_head, head3);
+    assert_eq!(record.ballot_count, 2);
+    assert_eq!(record.share_count, 1);
+    assert_eq!(record.tally, tally);
+}
+
+// ── Test 16: Ballot Merkle tree inclusion proofs ─────────────────────────────
+
+#[test]
+fn test_ballot_merkle_proofs() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+
+    let eid = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    advance_time(&env, 15);
+
+    let empty_root = client.get_ballot_root(&eid);
+    let mut digests: std::vec::Vec<Bytes> = std::vec::Vec::new();
+    for i in 0..5u8 {
+        let voter = TestKey::from_seed(40 + i);
+        issue_account(&env, &client, eid, &voter.pk_bytes_n(&env), &fake_nf(&env, 0x50 + i), &[&dist1]);
+        let (nf, c1, c2) = (fake_nf(&env, 0x60 + i), fake_point(&env, 2 * i), fake_point(&env, 2 * i + 1));
+        cast_ballot(&env, &client, eid, &voter, &nf, &c1, &c2);
+        digests.push(mk_cast_msg(&env, eid, &nf, &c1, &c2));
+    }
+
+    let root = client.get_ballot_root(&eid);
+    assert_ne!(root, empty_root);
+    for (i, digest) in digests.iter().enumerate() {
+        let proof = client.get_ballot_proof(&eid, &(i as u32)).unwrap();
+        assert_eq!(proof.len(), crate::merkle::TREE_DEPTH);
+        assert!(crate::merkle::verify(&env, &root, digest, &proof));
+        // A proof for one ballot does not vouch for another
+        let other = &digests[(i + 1) % digests.len()];
+        assert!(!crate::merkle::verify(&env, &root, other, &proof));
+    }
+    assert!(client.get_ballot_proof(&eid, &5).is_none());
+
+    // At the boundary: a depth-3 tree takes 7 leaves with correct roots, then
+    // reports itself full
+    let zero = BytesN::from_array(&env, &[0u8; 32]);
+    let mut slots: [BytesN<32>; 8] = core::array::from_fn(|_| zero.clone());
+    let mut frontier = crate::merkle::empty_frontier_at(&env, 3);
+    for i in 0..7u8 {
+        let leaf = crate::merkle::leaf_hash(&env, &Bytes::from_array(&env, &[i]));
+        assert!(crate::merkle::append(&env, &mut frontier, &leaf));
+        slots[i as usize] = leaf;
+        let mut layer = slots.to_vec();
+        while layer.len() > 1 {
+            layer = layer.chunks(2).map(|pair| crate::merkle::node_hash(&env, &pair[0], &pair[1])).collect();
+        }
+        assert_eq!(crate::merkle::root(&env, &frontier), layer[0]);
+    }
+    assert!(!crate::merkle::append(&env, &mut frontier, &zero));
+    assert_eq!(frontier.size, 7);
+
+    advance_time(&env, 300);
+    post_kh_share(&env, &client, eid, &kh1, 0);
+    let mut tally: Vec<u32> = Vec::new(&env);
+    tally.push_back(3u32);
+    tally.push_back(2u32);
+    client.finalize_tally(&eid, &tally);
+    assert_eq!(client.get_tally_record(&eid).unwrap().ballot_root, root);
+}
+
+// ── Test 17: Organizer-managed voter registry ────────────────────────────────
+
+#[test]
+fn test_voter_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    // The precomputed empty-subtree table matches E_{h+1} = node_hash(E_h, E_h)
+    let empties: std::vec::Vec<[u8; 32]> = smt_empties(&env).iter().map(|e| e.to_array()).collect();
+    assert_eq!(empties, crate::smt::EMPTY_SUBTREES.to_vec());
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let config = ElectionConfig {
+        revoting: false,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Registry,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let eid = deploy_election_with_config(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200, config);
+
+    let mut registry = TestRegistry::new(&env);
+    assert_eq!(client.get_eligibility_root(&eid), Some(registry.root(&env)));
+    assert_eq!(client.get_registry_version(&eid), Some(0));
+
+    let alice = Bytes::from_slice(&env, b"alice");
+    let bob = Bytes::from_slice(&env, b"bob");
+
+    // Add both voters; each update is proven against the current root
+    assert_eq!(client.add_voter(&eid, &alice, &registry.prove(&env, &alice)), 1);
+    registry.insert(&env, &alice);
+    assert_eq!(client.add_voter(&eid, &bob, &registry.prove(&env, &bob)), 2);
+    registry.insert(&env, &bob);
+    assert_eq!(client.get_eligibility_root(&eid), Some(registry.root(&env)));
+
+    // Adding an existing member fails: its slot is not empty
+    assert_eq!(
+        client.try_add_voter(&eid, &bob, &registry.prove(&env, &bob)),
+        Err(Ok(ContractError::InvalidMerkleProof))
+    );
+
+    let bob_proof = registry.prove(&env, &bob);
+    assert_eq!(client.revoke_voter(&eid, &bob, &bob_proof), 3);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    registry.remove(&env, &bob);
+    assert_eq!(topics, (symbol_short!("voter_rev"), eid).into_val(&env));
+    let event = events::RegistryUpdated::try_from_val(&env, &data).unwrap();
+    assert_eq!(
+        (event.voter, event.registry_version, event.root.clone()),
+        (bob.clone(), 3, registry.root(&env))
+    );
+    assert_eq!(client.get_eligibility_root(&eid), Some(event.root));
+
+    let history = client.get_registry_root(&eid, &2).unwrap();
+    assert_ne!(history.root, registry.root(&env));
+    assert_eq!(history.updated_at, 1000);
+
+    // Issuance needs a registry proof against the current root
+    let pk_a = TestKey::from_seed(40).pk_bytes_n(&env);
+    let nf_a = fake_nf(&env, 0x50);
+    let msg = mk_issue_msg(&env, eid, &pk_a, &nf_a, Some(&alice));
+    let mut sigs: Vec<(BytesN<32>, BytesN<64>)> = Vec::new(&env);
+    sigs.push_back((dist1.pk_bytes_n(&env), dist1.sign_bytes(&env, &msg)));
+    assert_eq!(
+        client.try_issue_account(&eid, &pk_a, &nf_a, &None, &None, &sigs),
+        Err(Ok(ContractError::WrongEligibilityMode))
+    );
+    let revoked = EligibilityProof::Registry(bob.clone(), bob_proof.clone());
+    assert_eq!(
+        client.try_issue_account(&eid, &pk_a, &nf_a, &Some(revoked), &None, &sigs),
+        Err(Ok(ContractError::InvalidMerkleProof))
+    );
+    let proof = EligibilityProof::Registry(alice.clone(), registry.prove(&env, &alice));
+    // The approval names the voter: one signed without alice does not cover her
+    let msg = mk_issue_msg(&env, eid, &pk_a, &nf_a, None);
+    let unbound = Vec::from_array(&env, [(dist1.pk_bytes_n(&env), dist1.sign_bytes(&env, &msg))]);
+    assert!(client.try_issue_account(&eid, &pk_a, &nf_a, &Some(proof.clone()), &None, &unbound).is_err());
+    client.issue_account(&eid, &pk_a, &nf_a, &Some(proof), &None, &sigs);
+    let (_, _, data) = env.events().all().last().unwrap();
+    let event = events::AccountIssued::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.voter, crate::merkle::leaf_hash(&env, &alice));
+    assert_eq!(client.get_casting_account(&eid, &pk_a).unwrap().status, AccountStatus::Issued);
+
+    // The registry freezes once voting opens
+    advance_time(&env, 15);
+    assert_eq!(
+        client.try_add_voter(&eid, &bob, &registry.prove(&env, &bob)),
+        Err(Ok(ContractError::RegistrationClosed))
+    );
+
+    // Static-mode elections have no registry
+    let static_eid = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    assert_eq!(
+        client.try_add_voter(&static_eid, &bob, &registry.prove(&env, &bob)),
+        Err(Ok(ContractError::WrongEligibilityMode))
+    );
+    assert_eq!(client.get_registry_version(&static_eid), None);
+}
+
+// ── Test 18: Revocation list blocks issuance ─────────────────────────────────
+
+#[test]
+fn test_revocation_root() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let config = ElectionConfig {
+        revoting: false,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Registry,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let eid = deploy_election_with_config(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200, config);
+
+    let alice = Bytes::from_slice(&env, b"alice");
+    let bob = Bytes::from_slice(&env, b"bob");
+    let mut registry = TestRegistry::new(&env);
+    for voter in [&alice, &bob] {
+        client.add_voter(&eid, voter, &registry.prove(&env, voter));
+        registry.insert(&env, voter);
+    }
+    advance_time(&env, 15);
+
+    // Court order: bob is struck off after registration closed
+    let mut revoked = TestRegistry::new(&env);
+    revoked.insert(&env, &bob);
+    client.set_revocation_root(&eid, &revoked.root(&env));
+    assert_eq!(client.get_revocation_root(&eid), Some(revoked.root(&env)));
+
+    let issue = |seed: u8, voter: &Bytes, revocation: Option<SmtProof>| {
+        let pk = TestKey::from_seed(seed).pk_bytes_n(&env);
+        let nf = fake_nf(&env, seed);
+        let msg = mk_issue_msg(&env, eid, &pk, &nf, Some(voter));
+        let mut sigs: Vec<(BytesN<32>, BytesN<64>)> = Vec::new(&env);
+        sigs.push_back((dist1.pk_bytes_n(&env), dist1.sign_bytes(&env, &msg)));
+        let eligibility = EligibilityProof::Registry(voter.clone(), registry.prove(&env, voter));
+        client.try_issue_account(&eid, &pk, &nf, &Some(eligibility), &revocation, &sigs)
+    };
+
+    // A non-membership proof is now mandatory
+    assert_eq!(issue(40, &alice, None), Err(Ok(ContractError::InvalidMerkleProof)));
+    assert!(issue(40, &alice, Some(revoked.prove(&env, &alice))).is_ok());
+
+    // Bob is still in the eligibility registry but revoked
+    assert_eq!(
+        issue(41, &bob, Some(revoked.prove(&env, &bob))),
+        Err(Ok(ContractError::VoterRevoked))
+    );
+    // A proof against a different revocation tree is rejected
+    let mut other = TestRegistry::new(&env);
+    other.insert(&env, &alice);
+    assert_eq!(
+        issue(41, &bob, Some(other.prove(&env, &bob))),
+        Err(Ok(ContractError::InvalidMerkleProof))
+    );
+
+    // Setting the empty root clears the list
+    client.set_revocation_root(&eid, &TestRegistry::new(&env).root(&env));
+    assert_eq!(client.get_revocation_root(&eid), None);
+    assert!(issue(41, &bob, None).is_ok());
+}
+
+// ── Test 19: Batch issuance with a Merkle multiproof ─────────────────────────
+
+#[test]
+fn test_batch_issue_accounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let dist2 = TestKey::from_seed(4);
+
+    let voters: std::vec::Vec<Bytes> =
+        (0..8u8).map(|i| TestKey::from_seed(70 + i).pk_bytes_n(&env).into()).collect();
+    let levels = dense_levels(&env, &voters);
+    let config = ElectionConfig {
+        revoting: false,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let eid = deploy_election_with_root(
+        &env, &client, &[&kh1], &[&dist1, &dist2], 2, 1, 10, 200, config,
+        levels[3][0].clone(),
+    );
+    advance_time(&env, 15);
+
+    let mk_items = |indices: &[u32], nf_seed: u8| {
+        let mut items: Vec<IssueItem> = Vec::new(&env);
+        for (n, &i) in indices.iter().enumerate() {
+            items.push_back(IssueItem {
+                pk_cast: TestKey::from_seed(100 + nf_seed + n as u8).pk_bytes_n(&env),
+                nf_issue: fake_nf(&env, nf_seed + n as u8),
+                leaf: voters[i as usize].clone(),
+                leaf_index: i,
+            });
+        }
+        items
+    };
+    let batch_sigs = |items: &Vec<IssueItem>| {
+        let digest = crate::batch_issue_msg(&env, eid, items);
+        let msg: Bytes = digest.into();
+        let mut sigs: Vec<(BytesN<32>, BytesN<64>)> = Vec::new(&env);
+        for k in [&dist1, &dist2] {
+            sigs.push_back((k.pk_bytes_n(&env), k.sign_bytes(&env, &msg)));
+        }
+        sigs
+    };
+
+    // Leaves 1, 2, 3 and 6: 2 and 3 share a parent, so 3 siblings suffice
+    let indices = [1u32, 2, 3, 6];
+    let items = mk_items(&indices, 0x20);
+    let proof = MerkleMultiProof { depth: 3, siblings: dense_multiproof(&env, &levels, &indices) };
+    assert_eq!(proof.siblings.len(), 3);
+
+    // Out-of-order items and a tampered proof are rejected
+    let mut swapped = items.clone();
+    swapped.set(0, items.get(1).unwrap());
+    swapped.set(1, items.get(0).unwrap());
+    assert_eq!(
+        client.try_batch_issue_accounts(&eid, &swapped, &proof, &batch_sigs(&swapped)),
+        Err(Ok(ContractError::InvalidBatch))
+    );
+    let mut bad = proof.clone();
+    bad.siblings.set(0, fake_nf(&env, 0xEE));
+    assert_eq!(
+        client.try_batch_issue_accounts(&eid, &items, &bad, &batch_sigs(&items)),
+        Err(Ok(ContractError::InvalidMerkleProof))
+    );
+
+    assert_eq!(client.batch_issue_accounts(&eid, &items, &proof, &batch_sigs(&items)), 4);
+    let events = env.events().all();
+    let issued = events
+        .iter()
+        .filter(|(_, topics, _)| *topics == (symbol_short!("issued"), eid).into_val(&env))
+        .count();
+    assert_eq!(issued, 4);
+    for item in items.iter() {
+        let account = client.get_casting_account(&eid, &item.pk_cast).unwrap();
+        assert_eq!(account.status, AccountStatus::Issued);
+        assert!(client.is_issue_nullifier_used(&eid, &item.nf_issue));
+    }
+
+    // Atomic: one reused nullifier rejects the whole batch
+    let indices = [0u32, 5];
+    let mut items = mk_items(&indices, 0x30);
+    let mut reused = items.get(1).unwrap();
+    reused.nf_issue = fake_nf(&env, 0x20);
+    items.set(1, reused);
+    let proof = MerkleMultiProof { depth: 3, siblings: dense_multiproof(&env, &levels, &indices) };
+    assert_eq!(
+        client.try_batch_issue_accounts(&eid, &items, &proof, &batch_sigs(&items)),
+        Err(Ok(ContractError::AlreadyIssued))
+    );
+    assert!(!client.is_issue_nullifier_used(&eid, &fake_nf(&env, 0x30)));
+    assert!(client.get_casting_account(&eid, &items.get(0).unwrap().pk_cast).is_none());
+}
+
+// ── Test 20: Token-gated, balance-weighted eligibility ───────────────────────
+
+#[test]
+fn test_token_gated_issuance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+    let token_id = env.register(MockToken, ());
+    let token = MockTokenClient::new(&env, &token_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+
+    let whale = Address::generate(&env);
+    let minnow = Address::generate(&env);
+    let dust = Address::generate(&env);
+    token.mint(&whale, &350);
+    token.mint(&minnow, &100);
+    token.mint(&dust, &99);
+
+    let gate = TokenGate { token: token_id.clone(), min_balance: 100, weighted: true };
+    let config = ElectionConfig {
+        revoting: false,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Token(gate.clone()),
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let eid = deploy_election_with_config(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200, config);
+
+    // Clearing a revocation list leaves the token gate in place
+    let mut revoked = TestRegistry::new(&env);
+    revoked.insert(&env, &Bytes::from_slice(&env, b"nobody"));
+    client.set_revocation_root(&eid, &revoked.root(&env));
+    client.set_revocation_root(&eid, &TestRegistry::new(&env).root(&env));
+    assert_eq!(client.get_revocation_root(&eid), None);
+    assert_eq!(client.get_election(&eid).unwrap().config.eligibility, EligibilityMode::Token(gate.clone()));
+
+    let issue = |seed: u8, holder: &Address| {
+        let pk = TestKey::from_seed(seed).pk_bytes_n(&env);
+        let nf = fake_nf(&env, seed);
+        let msg = mk_issue_msg(&env, eid, &pk, &nf, Some(&holder.clone().to_xdr(&env)));
+        let mut sigs: Vec<(BytesN<32>, BytesN<64>)> = Vec::new(&env);
+        sigs.push_back((dist1.pk_bytes_n(&env), dist1.sign_bytes(&env, &msg)));
+        let proof = EligibilityProof::Token(holder.clone());
+        client.try_issue_account(&eid, &pk, &nf, &Some(proof), &None, &sigs).map(|_| pk)
+    };
+
+    // Weight is floor(balance / min_balance); the holder must authorize
+    let whale_pk = issue(40, &whale).unwrap();
+    assert_eq!(env.auths()[0].0, whale);
+    assert_eq!(client.get_casting_account(&eid, &whale_pk).unwrap().weight, 3);
+
+    let minnow_pk = issue(41, &minnow).unwrap();
+    assert_eq!(client.get_casting_account(&eid, &minnow_pk).unwrap().weight, 1);
+    assert_eq!(
+        client.get_casting_account(&eid, &minnow_pk).unwrap().holder,
+        Some(minnow.clone())
+    );
+
+    assert_eq!(issue(42, &dust), Err(Ok(ContractError::InsufficientBalance)));
+    // One account per holder, even with a fresh issue nullifier
+    assert_eq!(issue(43, &whale), Err(Ok(ContractError::AlreadyIssued)));
+
+    // The counted balance is escrowed, so handing it to a fresh address to
+    // issue again is not possible; only the uncounted remainder moves
+    assert_eq!(token.balance(&whale), 50);
+    assert_eq!(token.balance(&contract_id), 400);
+    let fresh = Address::generate(&env);
+    token.transfer(&whale, &fresh, &50);
+    assert_eq!(issue(44, &fresh), Err(Ok(ContractError::InsufficientBalance)));
+    token.mint(&fresh, &60);
+    let fresh_pk = issue(44, &fresh).unwrap();
+    assert_eq!(client.get_casting_account(&eid, &fresh_pk).unwrap().weight, 1);
+    assert_eq!(client.try_release_tokens(&eid, &whale), Err(Ok(ContractError::NotTallied)));
+
+    // Ballots carry their account's weight
+    advance_time(&env, 15);
+    let whale_key = TestKey::from_seed(40);
+    cast_ballot(&env, &client, eid, &whale_key, &fake_nf(&env, 0x60), &fake_point(&env, 1), &fake_point(&env, 2));
+    assert_eq!(client.get_ballot(&eid, &0).unwrap().weight, 3);
+
+    // Escrows are released to their holders once the election ends
+    client.cancel(&eid, &Bytes::from_slice(&env, b"test"));
+    assert_eq!(client.release_tokens(&eid, &whale), 300);
+    assert_eq!(token.balance(&whale), 300);
+    assert_eq!(client.try_release_tokens(&eid, &whale), Err(Ok(ContractError::NotFound)));
+
+    // Non-positive minimums are rejected at deploy
+    let bad = ElectionConfig {
+        revoting: false,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Token(TokenGate { min_balance: 0, ..gate }),
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let organizer = Address::generate(&env);
+    let mut roster: Vec<BytesN<32>> = Vec::new(&env);
+    roster.push_back(kh1.pk_bytes_n(&env));
+    assert_eq!(
+        client.try_deploy(
+            &organizer,
+            &Bytes::from_slice(&env, b"bad"),
+            &2u32,
+            &1100u64,
+            &1200u64,
+            &fake_point(&env, 0xAA),
+            &fake_nf(&env, 0x42),
+            &Committee { members: roster.clone(), threshold: 1 },
+            &Committee { members: roster, threshold: 1 },
+            &bad,
+        ),
+        Err(Ok(ContractError::InvalidTokenGate))
+    );
+}
+
+// ── Test 21: Timelocked, admin-only upgrades ─────────────────────────────────
+
+#[test]
+fn test_timelocked_upgrade() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ElectionContract, (admin.clone(), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_schema_version(), 4);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let day = 24 * 60 * 60;
+    let eid = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 4 * day);
+    assert_eq!(client.get_phase(&eid), ElectionPhase::Registration);
+
+    // Natively registered contracts run from the (uploaded) empty WASM
+    let wasm_hash: BytesN<32> = env.crypto().sha256(&Bytes::new(&env)).into();
+    assert_eq!(client.try_execute_upgrade(&0, &10), Err(Ok(ContractError::NoPendingUpgrade)));
+
+    let pending = client.propose_upgrade(&wasm_hash);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(pending.executable_at, 1000 + 2 * day);
+    assert_eq!(client.get_pending_upgrade(), Some(pending));
+    assert_eq!(client.try_execute_upgrade(&0, &10), Err(Ok(ContractError::UpgradeTimelocked)));
+
+    // Timelock elapsed, but the election is voting, then awaiting its tally
+    set_timestamp(&env, 1000 + 2 * day);
+    assert_eq!(client.get_phase(&eid), ElectionPhase::Voting);
+    assert_eq!(client.try_execute_upgrade(&0, &10), Err(Ok(ContractError::ElectionInProgress)));
+    set_timestamp(&env, 1000 + 4 * day);
+    assert_eq!(client.get_phase(&eid), ElectionPhase::Tallying);
+    assert_eq!(client.try_execute_upgrade(&0, &10), Err(Ok(ContractError::ElectionInProgress)));
+
+    client.cancel(&eid, &Bytes::from_slice(&env, b"superseded"));
+    assert_eq!(client.get_phase(&eid), ElectionPhase::Cancelled);
+
+    // Elections are checked a page at a time; one that opens mid-walk
+    // restarts it
+    let later = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 4 * day);
+    deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 3 * day, 4 * day);
+    assert_eq!(client.execute_upgrade(&0, &2), Some(2));
+    assert_eq!(client.try_execute_upgrade(&0, &2), Err(Ok(ContractError::InvalidCursor)));
+    advance_time(&env, 10);
+    assert_eq!(client.get_phase(&later), ElectionPhase::Voting);
+    assert_eq!(client.try_execute_upgrade(&2, &2), Err(Ok(ContractError::InvalidCursor)));
+    assert_eq!(client.try_execute_upgrade(&0, &2), Err(Ok(ContractError::ElectionInProgress)));
+    client.cancel(&later, &Bytes::from_slice(&env, b"superseded"));
+    assert_eq!(client.execute_upgrade(&0, &2), Some(2));
+    assert_eq!(client.execute_upgrade(&2, &2), None);
+    assert_eq!(env.auths()[0].0, admin);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("upgraded"),).into_val(&env));
+    assert_eq!(events::UpgradeEvent::try_from_val(&env, &data).unwrap().wasm_hash, wasm_hash);
+    assert!(client.get_pending_upgrade().is_none());
+    assert_eq!(client.try_cancel_upgrade(), Err(Ok(ContractError::NoPendingUpgrade)));
+
+    // Already on the current schema: nothing to migrate
+    assert_eq!(client.migrate(&admin), 4);
+    assert_eq!(client.get_schema_version(), 4);
+
+    // A schema this code does not know is refused, not a panic
+    for unknown in [0u32, 5] {
+        env.as_contract(&contract_id, || env.storage().instance().set(&DataKey::SchemaVersion, &unknown));
+        assert_eq!(client.try_migrate(&admin), Err(Ok(ContractError::UnknownSchema)));
+    }
+}
+
+// ── Test 22: Schema-1 records stay readable and migrate lazily ───────────────
+
+#[test]
+fn test_schema_v1_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(ElectionContract, (admin.clone(), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+    assert_eq!(client.get_schema_version(), 4);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let voter = TestKey::from_seed(4);
+    let eid = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    issue_account(&env, &client, eid, &voter.pk_bytes_n(&env), &fake_nf(&env, 0x50), &[&dist1]);
+    advance_time(&env, 15);
+    let (nf, c1, c2) = (fake_nf(&env, 0x60), fake_point(&env, 1), fake_point(&env, 2));
+    cast_ballot(&env, &client, eid, &voter, &nf, &c1, &c2);
+
+    let params = client.get_election(&eid).unwrap();
+    let ballot = client.get_ballot(&eid, &0).unwrap();
+    let root = client.get_ballot_root(&eid);
+    // Schema 1 as the first release wrote it
+    let params_v1 = ElectionParamsV1 {
+        eid,
+        title: params.title.clone(),
+        options_count: params.options_count,
+        start_time: params.start_time,
+        end_time: params.end_time,
+        enc_pubkey: params.enc_pubkey.clone(),
+        tallied: params.tallied,
+    };
+    let ballot_v1 = EncryptedBallotV1 { nf_cast: ballot.nf_cast.clone(), c1: ballot.c1.clone(), c2: ballot.c2.clone() };
+
+    // Fields schema 1 lacked come back as defaults, run by the admin
+    let params = ElectionParams {
+        organizer: admin.clone(),
+        config: ElectionConfig {
+            revoting: false,
+            max_end_time: params.end_time,
+            eligibility: EligibilityMode::Static,
+            disputes: DisputePolicy::None,
+            staking: StakePolicy::None,
+        },
+        ..params
+    };
+
+    // Rewrite the entries as the pre-versioning code stored them: bare
+    // structs, no admin and the eid counter in persistent storage
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::Election(eid), &params_v1);
+        storage.set(&DataKey::Ballot(eid, 0), &ballot_v1);
+        storage.set(&DataKey::NextElectionId, &(eid + 1));
+        env.storage().instance().remove(&DataKey::NextElectionId);
+        env.storage().instance().remove(&DataKey::Admin);
+        env.storage().instance().set(&DataKey::SchemaVersion, &1u32);
+    });
+    assert_eq!(client.get_schema_version(), 1);
+
+    // Read paths upgrade transparently
+    assert_eq!(client.get_ballot(&eid, &0), Some(ballot.clone()));
+    let proof = client.get_ballot_proof(&eid, &0).unwrap();
+    assert!(crate::merkle::verify(&env, &root, &mk_cast_msg(&env, eid, &nf, &c1, &c2), &proof));
+    let stored_bare = || {
+        env.as_contract(&contract_id, || {
+            let stored: Val = env.storage().persistent().get(&DataKey::Election(eid)).unwrap();
+            VersionedElection::try_from_val(&env, &stored).is_err()
+        })
+    };
+    assert!(stored_bare());
+
+    // Admin calls are refused, not a panic, until migrate() records one
+    let wasm_hash: BytesN<32> = env.crypto().sha256(&Bytes::new(&env)).into();
+    assert_eq!(client.try_propose_upgrade(&wasm_hash), Err(Ok(ContractError::NotFound)));
+
+    // migrate() records the admin and moves the eid counter; election
+    // records are rewritten lazily
+    assert_eq!(client.migrate(&admin), 4);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_schema_version(), 4);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_election(&eid), Some(params.clone()));
+    assert!(stored_bare());
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().persistent().has(&DataKey::NextElectionId));
+    });
+    let next = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    assert_eq!(next, eid + 1);
+
+    // The next save wraps the election record in the current layout;
+    // ballots stay bare but readable
+    client.pause(&eid);
+    client.resume(&eid);
+    env.as_contract(&contract_id, || {
+        let storage = env.storage().persistent();
+        let stored: Val = storage.get(&DataKey::Election(eid)).unwrap();
+        assert_eq!(
+            VersionedElection::try_from_val(&env, &stored),
+            Ok(VersionedElection::V3(params.clone()))
+        );
+        let stored: Val = storage.get(&DataKey::Ballot(eid, 0)).unwrap();
+        assert!(VersionedBallot::try_from_val(&env, &stored).is_err());
+    });
+    assert_eq!(client.get_ballot(&eid, &0), Some(ballot));
+    assert_eq!(client.migrate(&admin), 4);
+}
+
+// ── Test 23: Typed, versioned event payloads ─────────────────────────────────
+
+#[test]
+fn test_typed_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let kh2 = TestKey::from_seed(2);
+    let dist1 = TestKey::from_seed(3);
+    let voter = TestKey::from_seed(4);
+    let eid = deploy_election(&env, &client, &[&kh1, &kh2], &[&dist1], 1, 2, 10, 200);
+    let last = |topic: Symbol| {
+        let (_, topics, data) = env.events().all().last().unwrap();
+        assert_eq!(topics, (topic, eid).into_val(&env));
+        data
+    };
+
+    let commitment = fake_point(&env, 0x70);
+    client.set_kh_commitment(&eid, &1, &commitment);
+    let event = events::CommitmentSet::try_from_val(&env, &last(symbol_short!("commit"))).unwrap();
+    assert_eq!(
+        event,
+        events::CommitmentSet { version: events::EVENT_VERSION, kh_idx: 1, commitment }
+    );
+
+    let pk = voter.pk_bytes_n(&env);
+    issue_account(&env, &client, eid, &pk, &fake_nf(&env, 0x50), &[&dist1]);
+    let event = events::AccountIssued::try_from_val(&env, &last(symbol_short!("issued"))).unwrap();
+    assert_eq!((event.pk_cast, event.weight), (pk, 1));
+
+    client.pause(&eid);
+    let event = events::StatusChanged::try_from_val(&env, &last(symbol_short!("paused"))).unwrap();
+    assert_eq!(event.changed_at, 1000);
+    client.resume(&eid);
+
+    advance_time(&env, 15);
+    cast_ballot(&env, &client, eid, &voter, &fake_nf(&env, 0x60), &fake_point(&env, 1), &fake_point(&env, 2));
+
+    // Below threshold: only the per-share event
+    advance_time(&env, 300);
+    post_kh_share(&env, &client, eid, &kh1, 0);
+    let all = env.events().all();
+    assert_eq!(all.len(), 1);
+    let (_, topics, data) = all.get(0).unwrap();
+    assert_eq!(topics, (symbol_short!("share"), eid).into_val(&env));
+    let event = events::SharePosted::try_from_val(&env, &data).unwrap();
+    assert_eq!((event.version, event.kh_idx, event.share_count), (events::EVENT_VERSION, 0, 1));
+
+    post_kh_share(&env, &client, eid, &kh2, 1);
+    let event = events::ThresholdReached::try_from_val(&env, &last(symbol_short!("threshold"))).unwrap();
+    assert_eq!((event.share_count, event.threshold), (2, 2));
+
+    let eid2 = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    let reason = Bytes::from_slice(&env, b"key compromise");
+    client.cancel(&eid2, &reason);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("cancelled"), eid2).into_val(&env));
+    let event = events::Cancelled::try_from_val(&env, &data).unwrap();
+    assert_eq!((event.cancelled_at, event.reason), (1315, reason));
+}
+
+// ── Test 24: Paginated views and stage summary ───────────────────────────────
+
+#[test]
+fn test_paginated_views() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let kh2 = TestKey::from_seed(2);
+    let dist1 = TestKey::from_seed(3);
+    let config = ElectionConfig {
+        revoting: true,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let eid = deploy_election_with_config(
+        &env, &client, &[&kh1, &kh2], &[&dist1], 1, 1, 10, 200, config,
+    );
+    let other = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    advance_time(&env, 15);
+
+    let voters: std::vec::Vec<TestKey> = (0..3).map(|i| TestKey::from_seed(40 + i)).collect();
+    for (i, voter) in voters.iter().enumerate() {
+        issue_account(&env, &client, eid, &voter.pk_bytes_n(&env), &fake_nf(&env, 0x50 + i as u8), &[&dist1]);
+        cast_ballot(&env, &client, eid, voter, &fake_nf(&env, 0x60 + i as u8),
+            &fake_point(&env, 2 * i as u8), &fake_point(&env, 2 * i as u8 + 1));
+    }
+    // Revote: the superseded nullifier stays listed
+    cast_ballot(&env, &client, eid, &voters[0], &fake_nf(&env, 0x70),
+        &fake_point(&env, 0x20), &fake_point(&env, 0x21));
+
+    let summary = client.get_summary(&eid);
+    assert_eq!(summary.phase, ElectionPhase::Voting);
+    assert_eq!(
+        (summary.issued_count, summary.ballot_count, summary.cast_count, summary.share_count),
+        (3, 3, 4, 0)
+    );
+
+    let page = client.get_ballots(&eid, &0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().nf_cast, fake_nf(&env, 0x70));
+    assert_eq!(page.get(1), client.get_ballot(&eid, &1));
+    assert_eq!(client.get_ballots(&eid, &1, &u32::MAX).len(), 2);
+    assert_eq!(client.get_ballots(&eid, &5, &10).len(), 0);
+
+    let accounts = client.list_casting_accounts(&eid, &1);
+    assert_eq!(accounts.next, None);
+    assert_eq!(accounts.accounts.len(), 2);
+    let (pk, account) = accounts.accounts.get(0).unwrap();
+    assert_eq!((pk, account.nf_issue), (voters[1].pk_bytes_n(&env), fake_nf(&env, 0x51)));
+
+    let nullifiers = client.list_cast_nullifiers(&eid, &0);
+    assert_eq!(nullifiers.next, None);
+    let expected: std::vec::Vec<BytesN<32>> =
+        [0x60, 0x61, 0x62, 0x70].iter().map(|&b| fake_nf(&env, b)).collect();
+    assert_eq!(nullifiers.nullifiers.iter().collect::<std::vec::Vec<_>>(), expected);
+
+    let page = client.list_elections(&0, &1);
+    assert_eq!(page.elections.len(), 1);
+    assert_eq!(page.next, Some(1));
+    let page = client.list_elections(&1, &10);
+    assert_eq!(page.elections.get(0).unwrap().eid, other);
+    assert_eq!(page.next, None);
+
+    // Only KH 1 posts; the range lists posted slots only
+    advance_time(&env, 300);
+    post_kh_share(&env, &client, eid, &kh2, 1);
+    let shares = client.get_kh_shares_range(&eid, &0, &10);
+    assert_eq!(shares.len(), 1);
+    assert_eq!(shares.get(0).unwrap(), (1, client.get_kh_shares(&eid, &1).unwrap()));
+    assert_eq!(client.try_get_kh_shares_range(&99, &0, &10), Err(Ok(ContractError::NotFound)));
+
+    let mut tally: Vec<u32> = Vec::new(&env);
+    tally.push_back(2u32);
+    tally.push_back(1u32);
+    client.finalize_tally(&eid, &tally);
+    assert_eq!(client.get_summary(&eid).phase, ElectionPhase::Tallied);
+
+    // Archiving drops the superseded nullifier too, and the eid leaves the list
+    client.archive_election(&eid, &0, &20);
+    assert!(!client.is_cast_nullifier_used(&eid, &fake_nf(&env, 0x60)));
+    assert_eq!(client.list_cast_nullifiers(&eid, &0).nullifiers.len(), 0);
+    assert_eq!(client.list_elections(&0, &10).elections.len(), 1);
+}
+
+// ── Test 25: Share batches are checked against the board ─────────────────────
+
+#[test]
+fn test_share_batch_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let voter1 = TestKey::from_seed(4);
+    let voter2 = TestKey::from_seed(5);
+
+    let eid = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    advance_time(&env, 15);
+    for (i, voter) in [&voter1, &voter2].into_iter().enumerate() {
+        let seed = i as u8 + 1;
+        issue_account(&env, &client, eid, &voter.pk_bytes_n(&env), &fake_nf(&env, seed), &[&dist1]);
+        let (c1, c2) = (fake_point(&env, seed), fake_point(&env, seed + 0x40));
+        cast_ballot(&env, &client, eid, voter, &fake_nf(&env, seed + 0x10), &c1, &c2);
+    }
+    advance_time(&env, 200);
+
+    let good = share_batch(&env, &client, eid);
+    let reject = |batch: &ShareBatch| try_post_batch(&env, &client, eid, &kh1, 0, batch).unwrap_err();
+
+    let mut batch = good.clone();
+    batch.version = SHARE_BATCH_VERSION + 1;
+    assert_eq!(reject(&batch), ContractError::UnsupportedShareBatch);
+
+    // One share short of the board
+    let mut batch = good.clone();
+    batch.shares.pop_back();
+    assert_eq!(reject(&batch), ContractError::ShareBatchMismatch);
+
+    // Shares out of board order
+    let mut batch = good.clone();
+    batch.shares = Vec::from_array(&env, [good.shares.get(1).unwrap(), good.shares.get(0).unwrap()]);
+    assert_eq!(reject(&batch), ContractError::ShareBatchMismatch);
+
+    // Right index, another ballot's C1
+    let mut batch = good.clone();
+    let mut share = batch.shares.get(0).unwrap();
+    share.c1 = good.shares.get(1).unwrap().c1;
+    batch.shares.set(0, share);
+    assert_eq!(reject(&batch), ContractError::ShareBatchMismatch);
+
+    let mut batch = good.clone();
+    let mut share = batch.shares.get(1).unwrap();
+    share.d = Bytes::from_array(&env, &[0x02; 32]);
+    batch.shares.set(1, share);
+    assert_eq!(reject(&batch), ContractError::EncodingError);
+    assert_eq!(client.get_share_count(&eid), 0);
+
+    // A CP proof rides along in the signed blob
+    let mut batch = good.clone();
+    let mut share = batch.shares.get(0).unwrap();
+    share.proof = ShareProof::Cp(CpProof {
+        r1: fake_point(&env, 0x30),
+        r2: fake_point(&env, 0x31),
+        s: BytesN::from_array(&env, &[0x07; 32]),
+    });
+    batch.shares.set(0, share);
+    assert_eq!(try_post_batch(&env, &client, eid, &kh1, 0, &batch), Ok(1));
+    let blob = client.get_kh_shares(&eid, &0).unwrap();
+    assert_eq!(blob, crate::serialise_share_batch(&env, &batch));
+    assert_eq!(blob.len(), 1 + 4 + 2 * (4 + 33 + 33 + 1) + 33 + 33 + 32);
+    assert_eq!(blob.get(0), Some(SHARE_BATCH_VERSION as u8));
+}
+
+// ── Test 26: Chunked, resumable share uploads ────────────────────────────────
+
+/// Chunks `(start, shares start .. end)` of `batch`, one per `bounds` window.
+fn share_chunks(batch: &ShareBatch, bounds: &[(u32, u32)]) -> std::vec::Vec<(u32, ShareBatch)> {
+    bounds
+        .iter()
+        .map(|&(start, end)| {
+            let shares = batch.shares.slice(start..end);
+            (start, ShareBatch { version: batch.version, shares })
+        })
+        .collect()
+}
+
+/// Chain digest over `chunks`, as signed in `begin_shares`.
+fn upload_digest(env: &Env, eid: u64, chunks: &[(u32, ShareBatch)]) -> BytesN<32> {
+    let mut chain = BytesN::from_array(env, &[0u8; 32]);
+    for (start, batch) in chunks {
+        let blob = crate::serialise_share_batch(env, batch);
+        chain = crate::shares_chain_step(env, &chain, &crate::shares_chunk_hash(env, eid, *start, &blob));
+    }
+    chain
+}
+
+#[test]
+fn test_chunked_share_upload() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let kh2 = TestKey::from_seed(2);
+    let dist1 = TestKey::from_seed(3);
+    let voters = [TestKey::from_seed(4), TestKey::from_seed(5), TestKey::from_seed(6)];
+
+    let eid = deploy_election(&env, &client, &[&kh1, &kh2], &[&dist1], 1, 2, 10, 200);
+    advance_time(&env, 15);
+    for (i, voter) in voters.iter().enumerate() {
+        let seed = i as u8 + 1;
+        issue_account(&env, &client, eid, &voter.pk_bytes_n(&env), &fake_nf(&env, seed), &[&dist1]);
+        let (c1, c2) = (fake_point(&env, seed), fake_point(&env, seed + 0x40));
+        cast_ballot(&env, &client, eid, voter, &fake_nf(&env, seed + 0x10), &c1, &c2);
+    }
+    advance_time(&env, 200);
+
+    let good = share_batch(&env, &client, eid);
+    let chunks = share_chunks(&good, &[(0, 2), (2, 3)]);
+    let digest = upload_digest(&env, eid, &chunks);
+
+    let begin = |kh: &TestKey, kh_idx: u32, digest: &BytesN<32>| {
+        let sig = kh.sign_bytes(&env, &crate::shares_begin_msg(&env, eid, digest).into());
+        client
+            .try_begin_shares(&eid, &kh_idx, digest, &kh.pk_bytes_n(&env), &sig)
+            .map(|next| next.unwrap())
+            .map_err(|e| e.unwrap())
+    };
+    let chunk_sig = |kh: &TestKey, digest: &BytesN<32>, start: u32, batch: &ShareBatch| {
+        let hash = crate::shares_chunk_hash(&env, eid, start, &crate::serialise_share_batch(&env, batch));
+        kh.sign_bytes(&env, &crate::shares_chunk_msg(&env, eid, digest, &hash).into())
+    };
+    let post_chunk = |kh: &TestKey, kh_idx: u32, digest: &BytesN<32>, start: u32, batch: &ShareBatch| {
+        let sig = chunk_sig(kh, digest, start, batch);
+        client
+            .try_post_share_chunk(&eid, &kh_idx, &start, batch, &sig)
+            .map(|next| next.unwrap())
+            .map_err(|e| e.unwrap())
+    };
+    let commit = |kh_idx: u32| {
+        client.try_commit_shares(&eid, &kh_idx).map(|n| n.unwrap()).map_err(|e| e.unwrap())
+    };
+
+    // Chunks need an open upload, in board order
+    assert_eq!(post_chunk(&kh1, 0, &digest, 0, &chunks[0].1), Err(ContractError::NoShareUpload));
+    assert_eq!(commit(0), Err(ContractError::NoShareUpload));
+    assert_eq!(begin(&kh1, 0, &digest), Ok(0));
+    assert_eq!(post_chunk(&kh1, 0, &digest, 2, &chunks[1].1), Err(ContractError::ChunkOutOfOrder));
+    assert_eq!(post_chunk(&kh1, 0, &digest, 0, &chunks[0].1), Ok(2));
+
+    // A partial upload neither counts nor commits, and blocks a whole post
+    assert_eq!(commit(0), Err(ContractError::ShareUploadIncomplete));
+    assert_eq!(try_post_batch(&env, &client, eid, &kh1, 0, &good), Err(ContractError::AlreadyPosted));
+    assert_eq!(client.get_share_count(&eid), 0);
+
+    // Re-beginning with the same digest resumes where the upload left off
+    assert_eq!(begin(&kh1, 0, &digest), Ok(2));
+    assert_eq!(post_chunk(&kh1, 0, &digest, 2, &chunks[1].1), Ok(3));
+    assert_eq!(commit(0), Ok(1));
+    assert_eq!(commit(0), Err(ContractError::AlreadyPosted));
+    assert_eq!(begin(&kh1, 0, &digest), Err(ContractError::AlreadyPosted));
+
+    let upload = client.get_share_upload(&eid, &0).unwrap();
+    assert_eq!((upload.next, upload.chunks, upload.committed), (3, 2, true));
+    assert_eq!(upload.chain, digest);
+    for (i, (_, batch)) in chunks.iter().enumerate() {
+        let blob = client.get_share_chunk(&eid, &0, &(i as u32)).unwrap();
+        assert_eq!(blob, crate::serialise_share_batch(&env, batch));
+    }
+    assert_eq!(client.get_kh_shares(&eid, &0), None);
+
+    // Chunks that don't chain to the signed digest never commit; a fresh
+    // begin restarts the upload from ballot 0
+    let wrong = BytesN::from_array(&env, &[0x55; 32]);
+    assert_eq!(begin(&kh2, 1, &wrong), Ok(0));
+    for (start, batch) in &share_chunks(&good, &[(0, 1), (1, 2), (2, 3)]) {
+        assert_eq!(post_chunk(&kh2, 1, &wrong, *start, batch), Ok(start + 1));
+    }
+    assert_eq!(commit(1), Err(ContractError::ShareDigestMismatch));
+    assert_eq!(client.get_share_count(&eid), 1);
+
+    // The restart drops every chunk of the abandoned upload, including the
+    // one past the new upload's two
+    assert_eq!(begin(&kh2, 1, &digest), Ok(0));
+    for c in 0..3 {
+        assert_eq!(client.get_share_chunk(&eid, &1, &c), None);
+    }
+    // A chunk signed for the abandoned upload does not verify in this one
+    let stale = chunk_sig(&kh2, &wrong, 0, &chunks[0].1);
+    assert!(client.try_post_share_chunk(&eid, &1, &0, &chunks[0].1, &stale).is_err());
+    for (start, batch) in &chunks {
+        assert_eq!(post_chunk(&kh2, 1, &digest, *start, batch), Ok(start + batch.shares.len()));
+    }
+    assert_eq!(commit(1), Ok(2));
+    assert_eq!(client.get_share_upload(&eid, &1).unwrap().chunks, 2);
+    assert_eq!(client.get_share_chunk(&eid, &1, &2), None);
+    let summary = client.get_summary(&eid);
+    assert_eq!(summary.share_count, summary.kh_threshold);
+}
+
+// ── Test 27: Dispute window, struck and withdrawn shares ─────────────────────
+
+fn k256_bytes(env: &Env, point: &k256::ProjectivePoint) -> Bytes {
+    use k256::elliptic_curve::sec1::ToEncodedPoint;
+    Bytes::from_slice(env, point.to_affine().to_encoded_point(true).as_bytes())
+}
+
+/// `D = sk·C1` with a CP proof, using a fixed nonce `r`.
+fn cp_share(env: &Env, ballot_index: u32, c1: &k256::ProjectivePoint, sk: &k256::Scalar, r: u64) -> DecryptionShare {
+    use k256::elliptic_curve::ops::Reduce;
+    use k256::{FieldBytes, ProjectivePoint, Scalar, U256};
+    let r = Scalar::from(r);
+    let (pk, d) = (ProjectivePoint::GENERATOR * sk, *c1 * sk);
+    let (r1, r2) = (ProjectivePoint::GENERATOR * r, *c1 * r);
+    let mut data = Bytes::new(env);
+    for point in [&ProjectivePoint::GENERATOR, &pk, c1, &d, &r1, &r2] {
+        data.append(&k256_bytes(env, point));
+    }
+    let hash = env.crypto().sha256(&data).to_array();
+    let c = <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(hash));
+    let s = r + c * sk;
+    DecryptionShare {
+        ballot_index,
+        c1: k256_bytes(env, c1),
+        d: k256_bytes(env, &d),
+        proof: ShareProof::Cp(CpProof {
+            r1: k256_bytes(env, &r1),
+            r2: k256_bytes(env, &r2),
+            s: BytesN::from_array(env, &s.to_bytes().into()),
+        }),
+    }
+}
+
+#[test]
+fn test_share_disputes() {
+    use k256::{ProjectivePoint, Scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let kh2 = TestKey::from_seed(2);
+    let dist1 = TestKey::from_seed(3);
+    let voters = [TestKey::from_seed(4), TestKey::from_seed(5)];
+
+    // Two-of-two Feldman DKG: KH j deals f_j(x) = a_j0 + a_j1·x
+    let coeffs = [[11u64, 12], [21, 22]];
+    let mut commitments = Vec::new(&env);
+    for row in &coeffs {
+        let mut points = Vec::new(&env);
+        for &a in row {
+            points.push_back(k256_bytes(&env, &(ProjectivePoint::GENERATOR * Scalar::from(a))));
+        }
+        commitments.push_back(points);
+    }
+    // sk_i = Σ_j f_j(i) for Shamir index i = kh_idx + 1
+    let sk = |i: u64| coeffs.iter().fold(Scalar::ZERO, |acc, [a0, a1]| acc + Scalar::from(a0 + a1 * i));
+
+    let config = ElectionConfig {
+        revoting: false,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::Window(DisputeWindow {
+            secs: 100,
+            vss_digest: crate::cp::vss_digest(&env, &commitments),
+        }),
+        staking: StakePolicy::None,
+    };
+    let eid = deploy_election_with_config(&env, &client, &[&kh1, &kh2], &[&dist1], 1, 2, 10, 200, config);
+    let plain = deploy_election(&env, &client, &[&kh1, &kh2], &[&dist1], 1, 2, 10, 200);
+    advance_time(&env, 15);
+    let c1s = [ProjectivePoint::GENERATOR * Scalar::from(5u64), ProjectivePoint::GENERATOR * Scalar::from(6u64)];
+    for (i, voter) in voters.iter().enumerate() {
+        let seed = i as u8 + 1;
+        issue_account(&env, &client, eid, &voter.pk_bytes_n(&env), &fake_nf(&env, seed), &[&dist1]);
+        let c2 = fake_point(&env, seed + 0x40);
+        cast_ballot(&env, &client, eid, voter, &fake_nf(&env, seed + 0x10), &k256_bytes(&env, &c1s[i]), &c2);
+    }
+    advance_time(&env, 200);
+
+    let batch = |secret: Scalar| {
+        let mut shares = Vec::new(&env);
+        for (i, c1) in c1s.iter().enumerate() {
+            shares.push_back(cp_share(&env, i as u32, c1, &secret, 7 + i as u64));
+        }
+        ShareBatch { version: SHARE_BATCH_VERSION, shares }
+    };
+    let dispute = |kh_idx: u32, ballot_index: u32, evidence: &ShareEvidence| {
+        client
+            .try_dispute_share(&eid, &kh_idx, &ballot_index, evidence)
+            .map(|n| n.unwrap())
+            .map_err(|e| e.unwrap())
+    };
+    let bad_proof = ShareEvidence::BadProof(commitments.clone());
+
+    assert_eq!(
+        client.try_dispute_share(&plain, &0, &0, &bad_proof),
+        Err(Ok(ContractError::DisputesDisabled))
+    );
+    assert_eq!(dispute(0, 0, &bad_proof), Err(ContractError::SharesNotPosted));
+
+    // KH 1 proves with its own share; KH 2 with someone else's
+    assert_eq!(try_post_batch(&env, &client, eid, &kh1, 0, &batch(sk(1))), Ok(1));
+    assert_eq!(client.get_dispute_deadline(&eid), None);
+    assert_eq!(try_post_batch(&env, &client, eid, &kh2, 1, &batch(sk(1))), Ok(2));
+    assert_eq!(client.get_dispute_deadline(&eid), Some(1215 + 100));
+    assert_eq!(
+        client.try_finalize_tally(&eid, &Vec::from_array(&env, [1u32, 1])),
+        Err(Ok(ContractError::DisputeWindowOpen))
+    );
+
+    // Valid shares and wrong matrices don't strike anything
+    assert_eq!(dispute(0, 0, &bad_proof), Err(ContractError::EvidenceRejected));
+    let mut forged = commitments.clone();
+    forged.set(1, commitments.get(0).unwrap());
+    assert_eq!(dispute(1, 0, &ShareEvidence::BadProof(forged)), Err(ContractError::VssMismatch));
+    // The same points in ragged rows would derive another public share
+    let (row0, row1) = (commitments.get(0).unwrap(), commitments.get(1).unwrap());
+    let mut tail = Vec::from_array(&env, [row0.get(1).unwrap()]);
+    tail.append(&row1);
+    let ragged = Vec::from_array(&env, [Vec::from_array(&env, [row0.get(0).unwrap()]), tail]);
+    assert_eq!(dispute(0, 0, &ShareEvidence::BadProof(ragged)), Err(ContractError::VssMismatch));
+    assert_eq!(dispute(1, 5, &bad_proof), Err(ContractError::ShareBatchMismatch));
+
+    let shares_hash = crate::shares_msg(&env, eid, &client.get_kh_shares(&eid, &1).unwrap());
+    assert_eq!(dispute(1, 1, &bad_proof), Ok(1));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("struck"), eid).into_val(&env));
+    let event = events::SharesStruck::try_from_val(&env, &data).unwrap();
+    assert_eq!((event.kh_idx, event.ballot_index, event.share_count), (1, 1, 1));
+    assert_eq!(event.shares_hash, shares_hash);
+    assert_eq!(client.get_kh_shares(&eid, &1), None);
+    assert_eq!(client.get_dispute_deadline(&eid), None);
+
+    // With a dispute window, proofs belong on-chain: a share posted without
+    // one is struck as if its proof had failed
+    advance_time(&env, 50);
+    let mut unproven = batch(sk(2));
+    let mut share = unproven.shares.get(1).unwrap();
+    share.proof = ShareProof::None;
+    unproven.shares.set(1, share);
+    assert_eq!(try_post_batch(&env, &client, eid, &kh2, 1, &unproven), Ok(2));
+    assert_eq!(dispute(1, 0, &bad_proof), Err(ContractError::EvidenceRejected));
+    assert_eq!(dispute(1, 1, &bad_proof), Ok(1));
+
+    // The struck key holder may post again, which restarts the window
+    assert_eq!(try_post_batch(&env, &client, eid, &kh2, 1, &batch(sk(2))), Ok(2));
+    assert_eq!(client.get_dispute_deadline(&eid), Some(1265 + 100));
+    assert_eq!(dispute(1, 0, &bad_proof), Err(ContractError::EvidenceRejected));
+
+    // A key holder withdraws its own counted shares with a signature
+    let shares_hash = crate::shares_msg(&env, eid, &client.get_kh_shares(&eid, &0).unwrap());
+    let withdraw_sig = |nonce: u32| kh1.sign_bytes(&env, &crate::withdraw_msg(&env, eid, nonce, &shares_hash).into());
+    assert_eq!(client.get_withdraw_nonce(&eid, &0), 0);
+    let sig = withdraw_sig(0);
+    assert_eq!(client.withdraw_shares(&eid, &0, &sig), 1);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("withdrawn"), eid).into_val(&env));
+    assert_eq!(events::SharesWithdrawn::try_from_val(&env, &data).unwrap().shares_hash, shares_hash);
+    assert_eq!(client.get_withdraw_nonce(&eid, &0), 1);
+    assert_eq!(client.try_withdraw_shares(&eid, &0, &sig), Err(Ok(ContractError::SharesNotPosted)));
+    assert_eq!(try_post_batch(&env, &client, eid, &kh1, 0, &batch(sk(1))), Ok(2));
+
+    // Re-posting the same batch does not let the old signature pull it again
+    assert!(client.try_withdraw_shares(&eid, &0, &sig).is_err());
+    assert_eq!(client.withdraw_shares(&eid, &0, &withdraw_sig(1)), 1);
+    assert_eq!(try_post_batch(&env, &client, eid, &kh1, 0, &batch(sk(1))), Ok(2));
+
+    advance_time(&env, 99);
+    assert_eq!(
+        client.try_finalize_tally(&eid, &Vec::from_array(&env, [1u32, 1])),
+        Err(Ok(ContractError::DisputeWindowOpen))
+    );
+    advance_time(&env, 1);
+
+    // A missing threshold time never counts as a closed window
+    let key = DataKey::ThresholdAt(eid);
+    let threshold_at: u64 = env.as_contract(&contract_id, || env.storage().persistent().get(&key).unwrap());
+    env.as_contract(&contract_id, || env.storage().persistent().remove(&key));
+    assert_eq!(
+        client.try_finalize_tally(&eid, &Vec::from_array(&env, [1u32, 1])),
+        Err(Ok(ContractError::DisputeWindowOpen))
+    );
+    env.as_contract(&contract_id, || env.storage().persistent().set(&key, &threshold_at));
+    client.finalize_tally(&eid, &Vec::from_array(&env, [1u32, 1]));
+}
+
+// ── Test 28: Key-holder stakes, slashing and return ──────────────────────────
+
+#[test]
+fn test_kh_stakes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+    let token_id = env.register(MockToken, ());
+    let token = MockTokenClient::new(&env, &token_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let kh2 = TestKey::from_seed(2);
+    let kh3 = TestKey::from_seed(6);
+    let dist1 = TestKey::from_seed(3);
+    let voter = TestKey::from_seed(4);
+    let stakers = [Address::generate(&env), Address::generate(&env)];
+    for staker in &stakers {
+        token.mint(staker, &100);
+    }
+    let recipient = Address::generate(&env);
+
+    let bond = KhBond { token: token_id.clone(), amount: 40, recipient: recipient.clone(), tally_deadline: 1300 };
+    let config = |bond: KhBond| ElectionConfig {
+        revoting: false,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::Bond(bond),
+    };
+    let try_deploy = |bond: KhBond| {
+        client.try_deploy(
+            &Address::generate(&env),
+            &Bytes::from_slice(&env, b"Staked"),
+            &2,
+            &1010,
+            &1200,
+            &fake_point(&env, 0x99),
+            &fake_nf(&env, 0x42),
+            &Committee { members: Vec::from_array(&env, [dist1.pk_bytes_n(&env)]), threshold: 1 },
+            &Committee { members: Vec::from_array(&env, [kh1.pk_bytes_n(&env)]), threshold: 1 },
+            &config(bond),
+        )
+    };
+    assert_eq!(try_deploy(KhBond { amount: 0, ..bond.clone() }), Err(Ok(ContractError::InvalidStakePolicy)));
+    assert_eq!(try_deploy(KhBond { tally_deadline: 1200, ..bond.clone() }), Err(Ok(ContractError::InvalidStakePolicy)));
+
+    let khs = [&kh1, &kh2, &kh3];
+    let eid = deploy_election_with_config(&env, &client, &khs, &[&dist1], 1, 2, 10, 200, config(bond.clone()));
+    let cancelled = deploy_election_with_config(&env, &client, &khs, &[&dist1], 1, 2, 10, 200, config(bond.clone()));
+    let unbonded = deploy_election_with_config(&env, &client, &khs, &[&dist1], 1, 2, 10, 200, config(bond));
+    let plain = deploy_election(&env, &client, &khs, &[&dist1], 1, 2, 10, 200);
+
+    // Every slot bonds a stake, slot 2 paid by the second staker too
+    let stake_sig = |eid: u64, kh: &TestKey, kh_idx: u32, staker: &Address| {
+        kh.sign_bytes(&env, &crate::stake_msg(&env, eid, kh_idx, staker).into())
+    };
+    let sig = stake_sig(plain, &kh1, 0, &stakers[0]);
+    assert_eq!(client.try_deposit_stake(&plain, &0, &stakers[0], &sig), Err(Ok(ContractError::StakingDisabled)));
+    // The key holder's signature binds the slot to its staker
+    assert!(client.try_deposit_stake(&eid, &0, &stakers[1], &stake_sig(eid, &kh1, 0, &stakers[0])).is_err());
+    assert!(client.try_deposit_stake(&eid, &0, &stakers[0], &stake_sig(eid, &kh2, 0, &stakers[0])).is_err());
+    client.deposit_stake(&eid, &0, &stakers[0], &stake_sig(eid, &kh1, 0, &stakers[0]));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("staked"), eid).into_val(&env));
+    assert_eq!(events::StakeDeposited::try_from_val(&env, &data).unwrap().amount, 40);
+    client.deposit_stake(&eid, &1, &stakers[1], &stake_sig(eid, &kh2, 1, &stakers[1]));
+    let sig = stake_sig(eid, &kh1, 0, &stakers[1]);
+    assert_eq!(client.try_deposit_stake(&eid, &0, &stakers[1], &sig), Err(Ok(ContractError::AlreadyStaked)));
+    assert_eq!(client.try_deposit_stake(&eid, &3, &stakers[1], &sig), Err(Ok(ContractError::NotKeyHolder)));
+    assert_eq!(client.get_kh_stake(&eid, &1), Some(stakers[1].clone()));
+    client.deposit_stake(&eid, &2, &stakers[1], &stake_sig(eid, &kh3, 2, &stakers[1]));
+    assert_eq!(token.balance(&contract_id), 120);
+
+    // A cancelled election returns its stakes
+    client.deposit_stake(&cancelled, &0, &stakers[0], &stake_sig(cancelled, &kh1, 0, &stakers[0]));
+    assert_eq!(token.balance(&stakers[0]), 20);
+    client.cancel(&cancelled, &Bytes::from_slice(&env, b"superseded"));
+    assert_eq!(token.balance(&stakers[0]), 60);
+    assert_eq!(client.get_kh_stake(&cancelled, &0), None);
+    client.deposit_stake(&unbonded, &0, &stakers[0], &stake_sig(unbonded, &kh1, 0, &stakers[0]));
+
+    advance_time(&env, 15);
+    let sig = stake_sig(unbonded, &kh1, 0, &stakers[1]);
+    assert_eq!(client.try_deposit_stake(&unbonded, &0, &stakers[1], &sig), Err(Ok(ContractError::RegistrationClosed)));
+
+    // An election short of stakes at the start takes no ballots
+    let (c1, c2) = (fake_point(&env, 1), fake_point(&env, 2));
+    issue_account(&env, &client, unbonded, &voter.pk_bytes_n(&env), &fake_nf(&env, 0x51), &[&dist1]);
+    let nf = fake_nf(&env, 0x61);
+    let sig = voter.sign_bytes(&env, &mk_cast_msg(&env, unbonded, &nf, &c1, &c2));
+    assert_eq!(
+        client.try_cast(&unbonded, &nf, &c1, &c2, &voter.pk_bytes_n(&env), &sig),
+        Err(Ok(ContractError::StakesIncomplete))
+    );
+
+    issue_account(&env, &client, eid, &voter.pk_bytes_n(&env), &fake_nf(&env, 0x50), &[&dist1]);
+    cast_ballot(&env, &client, eid, &voter, &fake_nf(&env, 0x60), &c1, &c2);
+    advance_time(&env, 200);
+    post_kh_share(&env, &client, eid, &kh1, 0);
+    post_kh_share(&env, &client, eid, &kh3, 2);
+
+    // Slot 1 never posted: its stake is forfeit once the deadline passes,
+    // even while the organizer has the election paused
+    assert_eq!(client.try_slash_absent(&eid), Err(Ok(ContractError::TallyDeadlineNotReached)));
+    set_timestamp(&env, 1300);
+    client.pause(&eid);
+    assert_eq!(client.slash_absent(&eid), 1);
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("slashed"), eid).into_val(&env));
+    let event = events::StakeSlashed::try_from_val(&env, &data).unwrap();
+    assert_eq!((event.kh_idx, event.recipient, event.amount), (1, recipient.clone(), 40));
+    assert_eq!(token.balance(&recipient), 40);
+    assert_eq!(client.get_kh_stake(&eid, &1), None);
+    assert_eq!(client.slash_absent(&eid), 0);
+    client.resume(&eid);
+
+    // The tally returns the remaining stakes
+    client.finalize_tally(&eid, &Vec::from_array(&env, [1u32, 0]));
+    let (_, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("returned"), eid).into_val(&env));
+    assert_eq!(events::StakeReturned::try_from_val(&env, &data).unwrap().staker, stakers[1]);
+    assert_eq!(token.balance(&stakers[0]), 60);
+    assert_eq!(token.balance(&stakers[1]), 60);
+    assert_eq!(client.try_slash_absent(&eid), Err(Ok(ContractError::AlreadyTallied)));
+
+    // Cancelling after the deadline slashes absent slots rather than
+    // returning their stakes
+    client.cancel(&unbonded, &Bytes::from_slice(&env, b"abandoned"));
+    assert_eq!(client.get_kh_stake(&unbonded, &0), None);
+    assert_eq!(token.balance(&recipient), 80);
+    assert_eq!(token.balance(&stakers[0]), 60);
+    assert_eq!(token.balance(&contract_id), 0);
+}
+
+// ── Test 29: Paged maintenance ends where a single run does ──────────────────
+
+/// Page counts of each walk and the persistent entries after the bump and
+/// after the archive/delete, from `paged_maintenance`.
+type Maintenance = ([u32; 3], [std::vec::Vec<std::string::String>; 2]);
+
+/// Run two small elections in a fresh `Env` — one revoting and tallied, one
+/// left untallied — and walk them with `bump_election`, `archive_election`
+/// and `delete_election` pages of `limit` positions.
+fn paged_maintenance(limit: u32) -> Maintenance {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let config = ElectionConfig {
+        revoting: true,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let tallied = deploy_election_with_config(
+        &env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200, config,
+    );
+    let deleted = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    advance_time(&env, 15);
+    for seed in 0..4u8 {
+        let voter = TestKey::from_seed(0x40 + seed);
+        issue_account(&env, &client, tallied, &voter.pk_bytes_n(&env), &fake_nf(&env, 0x50 + seed), &[&dist1]);
+        cast_ballot(&env, &client, tallied, &voter, &fake_nf(&env, 0x60 + seed),
+            &fake_point(&env, seed), &fake_point(&env, seed + 1));
+    }
+    let voter = TestKey::from_seed(0x40);
+    cast_ballot(&env, &client, tallied, &voter, &fake_nf(&env, 0x70), &fake_point(&env, 9), &fake_point(&env, 8));
+    for seed in 0..2u8 {
+        let voter = TestKey::from_seed(0x48 + seed);
+        issue_account(&env, &client, deleted, &voter.pk_bytes_n(&env), &fake_nf(&env, 0x58 + seed), &[&dist1]);
+        cast_ballot(&env, &client, deleted, &voter, &fake_nf(&env, 0x68 + seed),
+            &fake_point(&env, seed), &fake_point(&env, seed + 2));
+    }
+
+    let walk = |step: &dyn Fn(u32) -> Option<u32>| {
+        let mut pages = 0;
+        let mut cursor = Some(0);
+        while let Some(c) = cursor {
+            cursor = step(c);
+            pages += 1;
+        }
+        pages
+    };
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 1_000_000;
+        li.timestamp += 1_000_000 * crate::LEDGER_SECS;
+    });
+    let bump_pages = walk(&|c| client.bump_election(&tallied, &c, &limit).next);
+    let bumped = persistent_entries(&env);
+
+    post_kh_share(&env, &client, tallied, &kh1, 0);
+    client.finalize_tally(&tallied, &Vec::from_array(&env, [2u32, 2]));
+    let archive_pages = walk(&|c| client.archive_election(&tallied, &c, &limit).next);
+    let delete_pages = walk(&|c| client.delete_election(&deleted, &c, &limit).next);
+
+    ([bump_pages, archive_pages, delete_pages], [bumped, persistent_entries(&env)])
+}
+
+/// Persistent contract-data entries of the ledger, rendered for comparison.
+fn persistent_entries(env: &Env) -> std::vec::Vec<std::string::String> {
+    use soroban_sdk::xdr::{ContractDataDurability, LedgerKey};
+    let mut entries: std::vec::Vec<_> = env
+        .to_ledger_snapshot()
+        .ledger_entries
+        .into_iter()
+        .filter(|(key, _)| matches!(
+            key.as_ref(),
+            LedgerKey::ContractData(k) if k.durability == ContractDataDurability::Persistent
+        ))
+        .map(|(key, (entry, ttl))| std::format!("{key:?} {entry:?} {ttl:?}"))
+        .collect();
+    entries.sort();
+    entries
+}
+
+#[test]
+fn test_paged_maintenance() {
+    // Tallied: 4 accounts, 4 ballots, 5 casts, 5 leaves, 1 registry root.
+    // Deleted: 2 accounts, 2 ballots, 2 casts, 2 leaves, 1 registry root.
+    let (pages, single) = paged_maintenance(20);
+    assert_eq!(pages, [1, 1, 1]);
+    let (pages, paged) = paged_maintenance(1);
+    assert_eq!(pages, [19, 19, 9]);
+    assert_eq!(paged, single);
+
+    // Only the contract instance and the archive record are left
+    let [_, after] = single;
+    assert_eq!(after.len(), 2);
+    assert!(after.iter().any(|entry| entry.contains("Archive")));
+
+    // A pending walk continues only from where it stopped
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let voter = TestKey::from_seed(4);
+    let eid = deploy_election(&env, &client, &[&kh1], &[&dist1], 1, 1, 10, 200);
+    advance_time(&env, 15);
+    issue_account(&env, &client, eid, &voter.pk_bytes_n(&env), &fake_nf(&env, 0x01), &[&dist1]);
+    assert_eq!(client.try_delete_election(&eid, &1, &1), Err(Ok(ContractError::NotFound)));
+    advance_time(&env, 200);
+    assert_eq!(client.delete_election(&eid, &0, &1).next, Some(1));
+    assert!(client.get_election(&eid).is_none());
+    assert_eq!(client.try_delete_election(&eid, &0, &1), Err(Ok(ContractError::NotFound)));
+    assert_eq!(client.try_delete_election(&eid, &2, &1), Err(Ok(ContractError::InvalidCursor)));
+    assert_eq!(client.delete_election(&eid, &1, &1).next, None);
+    assert_eq!(client.try_delete_election(&eid, &2, &1), Err(Ok(ContractError::NotFound)));
+}
+
+// ── Test 30: A single-election instance holds one admin-deployed election ───
+
+#[test]
+fn test_single_election_instance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+
+    let kh1 = TestKey::from_seed(1);
+    let dist1 = TestKey::from_seed(3);
+    let committee = |k: &TestKey| Committee { members: Vec::from_array(&env, [k.pk_bytes_n(&env)]), threshold: 1 };
+    let config = ElectionConfig {
+        revoting: false,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let deploy = |client: &ElectionContractClient, organizer: &Address| {
+        client.try_deploy(
+            organizer,
+            &Bytes::from_slice(&env, b"Solo"),
+            &2u32,
+            &1010,
+            &1200,
+            &fake_point(&env, 0xAA),
+            &fake_nf(&env, 0x42),
+            &committee(&dist1),
+            &committee(&kh1),
+            &config,
+        )
+    };
+    let signers = || env.auths().into_iter().map(|(a, _)| a).collect::<std::vec::Vec<_>>();
+
+    let admin = Address::generate(&env);
+    let organizer = Address::generate(&env);
+    let single = ElectionContractClient::new(&env, &env.register(ElectionContract, (admin.clone(), true)));
+
+    // Deploying needs the instance admin as well as the organizer
+    assert_eq!(deploy(&single, &organizer), Ok(Ok(0)));
+    assert_eq!(signers(), std::vec![organizer.clone(), admin.clone()]);
+    assert_eq!(deploy(&single, &admin), Err(Ok(ContractError::AlreadyExists)));
+    assert_eq!(single.get_election(&0).unwrap().organizer, organizer);
+
+    // A shared instance takes any organizer's elections without the admin
+    let shared = ElectionContractClient::new(&env, &env.register(ElectionContract, (admin.clone(), false)));
+    assert_eq!(deploy(&shared, &organizer), Ok(Ok(0)));
+    assert_eq!(signers(), std::vec![organizer.clone()]);
+    assert_eq!(deploy(&shared, &organizer), Ok(Ok(1)));
+}
+
+// ── Test 31: On-chain dispute checks fit the network limits ──────────────────
+
+/// Network caps on an uploaded contract and on one transaction's CPU.
+const MAX_WASM_BYTES: usize = 64 * 1024;
+const MAX_TX_INSTRUCTIONS: u64 = 100_000_000;
+
+/// The k256 dispute path is only metered realistically inside the VM, so
+/// this runs the WASM `election_wasm` builds.
+#[test]
+fn test_dispute_budget() {
+    use k256::{ProjectivePoint, Scalar};
+
+    let Some(wasm) = crate::testutils::election_wasm() else {
+        std::eprintln!("skipped: election.wasm could not be built (wasm32v1-none target missing?)");
+        return;
+    };
+    assert!(wasm.len() <= MAX_WASM_BYTES, "election.wasm is {} bytes", wasm.len());
+
+    let env = Env::default();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+    let client = ElectionContractClient::new(&env, &env.register(wasm.as_slice(), (Address::generate(&env), false)));
+
+    let kh1 = TestKey::from_seed(1);
+    let kh2 = TestKey::from_seed(2);
+    let dist1 = TestKey::from_seed(3);
+    let voter = TestKey::from_seed(4);
+
+    let coeffs = [[11u64, 12], [21, 22]];
+    let mut commitments = Vec::new(&env);
+    for row in &coeffs {
+        let mut points = Vec::new(&env);
+        for &a in row {
+            points.push_back(k256_bytes(&env, &(ProjectivePoint::GENERATOR * Scalar::from(a))));
+        }
+        commitments.push_back(points);
+    }
+    let config = ElectionConfig {
+        revoting: false,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::Window(DisputeWindow {
+            secs: 100,
+            vss_digest: crate::cp::vss_digest(&env, &commitments),
+        }),
+        staking: StakePolicy::None,
+    };
+    let eid = deploy_election_with_config(&env, &client, &[&kh1, &kh2], &[&dist1], 1, 2, 10, 200, config);
+    advance_time(&env, 15);
+    let c1 = ProjectivePoint::GENERATOR * Scalar::from(5u64);
+    issue_account(&env, &client, eid, &voter.pk_bytes_n(&env), &fake_nf(&env, 1), &[&dist1]);
+    cast_ballot(&env, &client, eid, &voter, &fake_nf(&env, 0x11), &k256_bytes(&env, &c1), &fake_point(&env, 0x41));
+    advance_time(&env, 200);
+
+    // KH 2 proves with a secret that is not its share, so the full check runs
+    let share = cp_share(&env, 0, &c1, &Scalar::from(7u64), 9);
+    let batch = ShareBatch { version: SHARE_BATCH_VERSION, shares: Vec::from_array(&env, [share]) };
+    assert_eq!(try_post_batch(&env, &client, eid, &kh2, 1, &batch), Ok(1));
+
+    let mut budget = env.cost_estimate().budget();
+    budget.reset_default();
+    assert_eq!(client.dispute_share(&eid, &1, &0, &ShareEvidence::BadProof(commitments)), 0);
+    let cpu = env.cost_estimate().budget().cpu_instruction_cost();
+    assert!(cpu <= MAX_TX_INSTRUCTIONS, "dispute_share used {cpu} instructions");
+}
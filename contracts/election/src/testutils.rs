@@ -0,0 +1,37 @@
+//! Test helpers for running the contract as the network does, from its WASM,
+//! where code size is capped and host calls are metered for real.
+
+extern crate std;
+
+use std::{format, process::Command, vec::Vec};
+
+const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../..");
+
+/// Build `election.wasm` the way `stellar contract build` does (release
+/// profile, `wasm32v1-none`) and return its bytes.
+///
+/// The build uses its own target directory: the `cargo test` calling this
+/// holds the lock on the workspace's.  Returns `None` if the build fails,
+/// e.g. without the `wasm32v1-none` target installed, so callers can skip;
+/// under `CI` that is a panic instead, so the WASM tests always run there.
+pub fn election_wasm() -> Option<Vec<u8>> {
+    let target_dir = format!("{WORKSPACE}/target/test-wasm");
+    let output = Command::new(env!("CARGO"))
+        .args(["build", "--release", "--target", "wasm32v1-none", "-p", "election"])
+        .env("CARGO_TARGET_DIR", &target_dir)
+        .current_dir(WORKSPACE)
+        .output();
+    match output {
+        Ok(output) if output.status.success() => {}
+        Ok(output) => {
+            let stderr = std::string::String::from_utf8_lossy(&output.stderr);
+            assert!(std::env::var_os("CI").is_none(), "building election.wasm failed:\n{stderr}");
+            return None;
+        }
+        Err(err) => {
+            assert!(std::env::var_os("CI").is_none(), "could not run cargo: {err}");
+            return None;
+        }
+    }
+    Some(std::fs::read(format!("{target_dir}/wasm32v1-none/release/election.wasm")).unwrap())
+}
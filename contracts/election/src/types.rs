@@ -1,19 +1,40 @@
-use soroban_sdk::{contracttype, Bytes, BytesN, Vec};
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Vec};
 
 // ── Storage keys ──────────────────────────────────────────────────────────────
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    /// Global counter; value = next eid to assign
+    /// Global counter; value = next eid to assign (instance storage)
     NextElectionId,
+    /// Contract admin, set by the constructor (instance storage)
+    Admin,
+    /// Proposed upgrade awaiting its timelock (instance storage)
+    PendingUpgrade,
+    /// Progress of a paged `execute_upgrade` (instance storage)
+    UpgradeCheck,
+    /// Storage schema version of the stored data (instance storage)
+    SchemaVersion,
+    /// The instance holds one election, created by its admin (instance storage)
+    SingleElection,
     /// Core election parameters
     Election(u64),
     /// Per-KH constant-term commitment A_j0 (33-byte compressed secp256k1)
     /// Key: (eid, kh_index as u32)
     KhCommitment(u64, u32),
-    /// Merkle root of the eligible-voter set
+    /// Merkle root of the eligible-voter set (current root in registry mode)
     EligibleRoot(u64),
+    /// Sparse Merkle root of revoked voters (absent = nobody revoked)
+    RevocationRoot(u64),
+    /// Token mode: holder address already issued a casting account
+    TokenHolder(u64, Address),
+    /// Token mode: balance the holder's issuance counted, held by the contract
+    /// until it is released (`TokenEscrow`)
+    TokenEscrow(u64, Address),
+    /// Registry mode: number of root changes since deploy
+    RegistryVersion(u64),
+    /// Registry mode: eligibility root at version v (`RegistryRoot`)
+    RegistryRoot(u64, u32),
     /// Distributor committee Ed25519 public keys (32-byte each)
     DistRoster(u64),
     /// Distributor threshold (M-of-N)
@@ -24,20 +45,60 @@ pub enum DataKey {
     KhThreshold(u64),
     /// Issue nullifiers consumed
     IssueNullifier(u64, BytesN<32>),
-    /// Casting accounts (32-byte Ed25519 pubkey) that have been registered
+    /// Casting account record (status, issue time, ballot index), keyed by
+    /// the 32-byte Ed25519 pubkey
     CastingAccount(u64, BytesN<32>),
+    /// Number of casting accounts issued
+    IssuedCount(u64),
+    /// pk_cast of the i-th issued casting account, so that issuance entries
+    /// can be enumerated (e.g. by `bump_election`)
+    Issued(u64, u32),
     /// Cast nullifiers consumed
     CastNullifier(u64, BytesN<32>),
+    /// Number of cast nullifiers consumed, revotes included
+    CastCount(u64),
+    /// nf_cast of the i-th accepted ballot, so that cast nullifiers can be
+    /// enumerated even after a revote overwrites the ballot slot
+    Cast(u64, u32),
     /// Number of ballots stored
     BallotCount(u64),
     /// Ballot at index i
     Ballot(u64, u32),
-    /// Number of KH share-batches posted
+    /// Number of KH share-batches posted, less any struck or withdrawn
     ShareCount(u64),
+    /// Account that bonded KH j's stake (`Address`), until it is slashed or
+    /// returned.  Key: (eid, kh_index)
+    KhStake(u64, u32),
+    /// Ledger timestamp at which `ShareCount` last reached the KH threshold;
+    /// removed when it drops back below
+    ThresholdAt(u64),
     /// Serialised decryption shares posted by KH j (index in roster)
     KhShare(u64, u32),
-    /// Final tally (one u32 per option)
+    /// Chunked share upload by KH j (`ShareUpload`)
+    ShareUpload(u64, u32),
+    /// Chunk k of KH j's upload, serialised like a share batch
+    /// Key: (eid, kh_index, chunk)
+    ShareChunk(u64, u32, u32),
+    /// Withdrawals by KH j so far (`u32`), bound into its next `withdraw_msg`
+    WithdrawNonce(u64, u32),
+    /// Final tally record (`TallyRecord`)
     Tally(u64),
+    /// Cancellation record, present once the organizer cancels the election
+    Cancellation(u64),
+    /// Append-only list of voting-window changes (`Vec<ScheduleChange>`)
+    ScheduleHistory(u64),
+    /// Compact record replacing all other entries once an election is archived
+    Archive(u64),
+    /// Walk position of an `archive_election` / `delete_election` that has
+    /// not yet freed every entry
+    Purge(u64),
+    /// Head of the ballot-board hash chain (see `board_step`)
+    BoardHead(u64),
+    /// Frontier of the incremental Merkle tree over accepted ballots
+    BallotTree(u64),
+    /// Leaf at position i of the ballot tree, kept so proofs can still be
+    /// built after a revote overwrites the ballot that produced it
+    BallotLeaf(u64, u32),
 }
 
 // ── Core structs ──────────────────────────────────────────────────────────────
@@ -46,6 +107,8 @@ pub enum DataKey {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ElectionParams {
     pub eid: u64,
+    /// Account allowed to pause, resume and cancel the election
+    pub organizer: Address,
     pub title: Bytes,
     pub options_count: u32,
     pub start_time: u64,
@@ -53,6 +116,434 @@ pub struct ElectionParams {
     /// Combined KH public key (33-byte compressed secp256k1)
     pub enc_pubkey: Bytes,
     pub tallied: bool,
+    pub status: ElectionStatus,
+    pub config: ElectionConfig,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ElectionStatus {
+    /// Normal operation
+    Active,
+    /// Halted by the organizer; issuance, casting and share posting rejected
+    Paused,
+    /// Permanently halted; retained read-only
+    Cancelled,
+}
+
+/// Lifecycle phase derived from status, schedule and tally state.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ElectionPhase {
+    /// Before `start_time`
+    Registration,
+    /// Between `start_time` and `end_time`
+    Voting,
+    /// After `end_time`, not yet tallied
+    Tallying,
+    Tallied,
+    Cancelled,
+}
+
+/// Stored under `DataKey::PendingUpgrade`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub proposed_at: u64,
+    /// Earliest ledger timestamp at which `execute_upgrade` succeeds
+    pub executable_at: u64,
+}
+
+/// Stored under `DataKey::UpgradeCheck` between pages of `execute_upgrade`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeCheck {
+    /// Eids below this are not voting or awaiting their tally
+    pub checked: u64,
+    /// Earliest `start_time` among the checked elections still registering;
+    /// once reached, the walk restarts
+    pub next_start: u64,
+}
+
+/// Stored under `DataKey::Cancellation(eid)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cancellation {
+    /// Ledger timestamp of the `cancel` call
+    pub cancelled_at: u64,
+    /// Free-form UTF-8 reason supplied by the organizer
+    pub reason: Bytes,
+}
+
+/// Per-election behaviour switches chosen at deploy time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElectionConfig {
+    /// When set, a casting account may cast again; the new ballot replaces
+    /// the previous one at the same ballot index.
+    pub revoting: bool,
+    /// Latest `end_time` that `extend_voting` may move the deadline to.
+    /// Equal to (or below) `end_time` disables extensions.
+    pub max_end_time: u64,
+    /// How `eligibility_root` is maintained and checked at issuance
+    pub eligibility: EligibilityMode,
+    /// Whether posted share batches can be disputed before `finalize_tally`
+    pub disputes: DisputePolicy,
+    /// Whether key holders bond a stake they forfeit by missing the tally
+    pub staking: StakePolicy,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StakePolicy {
+    /// Key holders put nothing at stake
+    None,
+    /// Each roster slot may be bonded with `deposit_stake` before
+    /// `start_time`; `slash_absent` forfeits the bond of a slot without
+    /// counted shares at the deadline
+    Bond(KhBond),
+}
+
+/// Key-holder bond parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KhBond {
+    /// SEP-41 token the stake is paid in
+    pub token: Address,
+    /// Stake per roster slot, in the token's base units
+    pub amount: i128,
+    /// Receives forfeited stakes
+    pub recipient: Address,
+    /// Ledger timestamp by which key holders must have counted shares;
+    /// after `max_end_time`
+    pub tally_deadline: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputePolicy {
+    /// `finalize_tally` as soon as the KH threshold is met; batches can only
+    /// be withdrawn by their key holder
+    None,
+    /// Anyone may strike a batch with `dispute_share`, and `finalize_tally`
+    /// waits until the window after the threshold has passed.
+    Window(DisputeWindow),
+}
+
+/// Dispute window parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeWindow {
+    /// Seconds after `ShareCount` reaches the KH threshold
+    pub secs: u64,
+    /// `cp::vss_digest` of the DKG's Feldman commitment matrix, which fixes
+    /// the public shares CP proofs are checked against
+    pub vss_digest: BytesN<32>,
+}
+
+/// Why a posted share is invalid, for `dispute_share`.  A C1 off the board
+/// needs no evidence: `post_share` and `post_share_chunk` reject it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ShareEvidence {
+    /// The share's CP proof is missing or fails against the key holder's
+    /// public share, derived from this commitment matrix (one row of
+    /// `kh_threshold` points per key holder).  With a dispute window every
+    /// share must carry its proof on-chain, so `ShareProof::None` is struck
+    /// with any matrix matching `vss_digest`.
+    BadProof(Vec<Vec<Bytes>>),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EligibilityMode {
+    /// Dense Merkle root fixed at deploy.  Distributors vouch for eligibility;
+    /// an on-chain `EligibilityProof::Merkle` is optional.
+    Static,
+    /// Organizer-managed sparse Merkle registry (`add_voter` / `revoke_voter`)
+    /// that freezes at `start_time`.  Issuance requires
+    /// `EligibilityProof::Registry` against the current root.
+    Registry,
+    /// Holders of a SAC / SEP-41 token.  Issuance requires
+    /// `EligibilityProof::Token` signed by a holder with at least
+    /// `min_balance`; each holder address is issued at most one account.
+    Token(TokenGate),
+}
+
+/// Token-gated eligibility parameters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGate {
+    /// SAC / SEP-41 token contract
+    pub token: Address,
+    /// Minimum balance to be eligible (> 0)
+    pub min_balance: i128,
+    /// Weight each ballot by `balance / min_balance` instead of 1
+    pub weighted: bool,
+}
+
+/// Tokens moved from a holder into the contract at issuance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenEscrow {
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Proof that the voter behind an issuance is eligible.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EligibilityProof {
+    /// (voter leaf bytes, bottom-up `(sibling, is_right)` path) in the dense tree
+    Merkle(Bytes, Vec<(BytesN<32>, bool)>),
+    /// (voter key bytes, membership proof) in the sparse registry tree
+    Registry(Bytes, SmtProof),
+    /// Token holder address; must authorize the issuance
+    Token(Address),
+}
+
+/// One casting account in a `batch_issue_accounts` call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssueItem {
+    pub pk_cast: BytesN<32>,
+    pub nf_issue: BytesN<32>,
+    /// Voter leaf bytes in the eligibility tree
+    pub leaf: Bytes,
+    /// Position of `leaf` in the eligibility tree
+    pub leaf_index: u32,
+}
+
+/// Multiproof for several leaves of the eligibility tree (see `merkle.rs`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleMultiProof {
+    /// Number of levels between the leaves and the root
+    pub depth: u32,
+    /// Nodes not derivable from the batch, bottom-up and left to right
+    pub siblings: Vec<BytesN<32>>,
+}
+
+/// Compressed sparse Merkle proof (see `smt.rs`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SmtProof {
+    /// Bit h set → the sibling at height h is non-empty and taken from `siblings`
+    pub bitmap: BytesN<32>,
+    /// Non-empty siblings, bottom-up
+    pub siblings: Vec<BytesN<32>>,
+}
+
+/// One version of a registry-mode eligibility root.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistryRoot {
+    pub root: BytesN<32>,
+    /// Ledger timestamp at which this root became current
+    pub updated_at: u64,
+}
+
+/// One entry of `DataKey::ScheduleHistory(eid)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleChange {
+    /// Ledger timestamp of the `extend_voting` call
+    pub changed_at: u64,
+    pub old_end_time: u64,
+    pub new_end_time: u64,
+}
+
+/// Incremental Merkle tree state (see `merkle::append`): the number of
+/// leaves and one frontier node per level.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MerkleFrontier {
+    pub size: u32,
+    pub branch: Vec<BytesN<32>>,
+}
+
+/// An Ed25519 signing committee (distributors or key-holders).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Committee {
+    /// Ed25519 public keys (32-byte each)
+    pub members: Vec<BytesN<32>>,
+    /// Number of member signatures / shares required
+    pub threshold: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccountStatus {
+    /// Registered by the distributors, no ballot cast yet
+    Issued,
+    /// Has a ballot on the board
+    Voted,
+}
+
+/// Stored under `DataKey::CastingAccount(eid, pk_cast)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CastingAccount {
+    pub status: AccountStatus,
+    /// Ledger timestamp of `issue_account`
+    pub issued_at: u64,
+    /// Issue nullifier consumed when this account was registered
+    pub nf_issue: BytesN<32>,
+    /// Index of this account's ballot on the board, once cast
+    pub ballot_index: Option<u32>,
+    /// Token mode: the holder this account was issued to
+    pub holder: Option<Address>,
+    /// Tally weight of this account's ballot (1 unless token-weighted)
+    pub weight: u32,
+}
+
+/// Stored form of `ElectionParams` under `DataKey::Election` (schema 2+;
+/// schema 1 stored the bare `ElectionParamsV1`).  Variant numbers count
+/// wrapped layouts, struct suffixes the schema a layout was last written in.
+/// To change `ElectionParams`, freeze the current layout as
+/// `ElectionParamsV<schema>` for the latest variant, add a new variant holding
+/// `ElectionParams`, and convert in `load_election`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VersionedElection {
+    V1(ElectionParamsV2),
+    /// Schema 3: `ElectionConfig` gained `disputes`
+    V2(ElectionParamsV3),
+    /// Schema 4: `ElectionConfig` gained `staking`
+    V3(ElectionParams),
+}
+
+/// `ElectionParams` as the first release stored it, bare, in schema 1.  It
+/// predates organizers, pausing and `ElectionConfig`; `load_election` fills
+/// those in.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElectionParamsV1 {
+    pub eid: u64,
+    pub title: Bytes,
+    pub options_count: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub enc_pubkey: Bytes,
+    pub tallied: bool,
+}
+
+/// `ElectionParams` as stored in schema 2.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElectionParamsV2 {
+    pub eid: u64,
+    pub organizer: Address,
+    pub title: Bytes,
+    pub options_count: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub enc_pubkey: Bytes,
+    pub tallied: bool,
+    pub status: ElectionStatus,
+    pub config: ElectionConfigV2,
+}
+
+/// `ElectionConfig` in schema 2.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElectionConfigV2 {
+    pub revoting: bool,
+    pub max_end_time: u64,
+    pub eligibility: EligibilityMode,
+}
+
+impl From<ElectionParamsV2> for ElectionParams {
+    fn from(v2: ElectionParamsV2) -> Self {
+        ElectionParams {
+            eid: v2.eid,
+            organizer: v2.organizer,
+            title: v2.title,
+            options_count: v2.options_count,
+            start_time: v2.start_time,
+            end_time: v2.end_time,
+            enc_pubkey: v2.enc_pubkey,
+            tallied: v2.tallied,
+            status: v2.status,
+            config: ElectionConfig {
+                revoting: v2.config.revoting,
+                max_end_time: v2.config.max_end_time,
+                eligibility: v2.config.eligibility,
+                disputes: DisputePolicy::None,
+                staking: StakePolicy::None,
+            },
+        }
+    }
+}
+
+/// `ElectionParams` as stored in schema 3.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElectionParamsV3 {
+    pub eid: u64,
+    pub organizer: Address,
+    pub title: Bytes,
+    pub options_count: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub enc_pubkey: Bytes,
+    pub tallied: bool,
+    pub status: ElectionStatus,
+    pub config: ElectionConfigV3,
+}
+
+/// `ElectionConfig` in schema 3.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElectionConfigV3 {
+    pub revoting: bool,
+    pub max_end_time: u64,
+    pub eligibility: EligibilityMode,
+    pub disputes: DisputePolicy,
+}
+
+impl From<ElectionParamsV3> for ElectionParams {
+    fn from(v3: ElectionParamsV3) -> Self {
+        ElectionParams {
+            eid: v3.eid,
+            organizer: v3.organizer,
+            title: v3.title,
+            options_count: v3.options_count,
+            start_time: v3.start_time,
+            end_time: v3.end_time,
+            enc_pubkey: v3.enc_pubkey,
+            tallied: v3.tallied,
+            status: v3.status,
+            config: ElectionConfig {
+                revoting: v3.config.revoting,
+                max_end_time: v3.config.max_end_time,
+                eligibility: v3.config.eligibility,
+                disputes: v3.config.disputes,
+                staking: StakePolicy::None,
+            },
+        }
+    }
+}
+
+/// Stored form of `EncryptedBallot` under `DataKey::Ballot` (schema 2+;
+/// schema 1 stored the bare `EncryptedBallotV1`), versioned the same way as
+/// `VersionedElection`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VersionedBallot {
+    V1(EncryptedBallot),
+}
+
+/// `EncryptedBallot` as the first release stored it, bare, in schema 1:
+/// without a weight, which reads as 1.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EncryptedBallotV1 {
+    pub nf_cast: BytesN<32>,
+    pub c1: Bytes,
+    pub c2: Bytes,
 }
 
 /// A single encrypted ballot: (C1, C2) are 33-byte compressed secp256k1 points.
@@ -62,4 +553,250 @@ pub struct EncryptedBallot {
     pub nf_cast: BytesN<32>,
     pub c1: Bytes,
     pub c2: Bytes,
+    /// Casting account weight; the decrypted vote counts this many times
+    pub weight: u32,
+}
+
+/// Layout version of `ShareBatch`, written as the first byte of its blob.
+pub const SHARE_BATCH_VERSION: u32 = 1;
+
+/// A key holder's partial decryptions of the whole board, as `post_share`
+/// takes them.  Signed and stored in its `serialise_share_batch` form.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareBatch {
+    /// `SHARE_BATCH_VERSION`
+    pub version: u32,
+    /// One share per ballot, in board order
+    pub shares: Vec<DecryptionShare>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecryptionShare {
+    /// Board index of the ballot this share decrypts
+    pub ballot_index: u32,
+    /// That ballot's C1, as stored on the board
+    pub c1: Bytes,
+    /// D_j = sk_j·C1 (33-byte compressed secp256k1)
+    pub d: Bytes,
+    /// Proof that `d` was computed with the key holder's Feldman share;
+    /// verified off-chain, or on-chain by `dispute_share`
+    pub proof: ShareProof,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ShareProof {
+    None,
+    Cp(CpProof),
+}
+
+/// Chaum-Pedersen proof `log_G(PK_j) == log_C1(D_j)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CpProof {
+    /// r·G (33-byte compressed)
+    pub r1: Bytes,
+    /// r·C1 (33-byte compressed)
+    pub r2: Bytes,
+    /// Response s = r + c·sk_j, big-endian
+    pub s: BytesN<32>,
+}
+
+/// Progress of a chunked share upload (`begin_shares` … `commit_shares`).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ShareUpload {
+    /// Final `shares_chain_step` digest the key holder signed in `begin_shares`
+    pub digest: BytesN<32>,
+    /// Chain over the chunks stored so far, from 32 zero bytes
+    pub chain: BytesN<32>,
+    /// Board index the next chunk must start at
+    pub next: u32,
+    /// Number of chunks stored
+    pub chunks: u32,
+    /// Set by `commit_shares`; the batch then counts towards `ShareCount`
+    pub committed: bool,
+}
+
+/// Stored under `DataKey::Tally(eid)` by `finalize_tally`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TallyRecord {
+    /// One count per option
+    pub tally: Vec<u32>,
+    /// Number of ballots on the board at finalisation
+    pub ballot_count: u32,
+    /// Ballot-board head the tally was computed over
+    pub board_head: BytesN<32>,
+    /// Root of the ballot Merkle tree at finalisation
+    pub ballot_root: BytesN<32>,
+    /// Number of KH share batches posted at finalisation
+    pub share_count: u32,
+    /// Ledger timestamp of the `finalize_tally` call
+    pub tallied_at: u64,
+}
+
+/// Stored under `DataKey::Archive(eid)` by `archive_election`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedElection {
+    pub params: ElectionParams,
+    pub eligibility_root: BytesN<32>,
+    pub revocation_root: Option<BytesN<32>>,
+    pub dist: Committee,
+    pub kh: Committee,
+    /// VSS commitment A_j0 per KH, in roster order (empty if never set)
+    pub kh_commitments: Vec<Bytes>,
+    pub schedule_history: Vec<ScheduleChange>,
+    /// Number of casting accounts issued
+    pub issued_count: u32,
+    /// Number of ballots on the final board
+    pub ballot_count: u32,
+    /// Final head of the ballot-board hash chain (see `board_step`)
+    pub board_hash: BytesN<32>,
+    /// Final root of the ballot Merkle tree
+    pub ballot_root: BytesN<32>,
+    /// `(kh_idx, shares_msg digest)` for every KH that posted, i.e. the digest
+    /// the KH signed
+    pub share_hashes: Vec<(u32, BytesN<32>)>,
+    pub tally: Vec<u32>,
+    /// Ledger timestamp of the `archive_election` call
+    pub archived_at: u64,
+}
+
+/// `ArchivedElection` as stored in schema 2.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedElectionV1 {
+    pub params: ElectionParamsV2,
+    pub eligibility_root: BytesN<32>,
+    pub revocation_root: Option<BytesN<32>>,
+    pub dist: Committee,
+    pub kh: Committee,
+    pub kh_commitments: Vec<Bytes>,
+    pub schedule_history: Vec<ScheduleChange>,
+    pub issued_count: u32,
+    pub ballot_count: u32,
+    pub board_hash: BytesN<32>,
+    pub ballot_root: BytesN<32>,
+    pub share_hashes: Vec<(u32, BytesN<32>)>,
+    pub tally: Vec<u32>,
+    pub archived_at: u64,
+}
+
+impl From<ArchivedElectionV1> for ArchivedElection {
+    fn from(v1: ArchivedElectionV1) -> Self {
+        ArchivedElection {
+            params: v1.params.into(),
+            eligibility_root: v1.eligibility_root,
+            revocation_root: v1.revocation_root,
+            dist: v1.dist,
+            kh: v1.kh,
+            kh_commitments: v1.kh_commitments,
+            schedule_history: v1.schedule_history,
+            issued_count: v1.issued_count,
+            ballot_count: v1.ballot_count,
+            board_hash: v1.board_hash,
+            ballot_root: v1.ballot_root,
+            share_hashes: v1.share_hashes,
+            tally: v1.tally,
+            archived_at: v1.archived_at,
+        }
+    }
+}
+
+/// `ArchivedElection` as stored in schema 3.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedElectionV2 {
+    pub params: ElectionParamsV3,
+    pub eligibility_root: BytesN<32>,
+    pub revocation_root: Option<BytesN<32>>,
+    pub dist: Committee,
+    pub kh: Committee,
+    pub kh_commitments: Vec<Bytes>,
+    pub schedule_history: Vec<ScheduleChange>,
+    pub issued_count: u32,
+    pub ballot_count: u32,
+    pub board_hash: BytesN<32>,
+    pub ballot_root: BytesN<32>,
+    pub share_hashes: Vec<(u32, BytesN<32>)>,
+    pub tally: Vec<u32>,
+    pub archived_at: u64,
+}
+
+impl From<ArchivedElectionV2> for ArchivedElection {
+    fn from(v2: ArchivedElectionV2) -> Self {
+        ArchivedElection {
+            params: v2.params.into(),
+            eligibility_root: v2.eligibility_root,
+            revocation_root: v2.revocation_root,
+            dist: v2.dist,
+            kh: v2.kh,
+            kh_commitments: v2.kh_commitments,
+            schedule_history: v2.schedule_history,
+            issued_count: v2.issued_count,
+            ballot_count: v2.ballot_count,
+            board_hash: v2.board_hash,
+            ballot_root: v2.ballot_root,
+            share_hashes: v2.share_hashes,
+            tally: v2.tally,
+            archived_at: v2.archived_at,
+        }
+    }
+}
+
+// ── Paginated views ───────────────────────────────────────────────────────────
+
+/// One page of `list_casting_accounts`, in issuance order.  Each account
+/// carries its `nf_issue`, so this also enumerates the issue nullifiers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccountPage {
+    pub accounts: Vec<(BytesN<32>, CastingAccount)>,
+    /// Cursor for the following page; `None` once the index is exhausted
+    pub next: Option<u32>,
+}
+
+/// One page of `list_cast_nullifiers`, in casting order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NullifierPage {
+    pub nullifiers: Vec<BytesN<32>>,
+    pub next: Option<u32>,
+}
+
+/// Progress of a paged `bump_election`, `archive_election` or
+/// `delete_election`: pass `next` back as the cursor until it is `None`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WalkPage {
+    pub next: Option<u32>,
+}
+
+/// One page of `list_elections`.  Archived and deleted eids are skipped.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElectionPage {
+    pub elections: Vec<ElectionParams>,
+    pub next: Option<u64>,
+}
+
+/// Returned by `get_summary`: the election's phase and the entry counts of
+/// each protocol stage, so a reader can size its paginated fetches.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElectionSummary {
+    pub phase: ElectionPhase,
+    /// Stage 1: casting accounts issued (= issue nullifiers consumed)
+    pub issued_count: u32,
+    /// Stage 2: ballots on the board (one per voting account)
+    pub ballot_count: u32,
+    /// Stage 2: cast nullifiers consumed, revotes included
+    pub cast_count: u32,
+    /// Stage 3: KH share batches posted, against `kh_threshold` needed
+    pub share_count: u32,
+    pub kh_threshold: u32,
 }
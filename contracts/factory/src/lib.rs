@@ -0,0 +1,164 @@
+#![no_std]
+
+mod types;
+
+#[cfg(test)]
+mod test;
+
+use soroban_sdk::{
+    contract, contractimpl, contractmeta, symbol_short, Address, Bytes, BytesN, Env, IntoVal,
+    Symbol, Val, Vec,
+};
+use types::{Committee, DataKey, ElectionConfig, InstanceInfo};
+
+contractmeta!(
+    key = "Description",
+    val = "Stellot dagger - per-election contract factory (PoC)"
+);
+
+/// Write a directory entry and keep it alive for as long as the network allows.
+fn store<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, val: &V) {
+    let ttl = env.storage().max_ttl();
+    env.storage().persistent().set(key, val);
+    env.storage().persistent().extend_ttl(key, ttl, ttl);
+}
+
+fn bump_instance(env: &Env) {
+    let ttl = env.storage().max_ttl();
+    env.storage().instance().extend_ttl(ttl, ttl);
+}
+
+// ── Contract ──────────────────────────────────────────────────────────────────
+
+#[contract]
+pub struct FactoryContract;
+
+#[contractimpl]
+impl FactoryContract {
+    /// * admin         — may replace the election WASM with `set_election_wasm`
+    /// * election_wasm — hash of the uploaded election contract WASM
+    pub fn __constructor(env: Env, admin: Address, election_wasm: BytesN<32>) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::ElectionWasm, &election_wasm);
+        env.storage().instance().set(&DataKey::InstanceCount, &0u64);
+        bump_instance(&env);
+    }
+
+    /// Deploy a fresh election contract instance and create its election.
+    ///
+    /// The instance is deployed from the current election WASM as a
+    /// single-election instance with the organizer as its admin (the only
+    /// account that can upgrade it or deploy its election), then its `deploy`
+    /// is invoked with these arguments, which mirror it parameter for
+    /// parameter.  The organizer must authorize both calls.
+    ///
+    /// Returns the instance address, which is also recorded in the
+    /// organizer's directory and emitted as a `created` event.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_election(
+        env: Env,
+        organizer: Address,
+        title: Bytes,
+        options_count: u32,
+        start_time: u64,
+        end_time: u64,
+        enc_pubkey: Bytes,
+        eligibility_root: BytesN<32>,
+        dist: Committee,
+        kh: Committee,
+        config: ElectionConfig,
+    ) -> Address {
+        organizer.require_auth();
+
+        let wasm: BytesN<32> = env.storage().instance().get(&DataKey::ElectionWasm).unwrap();
+        let index: u64 = env.storage().instance().get(&DataKey::InstanceCount).unwrap();
+
+        let mut salt = [0u8; 32];
+        salt[24..].copy_from_slice(&index.to_be_bytes());
+        let address = env
+            .deployer()
+            .with_current_contract(BytesN::from_array(&env, &salt))
+            .deploy_v2(wasm.clone(), (organizer.clone(), true));
+
+        let args: Vec<Val> = (
+            organizer.clone(),
+            title,
+            options_count,
+            start_time,
+            end_time,
+            enc_pubkey,
+            eligibility_root,
+            dist,
+            kh,
+            config,
+        )
+            .into_val(&env);
+        let _: u64 = env.invoke_contract(&address, &Symbol::new(&env, "deploy"), args);
+
+        store(
+            &env,
+            &DataKey::Instance(index),
+            &InstanceInfo {
+                address: address.clone(),
+                organizer: organizer.clone(),
+                wasm_hash: wasm,
+                created_at: env.ledger().timestamp(),
+            },
+        );
+        let count: u32 = env
+            .storage().persistent().get(&DataKey::OrganizerCount(organizer.clone())).unwrap_or(0);
+        store(&env, &DataKey::OrganizerInstance(organizer.clone(), count), &index);
+        store(&env, &DataKey::OrganizerCount(organizer.clone()), &(count + 1));
+        env.storage().instance().set(&DataKey::InstanceCount, &(index + 1));
+        bump_instance(&env);
+
+        env.events()
+            .publish((symbol_short!("created"), organizer), (address.clone(), index));
+
+        address
+    }
+
+    /// Deploy future instances from `election_wasm`.  Existing instances keep
+    /// their code.  Admin only.
+    pub fn set_election_wasm(env: Env, election_wasm: BytesN<32>) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::ElectionWasm, &election_wasm);
+        bump_instance(&env);
+
+        env.events().publish((symbol_short!("wasm"),), election_wasm);
+    }
+
+    // ── Views ─────────────────────────────────────────────────────────────────
+
+    pub fn get_admin(env: Env) -> Address {
+        env.storage().instance().get(&DataKey::Admin).unwrap()
+    }
+
+    pub fn get_election_wasm(env: Env) -> BytesN<32> {
+        env.storage().instance().get(&DataKey::ElectionWasm).unwrap()
+    }
+
+    pub fn get_instance_count(env: Env) -> u64 {
+        env.storage().instance().get(&DataKey::InstanceCount).unwrap_or(0)
+    }
+
+    pub fn get_instance(env: Env, index: u64) -> Option<InstanceInfo> {
+        env.storage().persistent().get(&DataKey::Instance(index))
+    }
+
+    /// Instances created by `organizer`, oldest first.
+    pub fn get_organizer_instances(env: Env, organizer: Address) -> Vec<InstanceInfo> {
+        let storage = env.storage().persistent();
+        let count: u32 = storage.get(&DataKey::OrganizerCount(organizer.clone())).unwrap_or(0);
+        let mut out = Vec::new(&env);
+        for i in 0..count {
+            let index: u64 = storage
+                .get(&DataKey::OrganizerInstance(organizer.clone(), i))
+                .unwrap();
+            out.push_back(storage.get(&DataKey::Instance(index)).unwrap());
+        }
+        out
+    }
+}
@@ -0,0 +1,190 @@
+#![cfg(test)]
+
+extern crate std;
+
+use election::error::ContractError;
+use election::{types as election_types, ElectionContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation},
+    Address, Bytes, BytesN, Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+use crate::types::{
+    Committee, DisputePolicy, DisputeWindow, ElectionConfig, EligibilityMode, KhBond, StakePolicy,
+    TokenGate,
+};
+use crate::{FactoryContract, FactoryContractClient};
+
+fn committee(env: &Env, seed: u8) -> Committee {
+    Committee { members: Vec::from_array(env, [BytesN::from_array(env, &[seed; 32])]), threshold: 1 }
+}
+
+// ── Test 1: Constructor state and empty directory ─────────────────────────────
+
+#[test]
+fn test_constructor() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let wasm = BytesN::from_array(&env, &[7u8; 32]);
+    let factory_id = env.register(FactoryContract, (admin.clone(), wasm.clone()));
+    let client = FactoryContractClient::new(&env, &factory_id);
+
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_election_wasm(), wasm);
+    assert_eq!(client.get_instance_count(), 0);
+    assert!(client.get_instance(&0).is_none());
+    assert_eq!(client.get_organizer_instances(&Address::generate(&env)).len(), 0);
+}
+
+// ── Test 2: Only the admin replaces the election WASM ────────────────────────
+
+#[test]
+fn test_set_election_wasm() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let factory_id = env.register(
+        FactoryContract,
+        (admin.clone(), BytesN::from_array(&env, &[7u8; 32])),
+    );
+    let client = FactoryContractClient::new(&env, &factory_id);
+
+    // Without authorization the call is rejected
+    let next = BytesN::from_array(&env, &[8u8; 32]);
+    assert!(client.try_set_election_wasm(&next).is_err());
+
+    env.mock_all_auths();
+    client.set_election_wasm(&next);
+    assert_eq!(
+        env.auths(),
+        std::vec![(
+            admin,
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    factory_id.clone(),
+                    Symbol::new(&env, "set_election_wasm"),
+                    (next.clone(),).into_val(&env),
+                )),
+                sub_invocations: std::vec![],
+            },
+        )]
+    );
+    assert_eq!(client.get_election_wasm(), next);
+}
+
+// ── Test 3: The mirrored deploy types encode as the election contract's ─────
+
+#[test]
+fn test_deploy_types_match_election() {
+    let env = Env::default();
+    let token = Address::generate(&env);
+    let configs = [
+        ElectionConfig {
+            revoting: false,
+            max_end_time: 1200,
+            eligibility: EligibilityMode::Static,
+            disputes: DisputePolicy::None,
+            staking: StakePolicy::None,
+        },
+        ElectionConfig {
+            revoting: true,
+            max_end_time: 1500,
+            eligibility: EligibilityMode::Token(TokenGate { token: token.clone(), min_balance: 10, weighted: true }),
+            disputes: DisputePolicy::Window(DisputeWindow { secs: 60, vss_digest: BytesN::from_array(&env, &[9; 32]) }),
+            staking: StakePolicy::Bond(KhBond {
+                token: token.clone(),
+                amount: 100,
+                recipient: Address::generate(&env),
+                tally_deadline: 2000,
+            }),
+        },
+        ElectionConfig {
+            revoting: false,
+            max_end_time: 1200,
+            eligibility: EligibilityMode::Registry,
+            disputes: DisputePolicy::None,
+            staking: StakePolicy::None,
+        },
+    ];
+    for config in configs {
+        let val: Val = config.into_val(&env);
+        let theirs = election_types::ElectionConfig::try_from_val(&env, &val).unwrap();
+        let back: Val = theirs.into_val(&env);
+        assert_eq!(ElectionConfig::try_from_val(&env, &back).unwrap(), config);
+    }
+
+    let val: Val = committee(&env, 1).into_val(&env);
+    let theirs = election_types::Committee::try_from_val(&env, &val).unwrap();
+    assert_eq!(theirs.members.get(0).unwrap().to_array(), [1; 32]);
+    assert_eq!(theirs.threshold, 1);
+}
+
+// ── Test 4: create_election deploys a single-election instance (real WASM) ──
+
+/// `create_election` deploys real WASM, built here by `election_wasm`.
+#[test]
+fn test_create_election() {
+    let Some(wasm) = election::testutils::election_wasm() else {
+        std::eprintln!("skipped: election.wasm could not be built (wasm32v1-none target missing?)");
+        return;
+    };
+    let env = Env::default();
+    env.mock_all_auths();
+    let wasm_hash = env.deployer().upload_contract_wasm(Bytes::from_slice(&env, &wasm));
+    let admin = Address::generate(&env);
+    let client = FactoryContractClient::new(&env, &env.register(FactoryContract, (admin, wasm_hash.clone())));
+
+    let organizer = Address::generate(&env);
+    let config = ElectionConfig {
+        revoting: false,
+        max_end_time: 1200,
+        eligibility: EligibilityMode::Static,
+        disputes: DisputePolicy::None,
+        staking: StakePolicy::None,
+    };
+    let create = || {
+        client.create_election(
+            &organizer,
+            &Bytes::from_slice(&env, b"Factory election"),
+            &2u32,
+            &100u64,
+            &1200u64,
+            &Bytes::from_array(&env, &[2u8; 33]),
+            &BytesN::from_array(&env, &[4u8; 32]),
+            &committee(&env, 3),
+            &committee(&env, 1),
+            &config,
+        )
+    };
+    let first = create();
+    let second = create();
+    assert_ne!(first, second);
+
+    // Each instance holds one election, administered by the organizer
+    let instance = ElectionContractClient::new(&env, &first);
+    let params = instance.get_election(&0).unwrap();
+    assert_eq!(params.organizer, organizer);
+    assert_eq!(params.title, Bytes::from_slice(&env, b"Factory election"));
+    assert_eq!(instance.get_admin(), organizer);
+    assert!(instance.get_election(&1).is_none());
+    let again = instance.try_deploy(
+        &organizer,
+        &params.title,
+        &2u32,
+        &100u64,
+        &1200u64,
+        &params.enc_pubkey,
+        &BytesN::from_array(&env, &[4u8; 32]),
+        &election_types::Committee { members: Vec::from_array(&env, [BytesN::from_array(&env, &[3; 32])]), threshold: 1 },
+        &election_types::Committee { members: Vec::from_array(&env, [BytesN::from_array(&env, &[1; 32])]), threshold: 1 },
+        &params.config,
+    );
+    assert_eq!(again, Err(Ok(ContractError::AlreadyExists)));
+
+    // The directory lists both instances in creation order
+    assert_eq!(client.get_instance_count(), 2);
+    let listed = client.get_organizer_instances(&organizer);
+    assert_eq!(listed.len(), 2);
+    assert_eq!(listed.get(0).unwrap().address, first);
+    assert_eq!(listed.get(1).unwrap().address, second);
+    assert_eq!(listed.get(0).unwrap().wasm_hash, wasm_hash);
+}
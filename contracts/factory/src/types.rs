@@ -0,0 +1,102 @@
+use soroban_sdk::{contracttype, Address, BytesN, Vec};
+
+// ── Storage keys ──────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// Address allowed to change the election WASM (instance storage)
+    Admin,
+    /// WASM hash new election instances are deployed from (instance storage)
+    ElectionWasm,
+    /// Number of instances deployed so far (instance storage)
+    InstanceCount,
+    /// Instance address by creation index
+    Instance(u64),
+    /// Directory: number of instances created by an organizer
+    OrganizerCount(Address),
+    /// Directory: creation index of the organizer's i-th instance
+    OrganizerInstance(Address, u32),
+}
+
+/// One deployed election instance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstanceInfo {
+    pub address: Address,
+    pub organizer: Address,
+    /// WASM hash the instance was deployed from
+    pub wasm_hash: BytesN<32>,
+    /// Ledger timestamp of `create_election`
+    pub created_at: u64,
+}
+
+// ── Election `deploy` parameters ─────────────────────────────────────────────
+//
+// Mirrors of the election contract's types, which encode to the same XDR.  The
+// factory cannot link the election crate: both export contract functions.
+
+/// An Ed25519 signing committee (distributors or key-holders).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Committee {
+    pub members: Vec<BytesN<32>>,
+    pub threshold: u32,
+}
+
+/// Per-election behaviour switches chosen at deploy time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ElectionConfig {
+    pub revoting: bool,
+    pub max_end_time: u64,
+    pub eligibility: EligibilityMode,
+    pub disputes: DisputePolicy,
+    pub staking: StakePolicy,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EligibilityMode {
+    Static,
+    Registry,
+    Token(TokenGate),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenGate {
+    pub token: Address,
+    pub min_balance: i128,
+    pub weighted: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputePolicy {
+    None,
+    Window(DisputeWindow),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeWindow {
+    pub secs: u64,
+    pub vss_digest: BytesN<32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StakePolicy {
+    None,
+    Bond(KhBond),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KhBond {
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+    pub tally_deadline: u64,
+}
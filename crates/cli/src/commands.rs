@@ -0,0 +1,279 @@
+//! Subcommand logic, free of argument parsing and file I/O so the tests can
+//! drive it against the contract directly.
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::rngs::OsRng;
+use stellot_client::dlog::BsgsTable;
+use stellot_client::elgamal::{self, encode_scalar};
+use stellot_client::merkle::EligibilityTree;
+use stellot_client::{cp, dkg as vss, msg};
+
+use crate::files::{
+    from_hex, from_hex_array, BallotEntry, ChunkPlan, DistSigFile, DkgSummary, KhFile,
+    MerkleFile, MerkleLeaf, ProofEntry, ShareEntry, ShareFile, UploadPlan,
+};
+use crate::Error;
+
+// ── dkg ───────────────────────────────────────────────────────────────────────
+
+/// Run an `m`-party, threshold-`t` DKG and give every key holder a fresh
+/// Ed25519 identity.
+pub fn dkg(m: u32, t: u32) -> Result<(Vec<KhFile>, DkgSummary), Error> {
+    let out = vss::run(m, t)?;
+    let mut kh_files = Vec::with_capacity(m as usize);
+    for (index, sk) in &out.shares {
+        let ed = SigningKey::generate(&mut OsRng);
+        kh_files.push(KhFile {
+            index: *index,
+            sk: hex::encode(encode_scalar(sk)),
+            commitment: hex::encode(out.commitments[*index as usize - 1][0]),
+            ed_sk: hex::encode(ed.to_bytes()),
+            ed_pk: hex::encode(ed.verifying_key().to_bytes()),
+        });
+    }
+    let summary = DkgSummary {
+        m,
+        t,
+        combined_pubkey: hex::encode(out.combined_pubkey),
+        kh_ed_pks: kh_files.iter().map(|kh| kh.ed_pk.clone()).collect(),
+        commitments: out
+            .commitments
+            .iter()
+            .map(|row| row.iter().map(hex::encode).collect())
+            .collect(),
+    };
+    Ok((kh_files, summary))
+}
+
+// ── distributor sign ──────────────────────────────────────────────────────────
+
+/// Sign `issue_msg(eid, pk_cast, nf_issue, voter)` with a distributor key,
+/// where `voter` is the `voter_leaf` of the eligibility leaf, if any.
+pub fn distributor_sign(
+    eid: u64,
+    pk_cast: &str,
+    nf_issue: &str,
+    leaf: Option<&str>,
+    dist_sk: &str,
+) -> Result<DistSigFile, Error> {
+    let key = SigningKey::from_bytes(&from_hex_array("dist-sk", dist_sk)?);
+    let pk_cast: [u8; 32] = from_hex_array("cast-pk", pk_cast)?;
+    let nf_issue: [u8; 32] = from_hex_array("nf-issue", nf_issue)?;
+    let leaf = leaf.map(|l| from_hex("leaf", l)).transpose()?;
+    let sig = key.sign(&msg::issue_msg(eid, &pk_cast, &nf_issue, &voter(leaf.as_deref())));
+    Ok(DistSigFile {
+        eid: eid.to_string(),
+        pk_cast: hex::encode(pk_cast),
+        nf_issue: hex::encode(nf_issue),
+        leaf: leaf.map(hex::encode),
+        dist_pk: hex::encode(key.verifying_key().to_bytes()),
+        dist_sig: hex::encode(sig.to_bytes()),
+    })
+}
+
+/// Check a collected signature file before it goes into `issue_account`.
+pub fn verify_dist_sig(file: &DistSigFile) -> Result<(), Error> {
+    let eid: u64 = file.eid.parse().map_err(|_| Error::Mismatch(format!("bad eid {}", file.eid)))?;
+    let leaf = file.leaf.as_deref().map(|l| from_hex("leaf", l)).transpose()?;
+    let msg = msg::issue_msg(
+        eid,
+        &from_hex_array("pk_cast", &file.pk_cast)?,
+        &from_hex_array("nf_issue", &file.nf_issue)?,
+        &voter(leaf.as_deref()),
+    );
+    verify(&file.dist_pk, &msg, &file.dist_sig, "distributor")
+}
+
+fn voter(leaf: Option<&[u8]>) -> [u8; 32] {
+    leaf.map_or(msg::NO_VOTER, msg::voter_leaf)
+}
+
+// ── kh decrypt ────────────────────────────────────────────────────────────────
+
+/// Partially decrypt every ballot with a key holder's share, prove each
+/// decryption, and sign the batch for `post_share`.
+pub fn kh_decrypt(kh: &KhFile, eid: u64, ballots: &[BallotEntry]) -> Result<ShareFile, Error> {
+    let sk = elgamal::decode_scalar(&from_hex_array("sk", &kh.sk)?)?;
+    let ed = SigningKey::from_bytes(&from_hex_array("ed_sk", &kh.ed_sk)?);
+
+    let mut shares = Vec::with_capacity(ballots.len());
+    for (i, ballot) in ballots.iter().enumerate() {
+        let c1 = from_hex_array("c1", &ballot.c1)?;
+        let (d, proof) = cp::prove(&c1, &sk)?;
+        shares.push(msg::DecryptionShare { ballot_index: i as u32, c1, d, proof: Some(proof) });
+    }
+    let sig = ed.sign(&msg::shares_msg(eid, &msg::serialise_share_batch(&shares)));
+
+    Ok(ShareFile {
+        eid,
+        kh_index: kh.index,
+        kh_pk: hex::encode(ed.verifying_key().to_bytes()),
+        shares: shares
+            .iter()
+            .map(|share| ShareEntry {
+                ballot_index: share.ballot_index,
+                c1: hex::encode(share.c1),
+                d: hex::encode(share.d),
+                proof: share.proof.as_ref().map(|p| ProofEntry {
+                    r1: hex::encode(p.r1),
+                    r2: hex::encode(p.r2),
+                    s: hex::encode(p.s),
+                }),
+            })
+            .collect(),
+        sig: hex::encode(sig.to_bytes()),
+        upload: None,
+    })
+}
+
+/// Split a share file into chunks of at most `chunk_size` shares and sign
+/// them for `begin_shares` and `post_share_chunk`.
+pub fn plan_upload(kh: &KhFile, file: &ShareFile, chunk_size: usize) -> Result<UploadPlan, Error> {
+    let ed = SigningKey::from_bytes(&from_hex_array("ed_sk", &kh.ed_sk)?);
+    let chunks = msg::share_chunks(&decode_shares(file)?, chunk_size);
+    let digest = msg::upload_digest(file.eid, &chunks);
+    Ok(UploadPlan {
+        digest: hex::encode(digest),
+        sig: hex::encode(ed.sign(&msg::shares_begin_msg(file.eid, &digest)).to_bytes()),
+        chunks: chunks
+            .iter()
+            .map(|(start, blob)| ChunkPlan {
+                start: *start,
+                count: u32::from_le_bytes(blob[1..5].try_into().unwrap()),
+                sig: hex::encode(ed.sign(&chunk_msg(file.eid, &digest, *start, blob)).to_bytes()),
+            })
+            .collect(),
+    })
+}
+
+/// Re-check a share file's signatures, as `post_share` (and, for a chunked
+/// upload, `begin_shares`, `post_share_chunk` and `commit_shares`) will.
+pub fn verify_share_file(file: &ShareFile) -> Result<(), Error> {
+    let shares = decode_shares(file)?;
+    let blob = msg::serialise_share_batch(&shares);
+    verify(&file.kh_pk, &msg::shares_msg(file.eid, &blob), &file.sig, "key holder")?;
+    let Some(upload) = &file.upload else {
+        return Ok(());
+    };
+    let mut chunks = Vec::with_capacity(upload.chunks.len());
+    let mut next = 0;
+    for chunk in &upload.chunks {
+        let end = chunk.start as usize + chunk.count as usize;
+        if chunk.start != next || chunk.count == 0 || end > shares.len() {
+            return Err(Error::Mismatch(format!("upload chunk at {} does not follow {next}", chunk.start)));
+        }
+        chunks.push((chunk.start, msg::serialise_share_batch(&shares[chunk.start as usize..end])));
+        next = end as u32;
+    }
+    if next as usize != shares.len() {
+        return Err(Error::Mismatch(format!("upload covers {next} of {} shares", shares.len())));
+    }
+    let digest = msg::upload_digest(file.eid, &chunks);
+    if hex::encode(digest) != upload.digest {
+        return Err(Error::Mismatch("upload digest does not match its chunks".into()));
+    }
+    for ((start, blob), chunk) in chunks.iter().zip(&upload.chunks) {
+        verify(&file.kh_pk, &chunk_msg(file.eid, &digest, *start, blob), &chunk.sig, "key holder")?;
+    }
+    verify(&file.kh_pk, &msg::shares_begin_msg(file.eid, &digest), &upload.sig, "key holder")
+}
+
+/// `shares_chunk_msg` for a chunk of the upload with `digest`.
+fn chunk_msg(eid: u64, digest: &[u8; 32], start: u32, blob: &[u8]) -> [u8; 32] {
+    msg::shares_chunk_msg(eid, digest, &msg::shares_chunk_hash(eid, start, blob))
+}
+
+fn decode_shares(file: &ShareFile) -> Result<Vec<msg::DecryptionShare>, Error> {
+    let mut shares = Vec::with_capacity(file.shares.len());
+    for share in &file.shares {
+        let proof = match &share.proof {
+            Some(p) => Some(cp::CpProof {
+                r1: from_hex_array("r1", &p.r1)?,
+                r2: from_hex_array("r2", &p.r2)?,
+                s: from_hex_array("s", &p.s)?,
+            }),
+            None => None,
+        };
+        shares.push(msg::DecryptionShare {
+            ballot_index: share.ballot_index,
+            c1: from_hex_array("c1", &share.c1)?,
+            d: from_hex_array("d", &share.d)?,
+            proof,
+        });
+    }
+    Ok(shares)
+}
+
+// ── tally combine ─────────────────────────────────────────────────────────────
+
+/// Lagrange-combine at least `t` share files and decrypt every ballot,
+/// adding each ballot's weight to its option.  The votes are recovered in one
+/// pass over `table`'s giant steps.
+pub fn tally_combine(
+    ballots: &[BallotEntry],
+    share_files: &[ShareFile],
+    options_count: u32,
+    table: &BsgsTable,
+) -> Result<Vec<u32>, Error> {
+    for file in share_files {
+        verify_share_file(file)?;
+        if file.shares.len() != ballots.len() {
+            return Err(Error::Mismatch(format!(
+                "key holder {} has {} shares for {} ballots",
+                file.kh_index,
+                file.shares.len(),
+                ballots.len()
+            )));
+        }
+    }
+
+    let mut points = Vec::with_capacity(ballots.len());
+    for (i, ballot) in ballots.iter().enumerate() {
+        let mut partials = Vec::with_capacity(share_files.len());
+        for file in share_files {
+            let share = &file.shares[i];
+            if share.ballot_index != i as u32 || !share.c1.eq_ignore_ascii_case(&ballot.c1) {
+                return Err(Error::Mismatch(format!(
+                    "key holder {} decrypted a different c1 for ballot {i}",
+                    file.kh_index
+                )));
+            }
+            partials.push((file.kh_index, from_hex_array::<33>("d", &share.d)?));
+        }
+        let d = elgamal::combine_partials(&partials)?;
+        points.push(elgamal::unblind(&from_hex("c2", &ballot.c2)?, &d)?);
+    }
+
+    // Each ballot decrypts to `(v+1)·G`
+    let mut tally = vec![0u32; options_count as usize];
+    for (x, ballot) in table.solve_all(&points, options_count as u64)?.into_iter().zip(ballots) {
+        let v = x.checked_sub(1).ok_or(stellot_client::Error::DiscreteLogNotFound)?;
+        tally[v as usize] += ballot.weight;
+    }
+    Ok(tally)
+}
+
+// ── merkle build ──────────────────────────────────────────────────────────────
+
+/// Eligibility tree over hex-encoded leaves (typically voter public keys).
+pub fn merkle_build(leaves: &[String]) -> Result<MerkleFile, Error> {
+    let raw: Vec<Vec<u8>> = leaves.iter().map(|l| from_hex("leaf", l)).collect::<Result<_, _>>()?;
+    let tree = EligibilityTree::build(&raw)?;
+    let mut out = Vec::with_capacity(raw.len());
+    for (i, leaf) in raw.iter().enumerate() {
+        let proof = tree
+            .prove(i)?
+            .into_iter()
+            .map(|(sibling, is_right)| (hex::encode(sibling), is_right))
+            .collect();
+        out.push(MerkleLeaf { leaf: hex::encode(leaf), proof });
+    }
+    Ok(MerkleFile { root: hex::encode(tree.root()), leaves: out })
+}
+
+fn verify(pk: &str, msg: &[u8; 32], sig: &str, what: &'static str) -> Result<(), Error> {
+    let pk = VerifyingKey::from_bytes(&from_hex_array("public key", pk)?)
+        .map_err(|_| Error::Hex("public key"))?;
+    let sig = Signature::from_bytes(&from_hex_array("signature", sig)?);
+    pk.verify(msg, &sig).map_err(|_| Error::BadSignature(what))
+}
@@ -0,0 +1,47 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    Client(stellot_client::Error),
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    /// A hex field failed to decode or has the wrong length
+    Hex(&'static str),
+    /// An Ed25519 signature failed to verify
+    BadSignature(&'static str),
+    /// Inputs that are individually valid but inconsistent with each other
+    Mismatch(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Client(e) => write!(f, "{e}"),
+            Error::Io(e) => write!(f, "{e}"),
+            Error::Json(e) => write!(f, "invalid JSON: {e}"),
+            Error::Hex(field) => write!(f, "`{field}` is not valid hex of the expected length"),
+            Error::BadSignature(what) => write!(f, "{what} signature does not verify"),
+            Error::Mismatch(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<stellot_client::Error> for Error {
+    fn from(e: stellot_client::Error) -> Self {
+        Error::Client(e)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
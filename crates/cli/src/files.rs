@@ -0,0 +1,139 @@
+//! On-disk formats, field-for-field compatible with the TS scripts.  All
+//! byte strings are lower-case hex.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+
+/// `kh{i}.json`, written by `dkg`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct KhFile {
+    /// 1-based Shamir index; the on-chain roster slot is `index - 1`
+    pub index: u32,
+    /// secp256k1 share sk_i, big-endian
+    pub sk: String,
+    /// 33-byte compressed A_i0
+    pub commitment: String,
+    /// Ed25519 seed used to sign `post_share`
+    pub ed_sk: String,
+    pub ed_pk: String,
+}
+
+/// `combined_pubkey.json`, written by `dkg`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct DkgSummary {
+    pub m: u32,
+    pub t: u32,
+    pub combined_pubkey: String,
+    pub kh_ed_pks: Vec<String>,
+    /// `commitments[j][k] = A_jk`
+    pub commitments: Vec<Vec<String>>,
+}
+
+/// `dist_sig_<pk_cast[..8]>.json`, written by `distributor sign`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct DistSigFile {
+    /// Decimal, as the TS script writes a bigint
+    pub eid: String,
+    pub pk_cast: String,
+    pub nf_issue: String,
+    /// Eligibility leaf the signature binds; absent when issuing without a proof
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leaf: Option<String>,
+    pub dist_pk: String,
+    pub dist_sig: String,
+}
+
+/// One entry of `ballots.json`, in board order.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct BallotEntry {
+    pub c1: String,
+    pub c2: String,
+    /// Tally weight; absent in files written by `e2e.sh`
+    #[serde(default = "one")]
+    pub weight: u32,
+}
+
+fn one() -> u32 {
+    1
+}
+
+/// `shares_kh{i}.json`, written by `kh decrypt`: a signed, ready-to-post
+/// share batch.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ShareFile {
+    pub eid: u64,
+    /// 1-based Shamir index of the key holder
+    pub kh_index: u32,
+    pub kh_pk: String,
+    /// One share per ballot, in board order
+    pub shares: Vec<ShareEntry>,
+    /// Ed25519 signature over `shares_msg(eid, serialise_share_batch(shares))`
+    pub sig: String,
+    /// Signed chunked upload of the same shares, from `kh decrypt --chunk-size`
+    #[serde(default)]
+    pub upload: Option<UploadPlan>,
+}
+
+/// `begin_shares` / `post_share_chunk` / `commit_shares` signatures for a
+/// share batch too large to post in one transaction.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct UploadPlan {
+    /// Chain digest over the chunks (`msg::upload_digest`)
+    pub digest: String,
+    /// Ed25519 signature over `shares_begin_msg(eid, digest)`
+    pub sig: String,
+    pub chunks: Vec<ChunkPlan>,
+}
+
+/// Shares `start .. start + count` of the file, posted as one chunk.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ChunkPlan {
+    pub start: u32,
+    pub count: u32,
+    /// Ed25519 signature over `shares_chunk_msg(eid, digest, chunk_hash)`
+    pub sig: String,
+}
+
+/// `DecryptionShare` in hex.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ShareEntry {
+    pub ballot_index: u32,
+    pub c1: String,
+    /// D_i = sk_i·C1
+    pub d: String,
+    /// Chaum-Pedersen proof for `d`
+    #[serde(default)]
+    pub proof: Option<ProofEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ProofEntry {
+    pub r1: String,
+    pub r2: String,
+    pub s: String,
+}
+
+/// Output of `merkle build`: the eligibility root plus a proof per leaf in
+/// the `(sibling, is_right_sibling)` form `issue_account` takes.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct MerkleFile {
+    pub root: String,
+    pub leaves: Vec<MerkleLeaf>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct MerkleLeaf {
+    pub leaf: String,
+    pub proof: Vec<(String, bool)>,
+}
+
+// ── Hex helpers ───────────────────────────────────────────────────────────────
+
+pub fn from_hex(field: &'static str, s: &str) -> Result<Vec<u8>, Error> {
+    hex::decode(s.trim()).map_err(|_| Error::Hex(field))
+}
+
+pub fn from_hex_array<const N: usize>(field: &'static str, s: &str) -> Result<[u8; N], Error> {
+    from_hex(field, s)?.try_into().map_err(|_| Error::Hex(field))
+}
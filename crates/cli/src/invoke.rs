@@ -0,0 +1,159 @@
+//! Contract invocations rendered as `stellar contract invoke` arguments,
+//! using the CLI's JSON encoding for vectors, tuples and enums.
+
+use serde_json::{json, Value};
+use stellot_client::msg::SHARE_BATCH_VERSION;
+
+use crate::files::ShareEntry;
+
+/// Where and as whom to invoke.
+#[derive(Clone, Debug)]
+pub struct Target {
+    pub contract: String,
+    pub source: String,
+    /// Network name (`local`, `testnet`, …) or a full RPC URL
+    pub network: String,
+}
+
+/// One contract call: function name plus `--name value` arguments.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Invocation {
+    pub function: &'static str,
+    pub args: Vec<(&'static str, String)>,
+}
+
+impl Invocation {
+    /// Arguments for the `stellar` binary.
+    pub fn command(&self, target: &Target) -> Vec<String> {
+        let network_flag = if target.network.contains("://") { "--rpc-url" } else { "--network" };
+        let mut cmd: Vec<String> = [
+            "contract", "invoke",
+            "--id", &target.contract,
+            "--source", &target.source,
+            network_flag, &target.network,
+            "--", self.function,
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        for (name, value) in &self.args {
+            cmd.push(format!("--{name}"));
+            cmd.push(value.clone());
+        }
+        cmd
+    }
+
+    /// The command as a copy-pasteable shell line.
+    pub fn shell_line(&self, target: &Target) -> String {
+        let quoted: Vec<String> = self.command(target).iter().map(|a| shell_quote(a)).collect();
+        format!("stellar {}", quoted.join(" "))
+    }
+
+    pub fn arg(&self, name: &str) -> Option<&str> {
+        self.args.iter().find(|(n, _)| *n == name).map(|(_, v)| v.as_str())
+    }
+}
+
+fn shell_quote(arg: &str) -> String {
+    if arg.chars().all(|c| c.is_ascii_alphanumeric() || "-_./:".contains(c)) {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+/// `issue_account(eid, pk_cast, nf_issue, eligibility, revocation, dist_sigs)`.
+/// `eligibility` is a static-mode Merkle proof `(leaf, path)`; revocation
+/// proofs are not supported here.
+pub fn issue_account(
+    eid: u64,
+    pk_cast: &str,
+    nf_issue: &str,
+    dist_sigs: &[(String, String)],
+    eligibility: Option<(&str, &[(String, bool)])>,
+) -> Invocation {
+    let mut args = vec![
+        ("eid", eid.to_string()),
+        ("pk_cast", pk_cast.to_string()),
+        ("nf_issue", nf_issue.to_string()),
+    ];
+    if let Some((leaf, path)) = eligibility {
+        args.push(("eligibility", json!({ "Merkle": [leaf, path] }).to_string()));
+    }
+    args.push(("dist_sigs", json!(dist_sigs).to_string()));
+    Invocation { function: "issue_account", args }
+}
+
+/// `post_share(eid, kh_idx, batch, kh_pk, sig)` with the 0-based roster slot.
+pub fn post_share(eid: u64, kh_index: u32, shares: &[ShareEntry], kh_pk: &str, sig: &str) -> Invocation {
+    Invocation {
+        function: "post_share",
+        args: vec![
+            ("eid", eid.to_string()),
+            ("kh_idx", (kh_index - 1).to_string()),
+            ("batch", share_batch(shares)),
+            ("kh_pk", kh_pk.to_string()),
+            ("sig", sig.to_string()),
+        ],
+    }
+}
+
+/// `begin_shares(eid, kh_idx, digest, kh_pk, sig)`.
+pub fn begin_shares(eid: u64, kh_index: u32, digest: &str, kh_pk: &str, sig: &str) -> Invocation {
+    Invocation {
+        function: "begin_shares",
+        args: vec![
+            ("eid", eid.to_string()),
+            ("kh_idx", (kh_index - 1).to_string()),
+            ("digest", digest.to_string()),
+            ("kh_pk", kh_pk.to_string()),
+            ("sig", sig.to_string()),
+        ],
+    }
+}
+
+/// `post_share_chunk(eid, kh_idx, start, batch, sig)`; `shares` are the
+/// chunk's own shares, starting at board index `start`.
+pub fn post_share_chunk(eid: u64, kh_index: u32, start: u32, shares: &[ShareEntry], sig: &str) -> Invocation {
+    Invocation {
+        function: "post_share_chunk",
+        args: vec![
+            ("eid", eid.to_string()),
+            ("kh_idx", (kh_index - 1).to_string()),
+            ("start", start.to_string()),
+            ("batch", share_batch(shares)),
+            ("sig", sig.to_string()),
+        ],
+    }
+}
+
+/// `commit_shares(eid, kh_idx)`.
+pub fn commit_shares(eid: u64, kh_index: u32) -> Invocation {
+    Invocation {
+        function: "commit_shares",
+        args: vec![("eid", eid.to_string()), ("kh_idx", (kh_index - 1).to_string())],
+    }
+}
+
+/// A `ShareBatch` argument.
+fn share_batch(shares: &[ShareEntry]) -> String {
+    let shares: Vec<Value> = shares
+        .iter()
+        .map(|share| {
+            let proof = match &share.proof {
+                Some(p) => json!({ "Cp": { "r1": p.r1, "r2": p.r2, "s": p.s } }),
+                None => json!("None"),
+            };
+            json!({ "ballot_index": share.ballot_index, "c1": share.c1, "d": share.d, "proof": proof })
+        })
+        .collect();
+    json!({ "version": SHARE_BATCH_VERSION, "shares": shares }).to_string()
+}
+
+/// `finalize_tally(eid, tally)`.
+pub fn finalize_tally(eid: u64, tally: &[u32]) -> Invocation {
+    Invocation {
+        function: "finalize_tally",
+        args: vec![("eid", eid.to_string()), ("tally", json!(tally).to_string())],
+    }
+}
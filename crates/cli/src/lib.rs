@@ -0,0 +1,14 @@
+//! Library half of the `stellot` command-line tool: file formats, contract
+//! invocations and the logic behind each subcommand.  `main.rs` only parses
+//! arguments and does I/O.
+
+mod error;
+
+pub mod commands;
+pub mod files;
+pub mod invoke;
+
+#[cfg(test)]
+mod test;
+
+pub use error::Error;
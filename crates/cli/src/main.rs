@@ -0,0 +1,305 @@
+//! `stellot` — off-chain ceremonies for the election contract: DKG,
+//! distributor signing, key-holder partial decryption, tally combination and
+//! eligibility trees.  Contract calls are printed as `stellar contract
+//! invoke` lines, or executed with `--run`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+use clap::{Args, Parser, Subcommand};
+use ed25519_dalek::SigningKey;
+use rand::rngs::OsRng;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use stellot::commands;
+use stellot::files::{BallotEntry, DistSigFile, KhFile, MerkleFile, ShareFile};
+use stellot::invoke::{self, Invocation, Target};
+use stellot::Error;
+use stellot_client::dlog::{self, BsgsTable};
+
+#[derive(Parser)]
+#[command(name = "stellot", version, about = "Off-chain tooling for stellot elections")]
+struct Cli {
+    #[command(subcommand)]
+    command: Cmd,
+}
+
+#[derive(Subcommand)]
+enum Cmd {
+    /// Run a Feldman VSS DKG and write one key file per key holder
+    Dkg {
+        #[arg(long)]
+        m: u32,
+        #[arg(long)]
+        t: u32,
+        #[arg(long, default_value = "./keys")]
+        output: PathBuf,
+    },
+    #[command(subcommand)]
+    Distributor(DistributorCmd),
+    #[command(subcommand)]
+    Kh(KhCmd),
+    #[command(subcommand)]
+    Tally(TallyCmd),
+    #[command(subcommand)]
+    Merkle(MerkleCmd),
+}
+
+#[derive(Subcommand)]
+enum DistributorCmd {
+    /// Sign issue_msg(eid, pk_cast, nf_issue, voter); generates a key if none is given
+    Sign {
+        #[arg(long)]
+        eid: u64,
+        #[arg(long)]
+        cast_pk: String,
+        #[arg(long)]
+        nf_issue: String,
+        /// Eligibility leaf the signature binds; its proof is read from --tree
+        #[arg(long, requires = "tree")]
+        leaf: Option<String>,
+        /// `merkle build` output holding the leaf's proof
+        #[arg(long, requires = "leaf")]
+        tree: Option<PathBuf>,
+        #[arg(long)]
+        dist_sk: Option<String>,
+        #[arg(long, default_value = "./keys")]
+        keys: PathBuf,
+        /// Further distributors' signature files to include in issue_account
+        #[arg(long = "with")]
+        with: Vec<PathBuf>,
+        #[command(flatten)]
+        target: TargetArgs,
+    },
+}
+
+#[derive(Subcommand)]
+enum KhCmd {
+    /// Partially decrypt every ballot and sign the batch
+    Decrypt {
+        #[arg(long)]
+        kh: PathBuf,
+        #[arg(long)]
+        eid: u64,
+        #[arg(long)]
+        ballots: PathBuf,
+        /// Defaults to shares_kh{index}.json next to the key file
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Also sign a chunked upload of at most this many shares per chunk
+        #[arg(long)]
+        chunk_size: Option<usize>,
+    },
+    /// Submit a share file written by `kh decrypt`, chunked if it was
+    /// written with `--chunk-size`
+    PostShare {
+        #[arg(long)]
+        shares: PathBuf,
+        /// Resume a chunked upload at this chunk
+        #[arg(long, default_value_t = 0)]
+        from_chunk: usize,
+        #[command(flatten)]
+        target: TargetArgs,
+    },
+}
+
+#[derive(Subcommand)]
+enum TallyCmd {
+    /// Lagrange-combine share files, decrypt the ballots and finalize
+    Combine {
+        #[arg(long)]
+        ballots: PathBuf,
+        #[arg(long, num_args = 1.., required = true)]
+        shares: Vec<PathBuf>,
+        #[arg(long)]
+        options_count: u32,
+        #[arg(long)]
+        eid: u64,
+        /// BSGS table file, built with the default size and saved if missing
+        #[arg(long)]
+        table: Option<PathBuf>,
+        #[command(flatten)]
+        target: TargetArgs,
+    },
+}
+
+#[derive(Subcommand)]
+enum MerkleCmd {
+    /// Build an eligibility tree from hex leaves, one per line
+    Build {
+        #[arg(long)]
+        leaves: PathBuf,
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Args)]
+struct TargetArgs {
+    #[arg(long, default_value = "<CONTRACT_ID>")]
+    contract: String,
+    #[arg(long, default_value = "local")]
+    network: String,
+    #[arg(long, default_value = "deploy")]
+    source: String,
+    /// Execute the invocation with `stellar` instead of printing it
+    #[arg(long)]
+    run: bool,
+}
+
+fn main() {
+    if let Err(e) = run(Cli::parse().command) {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+fn run(cmd: Cmd) -> Result<(), Error> {
+    match cmd {
+        Cmd::Dkg { m, t, output } => {
+            let (kh_files, summary) = commands::dkg(m, t)?;
+            fs::create_dir_all(&output)?;
+            for kh in &kh_files {
+                write_json(&output.join(format!("kh{}.json", kh.index)), kh)?;
+            }
+            write_json(&output.join("combined_pubkey.json"), &summary)?;
+            let placeholder = output.join("merkle_root.txt");
+            fs::write(&placeholder, hex::encode([0u8; 32]))?;
+            println!("Wrote {} (placeholder — run `stellot merkle build`)", placeholder.display());
+            println!("Combined public key: {}", summary.combined_pubkey);
+        }
+
+        Cmd::Distributor(DistributorCmd::Sign { eid, cast_pk, nf_issue, leaf, tree, dist_sk, keys, with, target }) => {
+            let dist_sk = match dist_sk {
+                Some(sk) => sk,
+                None => {
+                    let sk = SigningKey::generate(&mut OsRng);
+                    println!("Generated distributor key:");
+                    println!("  sk: {}", hex::encode(sk.to_bytes()));
+                    println!("  pk: {}", hex::encode(sk.verifying_key().to_bytes()));
+                    hex::encode(sk.to_bytes())
+                }
+            };
+            let proof = match (&leaf, &tree) {
+                (Some(leaf), Some(tree)) => {
+                    let tree: MerkleFile = read_json(tree)?;
+                    let entry = tree.leaves.into_iter().find(|l| l.leaf.eq_ignore_ascii_case(leaf.trim()));
+                    Some(entry.ok_or_else(|| Error::Mismatch(format!("leaf {leaf} is not in the tree")))?)
+                }
+                _ => None,
+            };
+            let file = commands::distributor_sign(eid, &cast_pk, &nf_issue, leaf.as_deref(), &dist_sk)?;
+            commands::verify_dist_sig(&file)?;
+            fs::create_dir_all(&keys)?;
+            write_json(&keys.join(format!("dist_sig_{}.json", &file.pk_cast[..8])), &file)?;
+
+            let mut sigs = vec![(file.dist_pk.clone(), file.dist_sig.clone())];
+            for path in &with {
+                let other: DistSigFile = read_json(path)?;
+                commands::verify_dist_sig(&other)?;
+                if other.eid != file.eid
+                    || other.pk_cast != file.pk_cast
+                    || other.nf_issue != file.nf_issue
+                    || other.leaf != file.leaf
+                {
+                    return Err(Error::Mismatch(format!("{} signs a different account", path.display())));
+                }
+                sigs.push((other.dist_pk, other.dist_sig));
+            }
+            let eligibility = proof.as_ref().map(|p| (p.leaf.as_str(), p.proof.as_slice()));
+            dispatch(&invoke::issue_account(eid, &file.pk_cast, &file.nf_issue, &sigs, eligibility), &target)?;
+        }
+
+        Cmd::Kh(KhCmd::Decrypt { kh, eid, ballots, output, chunk_size }) => {
+            let key: KhFile = read_json(&kh)?;
+            let ballots: Vec<BallotEntry> = read_json(&ballots)?;
+            let mut file = commands::kh_decrypt(&key, eid, &ballots)?;
+            if let Some(chunk_size) = chunk_size {
+                file.upload = Some(commands::plan_upload(&key, &file, chunk_size)?);
+            }
+            let output = output.unwrap_or_else(|| sibling(&kh, &format!("shares_kh{}.json", key.index)));
+            write_json(&output, &file)?;
+        }
+
+        Cmd::Kh(KhCmd::PostShare { shares, from_chunk, target }) => {
+            let file: ShareFile = read_json(&shares)?;
+            commands::verify_share_file(&file)?;
+            let Some(upload) = &file.upload else {
+                let call = invoke::post_share(file.eid, file.kh_index, &file.shares, &file.kh_pk, &file.sig);
+                return dispatch(&call, &target);
+            };
+            // Re-beginning with the same digest is a no-op, so resuming is safe
+            dispatch(&invoke::begin_shares(file.eid, file.kh_index, &upload.digest, &file.kh_pk, &upload.sig), &target)?;
+            for chunk in upload.chunks.iter().skip(from_chunk) {
+                let shares = &file.shares[chunk.start as usize..(chunk.start + chunk.count) as usize];
+                dispatch(&invoke::post_share_chunk(file.eid, file.kh_index, chunk.start, shares, &chunk.sig), &target)?;
+            }
+            dispatch(&invoke::commit_shares(file.eid, file.kh_index), &target)?;
+        }
+
+        Cmd::Tally(TallyCmd::Combine { ballots, shares, options_count, eid, table, target }) => {
+            let ballots: Vec<BallotEntry> = read_json(&ballots)?;
+            let share_files = shares.iter().map(|p| read_json(p)).collect::<Result<Vec<ShareFile>, _>>()?;
+            if let Some(f) = share_files.iter().find(|f| f.eid != eid) {
+                return Err(Error::Mismatch(format!("key holder {} shares are for eid {}", f.kh_index, f.eid)));
+            }
+            // Per-ballot values fit one giant step of a table sized to the options
+            let table = match &table {
+                Some(path) => BsgsTable::load_or_build(path, dlog::DEFAULT_BABY_STEPS)?,
+                None => BsgsTable::new(options_count),
+            };
+            let tally = commands::tally_combine(&ballots, &share_files, options_count, &table)?;
+            println!("Tally: {tally:?}");
+            dispatch(&invoke::finalize_tally(eid, &tally), &target)?;
+        }
+
+        Cmd::Merkle(MerkleCmd::Build { leaves, output }) => {
+            let leaves: Vec<String> = fs::read_to_string(&leaves)?
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty())
+                .map(String::from)
+                .collect();
+            let file: MerkleFile = commands::merkle_build(&leaves)?;
+            match output {
+                Some(path) => write_json(&path, &file)?,
+                None => println!("{}", serde_json::to_string_pretty(&file)?),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Print the invocation, or run it through `stellar` with `--run`.
+fn dispatch(call: &Invocation, args: &TargetArgs) -> Result<(), Error> {
+    let target = Target {
+        contract: args.contract.clone(),
+        source: args.source.clone(),
+        network: args.network.clone(),
+    };
+    if !args.run {
+        println!("{}", call.shell_line(&target));
+        return Ok(());
+    }
+    let status = Command::new("stellar").args(call.command(&target)).status()?;
+    if !status.success() {
+        return Err(Error::Mismatch(format!("stellar exited with {status}")));
+    }
+    Ok(())
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, Error> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), Error> {
+    fs::write(path, serde_json::to_string_pretty(value)?)?;
+    println!("Wrote {}", path.display());
+    Ok(())
+}
+
+fn sibling(path: &Path, name: &str) -> PathBuf {
+    path.parent().unwrap_or(Path::new(".")).join(name)
+}
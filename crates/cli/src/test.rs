@@ -0,0 +1,364 @@
+#![cfg(test)]
+
+use ed25519_dalek::{Signer, SigningKey};
+use election::types::{
+    Committee, CpProof, DecryptionShare, DisputePolicy, ElectionConfig, EligibilityMode,
+    EligibilityProof, ShareBatch, ShareProof, StakePolicy,
+};
+use election::{ElectionContract, ElectionContractClient};
+use serde_json::Value;
+use soroban_sdk::testutils::{Address as _, EnvTestConfig, Ledger, LedgerInfo};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec as SVec};
+use stellot_client::dlog::BsgsTable;
+use stellot_client::elgamal;
+use stellot_client::msg;
+use stellot_client::nullifier::{nullifier_cast, nullifier_issue};
+
+use crate::commands;
+use crate::files::{BallotEntry, DistSigFile, KhFile, ShareEntry, ShareFile};
+use crate::invoke::{self, Invocation, Target};
+use crate::Error;
+
+// ── Invocation arguments → contract values ────────────────────────────────────
+
+fn bytes(env: &Env, s: &str) -> Bytes {
+    Bytes::from_slice(env, &hex::decode(s).unwrap())
+}
+
+fn bytes_n<const N: usize>(env: &Env, s: &str) -> BytesN<N> {
+    BytesN::from_array(env, &hex::decode(s).unwrap().try_into().unwrap())
+}
+
+fn json_arg(call: &Invocation, name: &str) -> Value {
+    serde_json::from_str(call.arg(name).unwrap()).unwrap()
+}
+
+fn num_arg<T: std::str::FromStr>(call: &Invocation, name: &str) -> T
+where
+    T::Err: std::fmt::Debug,
+{
+    call.arg(name).unwrap().parse().unwrap()
+}
+
+fn str_at(v: &Value, i: usize) -> &str {
+    v[i].as_str().unwrap()
+}
+
+fn share_batch(env: &Env, v: &Value) -> ShareBatch {
+    let mut shares = SVec::new(env);
+    for share in v["shares"].as_array().unwrap() {
+        let proof = match &share["proof"]["Cp"] {
+            Value::Null => ShareProof::None,
+            p => ShareProof::Cp(CpProof {
+                r1: bytes(env, p["r1"].as_str().unwrap()),
+                r2: bytes(env, p["r2"].as_str().unwrap()),
+                s: bytes_n(env, p["s"].as_str().unwrap()),
+            }),
+        };
+        shares.push_back(DecryptionShare {
+            ballot_index: share["ballot_index"].as_u64().unwrap() as u32,
+            c1: bytes(env, share["c1"].as_str().unwrap()),
+            d: bytes(env, share["d"].as_str().unwrap()),
+            proof,
+        });
+    }
+    ShareBatch { version: v["version"].as_u64().unwrap() as u32, shares }
+}
+
+/// An `Env` that writes no test snapshot: the DKG and ballots draw from
+/// `OsRng`, so a snapshot would change on every run.
+fn test_env() -> Env {
+    Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false })
+}
+
+fn set_timestamp(env: &Env, ts: u64) {
+    env.ledger().set(LedgerInfo {
+        timestamp: ts,
+        protocol_version: 22,
+        sequence_number: env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 10,
+        min_persistent_entry_ttl: 10,
+        max_entry_ttl: 3110400,
+    });
+}
+
+fn seed(n: u8) -> [u8; 32] {
+    let mut raw = [n; 32];
+    raw[31] = n.wrapping_add(1);
+    raw
+}
+
+fn target() -> Target {
+    Target { contract: "CABC".into(), source: "deploy".into(), network: "local".into() }
+}
+
+// ── Test 1: File formats match the TS scripts ─────────────────────────────────
+
+#[test]
+fn test_file_formats() {
+    let kh: KhFile = serde_json::from_str(
+        r#"{"index":2,"sk":"01","commitment":"02","ed_sk":"03","ed_pk":"04"}"#,
+    )
+    .unwrap();
+    assert_eq!(kh.index, 2);
+
+    // ballots.json written by e2e.sh carries no weight
+    let ballots: Vec<BallotEntry> = serde_json::from_str(r#"[{"c1":"aa","c2":"bb"}]"#).unwrap();
+    assert_eq!(ballots[0].weight, 1);
+
+    let sig: DistSigFile = serde_json::from_str(
+        r#"{"eid":"18446744073709551615","pk_cast":"","nf_issue":"","dist_pk":"","dist_sig":""}"#,
+    )
+    .unwrap();
+    assert_eq!(sig.eid, u64::MAX.to_string());
+
+    let shares = ShareFile {
+        eid: 1,
+        kh_index: 1,
+        kh_pk: "00".into(),
+        shares: vec![ShareEntry { ballot_index: 0, c1: "aa".into(), d: "bb".into(), proof: None }],
+        sig: "11".into(),
+        upload: None,
+    };
+    let text = serde_json::to_string(&shares).unwrap();
+    assert!(text.contains(r#""shares":[{"ballot_index":0,"c1":"aa","d":"bb","proof":null}]"#));
+    assert_eq!(serde_json::from_str::<ShareFile>(&text).unwrap(), shares);
+}
+
+// ── Test 2: Invocation rendering ──────────────────────────────────────────────
+
+#[test]
+fn test_invocations() {
+    let share = ShareEntry { ballot_index: 0, c1: "aa".into(), d: "bb".into(), proof: None };
+    let call = invoke::post_share(5, 2, &[share], "cc", "dd");
+    let batch = r#"{"shares":[{"ballot_index":0,"c1":"aa","d":"bb","proof":"None"}],"version":1}"#;
+    assert_eq!(call.arg("kh_idx"), Some("1"));
+    assert_eq!(call.arg("batch"), Some(batch));
+    assert_eq!(
+        call.shell_line(&target()),
+        format!("stellar contract invoke --id CABC --source deploy --network local -- post_share --eid 5 --kh_idx 1 --batch '{batch}' --kh_pk cc --sig dd")
+    );
+
+    let rpc = Target { network: "http://localhost:8000/rpc".into(), ..target() };
+    assert!(call.command(&rpc).windows(2).any(|w| w[0] == "--rpc-url"));
+
+    let path = vec![("ee".to_string(), true)];
+    let call = invoke::issue_account(5, "aa", "bb", &[("cc".into(), "dd".into())], Some(("aa", &path)));
+    assert_eq!(call.arg("eligibility"), Some(r#"{"Merkle":["aa",[["ee",true]]]}"#));
+    assert_eq!(call.arg("dist_sigs"), Some(r#"[["cc","dd"]]"#));
+
+    let share = ShareEntry { ballot_index: 3, c1: "aa".into(), d: "bb".into(), proof: None };
+    let call = invoke::post_share_chunk(5, 2, 3, &[share], "dd");
+    assert_eq!(call.function, "post_share_chunk");
+    assert_eq!((call.arg("kh_idx"), call.arg("start")), (Some("1"), Some("3")));
+    assert_eq!(call.arg("batch"), Some(batch.replace("\"ballot_index\":0", "\"ballot_index\":3").as_str()));
+    assert_eq!(invoke::begin_shares(5, 2, "ee", "cc", "dd").arg("digest"), Some("ee"));
+    assert_eq!(invoke::commit_shares(5, 2).args, vec![("eid", "5".into()), ("kh_idx", "1".into())]);
+
+    assert_eq!(invoke::finalize_tally(5, &[1, 0, 2]).arg("tally"), Some("[1,0,2]"));
+}
+
+// ── Test 3: Bad inputs are rejected before anything is posted ─────────────────
+
+#[test]
+fn test_rejects_inconsistent_inputs() {
+    let (kh_files, summary) = commands::dkg(2, 2).unwrap();
+    assert!(matches!(commands::dkg(2, 3), Err(Error::Client(_))));
+
+    let ct = elgamal::encrypt(1, &hex::decode(&summary.combined_pubkey).unwrap().try_into().unwrap()).unwrap();
+    let ballots = vec![BallotEntry { c1: hex::encode(ct.c1), c2: hex::encode(ct.c2), weight: 1 }];
+    let mut shares: Vec<ShareFile> =
+        kh_files.iter().map(|kh| commands::kh_decrypt(kh, 9, &ballots).unwrap()).collect();
+    assert_eq!(commands::tally_combine(&ballots, &shares, 2, &BsgsTable::new(2)).unwrap(), vec![0, 1]);
+
+    // Tampered share → signature fails
+    shares[0].shares[0].d = shares[1].shares[0].d.clone();
+    assert!(matches!(commands::tally_combine(&ballots, &shares, 2, &BsgsTable::new(2)), Err(Error::BadSignature(_))));
+
+    // A batch over a different board
+    let other = BallotEntry { c1: hex::encode(elgamal::public_key(&elgamal::random_scalar())), ..ballots[0].clone() };
+    shares[0] = commands::kh_decrypt(&kh_files[0], 9, &[other]).unwrap();
+    assert!(matches!(commands::tally_combine(&ballots, &shares, 2, &BsgsTable::new(2)), Err(Error::Mismatch(_))));
+
+    // Upload plans must cover the batch in order and chain to their digest
+    let mut file = commands::kh_decrypt(&kh_files[1], 9, &[ballots[0].clone(), ballots[0].clone()]).unwrap();
+    file.upload = Some(commands::plan_upload(&kh_files[1], &file, 1).unwrap());
+    commands::verify_share_file(&file).unwrap();
+    let mut gap = file.clone();
+    gap.upload.as_mut().unwrap().chunks.remove(0);
+    assert!(matches!(commands::verify_share_file(&gap), Err(Error::Mismatch(_))));
+    let mut swapped = file.clone();
+    swapped.upload.as_mut().unwrap().digest = hex::encode([0u8; 32]);
+    assert!(matches!(commands::verify_share_file(&swapped), Err(Error::Mismatch(_))));
+
+    let file = commands::distributor_sign(9, &hex::encode(seed(1)), &hex::encode(seed(2)), None, &hex::encode(seed(3))).unwrap();
+    commands::verify_dist_sig(&file).unwrap();
+    let forged = DistSigFile { eid: "10".into(), ..file.clone() };
+    assert!(matches!(commands::verify_dist_sig(&forged), Err(Error::BadSignature(_))));
+    // A signature without a leaf does not cover an issuance that proves one
+    let rebound = DistSigFile { leaf: Some(hex::encode(seed(4))), ..file };
+    assert!(matches!(commands::verify_dist_sig(&rebound), Err(Error::BadSignature(_))));
+    assert!(matches!(commands::merkle_build(&["zz".into()]), Err(Error::Hex("leaf"))));
+}
+
+// ── Test 4: Full election driven through the CLI's outputs ────────────────────
+
+#[test]
+fn test_full_election_via_cli() {
+    let env = test_env();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    // `stellot dkg --m 3 --t 2` and `stellot merkle build`
+    let (kh_files, summary) = commands::dkg(3, 2).unwrap();
+    let voters: Vec<SigningKey> = (30..33).map(|n| SigningKey::from_bytes(&seed(n))).collect();
+    let leaves: Vec<String> = voters.iter().map(|v| hex::encode(v.verifying_key().to_bytes())).collect();
+    let tree = commands::merkle_build(&leaves).unwrap();
+    let dist_sk = hex::encode(seed(20));
+    let dist_pk = SigningKey::from_bytes(&seed(20)).verifying_key().to_bytes();
+
+    let mut kh_roster = SVec::new(&env);
+    for pk in &summary.kh_ed_pks {
+        kh_roster.push_back(bytes_n(&env, pk));
+    }
+    let mut dist_roster = SVec::new(&env);
+    dist_roster.push_back(BytesN::from_array(&env, &dist_pk));
+    let eid = client.deploy(
+        &Address::generate(&env),
+        &Bytes::from_slice(&env, b"CLI election"),
+        &3u32,
+        &1010,
+        &1200,
+        &bytes(&env, &summary.combined_pubkey),
+        &bytes_n(&env, &tree.root),
+        &Committee { members: dist_roster, threshold: 1 },
+        &Committee { members: kh_roster, threshold: 2 },
+        &ElectionConfig {
+            revoting: false,
+            max_end_time: 1200,
+            eligibility: EligibilityMode::Static,
+            disputes: DisputePolicy::None,
+            staking: StakePolicy::None,
+        },
+    );
+
+    // `stellot distributor sign` → issue_account
+    for (voter, leaf) in voters.iter().zip(&tree.leaves) {
+        let nf_issue = hex::encode(nullifier_issue(&voter.to_bytes(), eid));
+        let file = commands::distributor_sign(eid, &leaf.leaf, &nf_issue, Some(&leaf.leaf), &dist_sk).unwrap();
+        let call = invoke::issue_account(
+            eid,
+            &file.pk_cast,
+            &file.nf_issue,
+            &[(file.dist_pk.clone(), file.dist_sig.clone())],
+            Some((&leaf.leaf, &leaf.proof)),
+        );
+
+        let merkle = &json_arg(&call, "eligibility")["Merkle"];
+        let mut path = SVec::new(&env);
+        for step in merkle[1].as_array().unwrap() {
+            path.push_back((bytes_n(&env, str_at(step, 0)), step[1].as_bool().unwrap()));
+        }
+        let mut sigs = SVec::new(&env);
+        for pair in json_arg(&call, "dist_sigs").as_array().unwrap() {
+            sigs.push_back((bytes_n(&env, str_at(pair, 0)), bytes_n(&env, str_at(pair, 1))));
+        }
+        client.issue_account(
+            &num_arg(&call, "eid"),
+            &bytes_n(&env, call.arg("pk_cast").unwrap()),
+            &bytes_n(&env, call.arg("nf_issue").unwrap()),
+            &Some(EligibilityProof::Merkle(bytes(&env, str_at(merkle, 0)), path)),
+            &None,
+            &sigs,
+        );
+    }
+
+    // Voters cast; the board is exported as ballots.json
+    set_timestamp(&env, 1015);
+    let pk: [u8; 33] = hex::decode(&summary.combined_pubkey).unwrap().try_into().unwrap();
+    for (voter, v) in voters.iter().zip([1u32, 2, 1]) {
+        let ct = elgamal::encrypt(v, &pk).unwrap();
+        let nf_cast = nullifier_cast(&voter.to_bytes(), eid);
+        let digest = msg::cast_msg(eid, &nf_cast, &ct.c1, &ct.c2);
+        client.cast(
+            &eid,
+            &BytesN::from_array(&env, &nf_cast),
+            &Bytes::from_slice(&env, &ct.c1),
+            &Bytes::from_slice(&env, &ct.c2),
+            &BytesN::from_array(&env, &voter.verifying_key().to_bytes()),
+            &BytesN::from_array(&env, &voter.sign(&digest).to_bytes()),
+        );
+    }
+    let ballots: Vec<BallotEntry> = client
+        .get_ballots(&eid, &0, &100)
+        .iter()
+        .map(|b| BallotEntry {
+            c1: hex::encode(b.c1.iter().collect::<Vec<u8>>()),
+            c2: hex::encode(b.c2.iter().collect::<Vec<u8>>()),
+            weight: b.weight,
+        })
+        .collect();
+
+    // `stellot kh decrypt` + `stellot kh post-share` for key holder 2, and
+    // with `--chunk-size 2` for key holder 3
+    set_timestamp(&env, 1300);
+    let mut share_files = Vec::new();
+    let file = commands::kh_decrypt(&kh_files[1], eid, &ballots).unwrap();
+    commands::verify_share_file(&file).unwrap();
+    let call = invoke::post_share(file.eid, file.kh_index, &file.shares, &file.kh_pk, &file.sig);
+    client.post_share(
+        &num_arg(&call, "eid"),
+        &num_arg(&call, "kh_idx"),
+        &share_batch(&env, &json_arg(&call, "batch")),
+        &bytes_n(&env, call.arg("kh_pk").unwrap()),
+        &bytes_n(&env, call.arg("sig").unwrap()),
+    );
+    share_files.push(file);
+
+    let mut file = commands::kh_decrypt(&kh_files[2], eid, &ballots).unwrap();
+    file.upload = Some(commands::plan_upload(&kh_files[2], &file, 2).unwrap());
+    commands::verify_share_file(&file).unwrap();
+    let upload = file.upload.clone().unwrap();
+    assert_eq!(upload.chunks.iter().map(|c| c.count).collect::<Vec<_>>(), [2, 1]);
+    let call = invoke::begin_shares(file.eid, file.kh_index, &upload.digest, &file.kh_pk, &upload.sig);
+    client.begin_shares(
+        &num_arg(&call, "eid"),
+        &num_arg(&call, "kh_idx"),
+        &bytes_n(&env, call.arg("digest").unwrap()),
+        &bytes_n(&env, call.arg("kh_pk").unwrap()),
+        &bytes_n(&env, call.arg("sig").unwrap()),
+    );
+    for chunk in &upload.chunks {
+        let shares = &file.shares[chunk.start as usize..(chunk.start + chunk.count) as usize];
+        let call = invoke::post_share_chunk(file.eid, file.kh_index, chunk.start, shares, &chunk.sig);
+        client.post_share_chunk(
+            &num_arg(&call, "eid"),
+            &num_arg(&call, "kh_idx"),
+            &num_arg(&call, "start"),
+            &share_batch(&env, &json_arg(&call, "batch")),
+            &bytes_n(&env, call.arg("sig").unwrap()),
+        );
+    }
+    assert_eq!(client.get_share_count(&eid), 1);
+    let call = invoke::commit_shares(file.eid, file.kh_index);
+    client.commit_shares(&num_arg(&call, "eid"), &num_arg(&call, "kh_idx"));
+    share_files.push(file);
+    assert_eq!(client.get_share_count(&eid), 2);
+
+    // `stellot tally combine` → finalize_tally
+    let tally = commands::tally_combine(&ballots, &share_files, 3, &BsgsTable::new(3)).unwrap();
+    assert_eq!(tally, vec![0, 2, 1]);
+    // A table smaller than the vote range takes more giant steps, same result
+    assert_eq!(commands::tally_combine(&ballots, &share_files, 3, &BsgsTable::new(1)).unwrap(), tally);
+    let call = invoke::finalize_tally(eid, &tally);
+    let mut onchain = SVec::new(&env);
+    for count in json_arg(&call, "tally").as_array().unwrap() {
+        onchain.push_back(count.as_u64().unwrap() as u32);
+    }
+    client.finalize_tally(&eid, &onchain);
+    assert_eq!(client.get_tally_record(&eid).unwrap().tally, onchain);
+}
@@ -0,0 +1,67 @@
+//! Chaum-Pedersen proof that a partial decryption is well formed, in the
+//! form `scripts/post_share.ts` computes it.
+//!
+//! Key holder `j` with share `sk_j` and public share `PK_j = sk_j·G` proves
+//! `log_G(PK_j) == log_C1(D_j)` without revealing `sk_j`:
+//!
+//! ```text
+//! R1 = r·G,  R2 = r·C1
+//! c  = SHA256(G ‖ PK_j ‖ C1 ‖ D_j ‖ R1 ‖ R2) mod q
+//! s  = r + c·sk_j
+//! ```
+//!
+//! and the verifier checks `s·G == R1 + c·PK_j` and `s·C1 == R2 + c·D_j`.
+
+use k256::{ProjectivePoint, Scalar};
+use sha2::{Digest, Sha256};
+
+use crate::elgamal::{
+    decode_point, decode_scalar, encode_point, encode_scalar, random_scalar, scalar_from_hash,
+};
+use crate::Error;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CpProof {
+    pub r1: [u8; 33],
+    pub r2: [u8; 33],
+    /// Response `s`, big-endian
+    pub s: [u8; 32],
+}
+
+/// Partially decrypt `c1` with `sk_j` and prove it: returns `(D_j, proof)`.
+pub fn prove(c1: &[u8], sk_j: &Scalar) -> Result<([u8; 33], CpProof), Error> {
+    let c1_point = decode_point(c1)?;
+    let pk_j = ProjectivePoint::GENERATOR * sk_j;
+    let d = c1_point * sk_j;
+    let r = random_scalar();
+    let (r1, r2) = (ProjectivePoint::GENERATOR * r, c1_point * r);
+    let c = challenge(&pk_j, &c1_point, &d, &r1, &r2);
+    let s = r + c * sk_j;
+    Ok((
+        encode_point(&d),
+        CpProof { r1: encode_point(&r1), r2: encode_point(&r2), s: encode_scalar(&s) },
+    ))
+}
+
+/// Check that `d` is `C1` raised to the discrete log of `pk_j`.
+pub fn verify(pk_j: &ProjectivePoint, c1: &[u8], d: &[u8], proof: &CpProof) -> Result<bool, Error> {
+    let (c1, d) = (decode_point(c1)?, decode_point(d)?);
+    let (r1, r2) = (decode_point(&proof.r1)?, decode_point(&proof.r2)?);
+    let s = decode_scalar(&proof.s)?;
+    let c = challenge(pk_j, &c1, &d, &r1, &r2);
+    Ok(ProjectivePoint::GENERATOR * s == r1 + *pk_j * c && c1 * s == r2 + d * c)
+}
+
+fn challenge(
+    pk_j: &ProjectivePoint,
+    c1: &ProjectivePoint,
+    d: &ProjectivePoint,
+    r1: &ProjectivePoint,
+    r2: &ProjectivePoint,
+) -> Scalar {
+    let mut h = Sha256::new();
+    for point in [&ProjectivePoint::GENERATOR, pk_j, c1, d, r1, r2] {
+        h.update(encode_point(point));
+    }
+    scalar_from_hash(&h.finalize().into())
+}
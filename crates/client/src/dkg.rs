@@ -0,0 +1,107 @@
+//! Feldman VSS DKG for the combined key-holder election key, simulated for
+//! all parties at once as `scripts/dkg.ts` does.
+//!
+//! Party `j` samples `f_j(x) = a_j0 + a_j1·x + … + a_j(t-1)·x^(t-1)`,
+//! publishes `A_jk = a_jk·G` and sends `s_ji = f_j(i)` to party `i`.  Party
+//! `i`'s share of the combined key is `sk_i = Σ_j s_ji`; the election key is
+//! `PK = Σ_j A_j0`.
+
+use k256::{ProjectivePoint, Scalar};
+use sha2::{Digest, Sha256};
+
+use crate::elgamal::{decode_point, encode_point, random_scalar};
+use crate::Error;
+
+pub struct DkgOutput {
+    /// Combined election public key `PK = Σ_j A_j0`
+    pub combined_pubkey: [u8; 33],
+    /// `(index, sk_i)` per party, 1-based
+    pub shares: Vec<(u32, Scalar)>,
+    /// `commitments[j][k] = A_jk`
+    pub commitments: Vec<Vec<[u8; 33]>>,
+}
+
+/// Run an `m`-party DKG with threshold `t`, verifying every share against
+/// the dealer's commitments.
+pub fn run(m: u32, t: u32) -> Result<DkgOutput, Error> {
+    if t < 1 || t > m {
+        return Err(Error::InvalidThreshold);
+    }
+
+    let polynomials: Vec<Vec<Scalar>> =
+        (0..m).map(|_| (0..t).map(|_| random_scalar()).collect()).collect();
+    let commitments: Vec<Vec<ProjectivePoint>> = polynomials
+        .iter()
+        .map(|coeffs| coeffs.iter().map(|a| ProjectivePoint::GENERATOR * a).collect())
+        .collect();
+
+    let mut shares = Vec::with_capacity(m as usize);
+    for i in 1..=m {
+        let mut sk_i = Scalar::ZERO;
+        for (coeffs, comms) in polynomials.iter().zip(&commitments) {
+            let s_ji = poly_eval(coeffs, i);
+            if !verify_share(comms, i, &s_ji) {
+                return Err(Error::InvalidShare);
+            }
+            sk_i += s_ji;
+        }
+        shares.push((i, sk_i));
+    }
+
+    let combined = commitments.iter().fold(ProjectivePoint::IDENTITY, |acc, c| acc + c[0]);
+    Ok(DkgOutput {
+        combined_pubkey: encode_point(&combined),
+        shares,
+        commitments: commitments
+            .iter()
+            .map(|row| row.iter().map(encode_point).collect())
+            .collect(),
+    })
+}
+
+/// Feldman check: `s·G == Σ_k A_k · i^k`.
+pub fn verify_share(commitments: &[ProjectivePoint], i: u32, s: &Scalar) -> bool {
+    let x = Scalar::from(i as u64);
+    let expected = commitments
+        .iter()
+        .rev()
+        .fold(ProjectivePoint::IDENTITY, |acc, a_k| acc * x + a_k);
+    ProjectivePoint::GENERATOR * s == expected
+}
+
+/// Public share `PK_i = sk_i·G = Σ_j Σ_k A_jk · i^k` of party `i`, from the
+/// published commitment matrix; what a CP proof from party `i` is checked
+/// against.
+pub fn public_share(commitments: &[Vec<[u8; 33]>], i: u32) -> Result<ProjectivePoint, Error> {
+    let x = Scalar::from(i as u64);
+    let mut pk = ProjectivePoint::IDENTITY;
+    for row in commitments {
+        let mut acc = ProjectivePoint::IDENTITY;
+        for a_k in row.iter().rev() {
+            acc = acc * x + decode_point(a_k)?;
+        }
+        pk += acc;
+    }
+    Ok(pk)
+}
+
+/// SHA256("stellot:vss" || rows_le32 || (len_le32 || A_j0 || A_j1 || …) per row):
+/// the commitment matrix an election's dispute window pins, so
+/// `dispute_share` can check CP proofs against `public_share`.
+pub fn vss_digest(commitments: &[Vec<[u8; 33]>]) -> [u8; 32] {
+    let mut h = Sha256::new();
+    h.update(b"stellot:vss");
+    h.update((commitments.len() as u32).to_le_bytes());
+    for row in commitments {
+        h.update((row.len() as u32).to_le_bytes());
+        for a in row {
+            h.update(a);
+        }
+    }
+    h.finalize().into()
+}
+
+fn poly_eval(coeffs: &[Scalar], i: u32) -> Scalar {
+    let x = Scalar::from(i as u64);
+    coeffs.iter().rev().fold(Scalar::ZERO, |acc, c| acc * x + c)
+}
@@ -0,0 +1,225 @@
+//! Baby-step giant-step discrete logs for decrypted tallies.
+//!
+//! Decryption leaves `x·G`: `(v+1)·G` for one ballot, `Σ w_i·(v_i+1)·G` for
+//! an aggregate.  `elgamal::decode_vote`'s linear search is fine for the
+//! former; an aggregate over millions of ballots needs a table.
+//!
+//! A table of `m` baby steps holds the x-coordinate of `j·G` for `1 ≤ j ≤ m`,
+//! truncated to 8 bytes and sorted.  `j·G` and `−j·G` share an x-coordinate,
+//! so one giant step covers a window of `2m + 1` values: for centre `c`,
+//! `Q = P − c·G` matches entry `j` when `x = c ± j`.  Recovering `x ≤ N`
+//! therefore takes `12·m` bytes and at most `N / (2m + 1)` giant steps:
+//!
+//! ```text
+//! m        table     giant steps for 2^32
+//! 2^16     768 KiB   32 768
+//! 2^20     12 MiB    2 048     (DEFAULT_BABY_STEPS)
+//! 2^24     192 MiB   128
+//! 2^26     768 MiB   32        (MAX_BABY_STEPS)
+//! ```
+//!
+//! Truncated keys can collide, so every match is confirmed by one scalar
+//! multiplication before it is returned.
+
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use k256::elliptic_curve::point::AffineCoordinates;
+use k256::{AffinePoint, ProjectivePoint, Scalar};
+
+use crate::Error;
+
+/// Baby steps of the default table: `2^32` in about 2 000 giant steps.
+pub const DEFAULT_BABY_STEPS: u32 = 1 << 20;
+
+/// Largest table built or read; a file header claiming more is rejected
+/// before anything is allocated for it.
+pub const MAX_BABY_STEPS: u32 = 1 << 26;
+
+/// File header: magic, then the baby-step count (little-endian `u32`).
+const MAGIC: &[u8; 8] = b"STLTBSGS";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BsgsTable {
+    baby_steps: u32,
+    /// Truncated x-coordinate of `j·G`, sorted
+    keys: Vec<u64>,
+    /// `j` for each key
+    steps: Vec<u32>,
+}
+
+impl BsgsTable {
+    /// Build a table of `baby_steps` (clamped to `1..=MAX_BABY_STEPS`) entries.
+    pub fn new(baby_steps: u32) -> BsgsTable {
+        let baby_steps = baby_steps.clamp(1, MAX_BABY_STEPS);
+        let mut entries = Vec::with_capacity(baby_steps as usize);
+        let mut acc = ProjectivePoint::IDENTITY;
+        for j in 1..=baby_steps {
+            acc += ProjectivePoint::GENERATOR;
+            entries.push((key(&acc.to_affine()), j));
+        }
+        entries.sort_unstable();
+        let (keys, steps) = entries.into_iter().unzip();
+        BsgsTable { baby_steps, keys, steps }
+    }
+
+    pub fn baby_steps(&self) -> u32 {
+        self.baby_steps
+    }
+
+    /// Size of the table's entries in memory and on disk.
+    pub fn memory_bytes(&self) -> usize {
+        self.keys.len() * 12
+    }
+
+    /// Giant steps needed to cover `0..=max`.
+    pub fn giant_steps(&self, max: u64) -> u64 {
+        max.saturating_sub(self.baby_steps as u64).div_ceil(self.window()) + 1
+    }
+
+    /// `x` with `x·G = point` and `0 ≤ x ≤ max`.
+    pub fn solve(&self, point: &ProjectivePoint, max: u64) -> Result<u64, Error> {
+        self.solve_all(std::slice::from_ref(point), max).map(|x| x[0])
+    }
+
+    /// Solve every point in one pass over the giant steps, e.g. all option
+    /// aggregates of a tally.  Fails if any point is out of range.
+    pub fn solve_all(&self, points: &[ProjectivePoint], max: u64) -> Result<Vec<u64>, Error> {
+        let stride = ProjectivePoint::GENERATOR * Scalar::from(self.window());
+        let mut found = vec![None; points.len()];
+        let mut current = points.to_vec();
+        for i in 0..self.giant_steps(max) {
+            let centre = i * self.window();
+            let mut pending = false;
+            for (q, x) in current.iter_mut().zip(&mut found) {
+                if x.is_none() {
+                    *x = self.lookup(q, centre, max);
+                    *q -= stride;
+                    pending = true;
+                }
+            }
+            if !pending {
+                break;
+            }
+        }
+        found.into_iter().map(|x| x.ok_or(Error::DiscreteLogNotFound)).collect()
+    }
+
+    /// Width of the window one giant step covers.
+    fn window(&self) -> u64 {
+        2 * self.baby_steps as u64 + 1
+    }
+
+    /// `centre + k` if `q = k·G` for some `|k| ≤ m`, within `0..=max`.
+    fn lookup(&self, q: &ProjectivePoint, centre: u64, max: u64) -> Option<u64> {
+        // The identity has no x-coordinate
+        if *q == ProjectivePoint::IDENTITY {
+            return Some(centre).filter(|x| *x <= max);
+        }
+        let key = key(&q.to_affine());
+        let start = self.keys.partition_point(|k| *k < key);
+        for n in start..self.keys.len() {
+            if self.keys[n] != key {
+                break;
+            }
+            let j = self.steps[n] as u64;
+            let jg = ProjectivePoint::GENERATOR * Scalar::from(j);
+            let x = if *q == jg {
+                centre.checked_add(j)
+            } else if *q == -jg {
+                centre.checked_sub(j)
+            } else {
+                continue;
+            };
+            return x.filter(|x| *x <= max);
+        }
+        None
+    }
+
+    // ── Persistence ───────────────────────────────────────────────────────────
+
+    pub fn write_to(&self, mut w: impl Write) -> io::Result<()> {
+        w.write_all(MAGIC)?;
+        w.write_all(&self.baby_steps.to_le_bytes())?;
+        for (key, j) in self.keys.iter().zip(&self.steps) {
+            w.write_all(&key.to_le_bytes())?;
+            w.write_all(&j.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    /// Read a table written by `write_to`.  The entries must be sorted and
+    /// in range, and the first and last are recomputed, so a truncated or
+    /// foreign file is rejected as `InvalidData`.
+    pub fn read_from(mut r: impl Read) -> io::Result<BsgsTable> {
+        let invalid = |what: &str| io::Error::new(io::ErrorKind::InvalidData, format!("BSGS table: {what}"));
+        let mut header = [0u8; 12];
+        r.read_exact(&mut header)?;
+        if &header[..8] != MAGIC {
+            return Err(invalid("bad magic"));
+        }
+        let baby_steps = u32::from_le_bytes(header[8..].try_into().unwrap());
+        if baby_steps == 0 {
+            return Err(invalid("no entries"));
+        }
+        if baby_steps > MAX_BABY_STEPS {
+            return Err(invalid("too many entries"));
+        }
+
+        // Past the default size, grow with the entries actually read rather
+        // than trusting the header
+        let capacity = baby_steps.min(DEFAULT_BABY_STEPS) as usize;
+        let mut keys = Vec::with_capacity(capacity);
+        let mut steps = Vec::with_capacity(capacity);
+        let mut entry = [0u8; 12];
+        for _ in 0..baby_steps {
+            r.read_exact(&mut entry).map_err(|_| invalid("truncated"))?;
+            keys.push(u64::from_le_bytes(entry[..8].try_into().unwrap()));
+            steps.push(u32::from_le_bytes(entry[8..].try_into().unwrap()));
+        }
+        if r.read(&mut [0u8])? != 0 {
+            return Err(invalid("trailing bytes"));
+        }
+        if keys.windows(2).any(|w| w[0] > w[1]) || steps.iter().any(|j| *j == 0 || *j > baby_steps) {
+            return Err(invalid("entries out of order"));
+        }
+        let table = BsgsTable { baby_steps, keys, steps };
+        for n in [0, table.keys.len() - 1] {
+            let jg = ProjectivePoint::GENERATOR * Scalar::from(table.steps[n] as u64);
+            if key(&jg.to_affine()) != table.keys[n] {
+                return Err(invalid("entries do not match the generator"));
+            }
+        }
+        Ok(table)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut w = BufWriter::new(File::create(path)?);
+        self.write_to(&mut w)?;
+        w.flush()
+    }
+
+    pub fn load(path: &Path) -> io::Result<BsgsTable> {
+        BsgsTable::read_from(BufReader::new(File::open(path)?))
+    }
+
+    /// The table at `path` if it holds `baby_steps` entries; otherwise build
+    /// one and save it there.
+    pub fn load_or_build(path: &Path, baby_steps: u32) -> io::Result<BsgsTable> {
+        match BsgsTable::load(path) {
+            Ok(table) if table.baby_steps == baby_steps.clamp(1, MAX_BABY_STEPS) => return Ok(table),
+            Ok(_) => {}
+            Err(e) if matches!(e.kind(), io::ErrorKind::NotFound | io::ErrorKind::InvalidData) => {}
+            Err(e) => return Err(e),
+        }
+        let table = BsgsTable::new(baby_steps);
+        table.save(path)?;
+        Ok(table)
+    }
+}
+
+/// First 8 bytes of the x-coordinate.
+fn key(p: &AffinePoint) -> u64 {
+    u64::from_be_bytes(p.x()[..8].try_into().unwrap())
+}
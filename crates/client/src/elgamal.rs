@@ -0,0 +1,161 @@
+//! Exponential ElGamal on secp256k1 with threshold decryption.
+//!
+//! A vote `v` is encoded as `(v+1)·G` so that `v = 0` never encrypts the
+//! point at infinity:
+//!
+//! ```text
+//! C1 = r·G
+//! C2 = (v+1)·G + r·PK
+//! ```
+//!
+//! Key holder `j` publishes `D_j = sk_j·C1`; any `t` of them combine by
+//! Lagrange interpolation at zero into `D = sk·C1`, and `C2 − D = (v+1)·G`.
+
+use k256::{
+    elliptic_curve::{
+        ops::Reduce,
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+        Field, PrimeField,
+    },
+    AffinePoint, EncodedPoint, FieldBytes, ProjectivePoint, Scalar, U256,
+};
+use rand::rngs::OsRng;
+
+use crate::Error;
+
+/// A ciphertext as stored on-chain: two 33-byte compressed points.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Ciphertext {
+    pub c1: [u8; 33],
+    pub c2: [u8; 33],
+}
+
+// ── Encoding ──────────────────────────────────────────────────────────────────
+
+/// 33-byte SEC1 compressed encoding.  Panics on the identity, which never
+/// appears in a well-formed ciphertext or share.
+pub fn encode_point(point: &ProjectivePoint) -> [u8; 33] {
+    point
+        .to_affine()
+        .to_encoded_point(true)
+        .as_bytes()
+        .try_into()
+        .expect("identity point has no compressed encoding")
+}
+
+pub fn decode_point(bytes: &[u8]) -> Result<ProjectivePoint, Error> {
+    let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| Error::InvalidPoint)?;
+    Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+        .map(ProjectivePoint::from)
+        .ok_or(Error::InvalidPoint)
+}
+
+/// Big-endian 32-byte scalar, as the TS scripts write secret keys.
+pub fn decode_scalar(bytes: &[u8; 32]) -> Result<Scalar, Error> {
+    Option::<Scalar>::from(Scalar::from_repr(FieldBytes::from(*bytes)))
+        .filter(|s| !bool::from(s.is_zero()))
+        .ok_or(Error::InvalidScalar)
+}
+
+pub fn encode_scalar(scalar: &Scalar) -> [u8; 32] {
+    scalar.to_repr().into()
+}
+
+/// Reduce 32 arbitrary bytes (e.g. a hash) into a scalar.
+pub fn scalar_from_hash(bytes: &[u8; 32]) -> Scalar {
+    <Scalar as Reduce<U256>>::reduce_bytes(&FieldBytes::from(*bytes))
+}
+
+pub fn random_scalar() -> Scalar {
+    loop {
+        let s = Scalar::random(&mut OsRng);
+        if !bool::from(s.is_zero()) {
+            return s;
+        }
+    }
+}
+
+/// `sk·G` compressed.
+pub fn public_key(sk: &Scalar) -> [u8; 33] {
+    encode_point(&(ProjectivePoint::GENERATOR * sk))
+}
+
+// ── Encryption ────────────────────────────────────────────────────────────────
+
+/// Encrypt option `v` (0-based) under the election key `pk`.
+pub fn encrypt(v: u32, pk: &[u8; 33]) -> Result<Ciphertext, Error> {
+    encrypt_with(v, pk, &random_scalar())
+}
+
+/// `encrypt` with caller-chosen randomness `r`, for tests and re-encryption
+/// checks.
+pub fn encrypt_with(v: u32, pk: &[u8; 33], r: &Scalar) -> Result<Ciphertext, Error> {
+    let pk = decode_point(pk)?;
+    let c1 = ProjectivePoint::GENERATOR * r;
+    let c2 = ProjectivePoint::GENERATOR * Scalar::from(v as u64 + 1) + pk * r;
+    Ok(Ciphertext { c1: encode_point(&c1), c2: encode_point(&c2) })
+}
+
+// ── Threshold decryption ──────────────────────────────────────────────────────
+
+/// D_j = sk_j·C1
+pub fn partial_decrypt(c1: &[u8], sk_j: &Scalar) -> Result<[u8; 33], Error> {
+    Ok(encode_point(&(decode_point(c1)? * sk_j)))
+}
+
+/// λ_j(0) = ∏_{k ∈ S, k≠j} k / (k − j) over Z_q, for 1-based party indices.
+pub fn lagrange_coeff(j: u32, indices: &[u32]) -> Result<Scalar, Error> {
+    let distinct = indices.iter().enumerate().all(|(n, k)| !indices[..n].contains(k));
+    if !distinct || indices.contains(&0) {
+        return Err(Error::InvalidPartyIndices);
+    }
+    let mut num = Scalar::ONE;
+    let mut den = Scalar::ONE;
+    for &k in indices {
+        if k == j {
+            continue;
+        }
+        num *= Scalar::from(k as u64);
+        den *= Scalar::from(k as u64) - Scalar::from(j as u64);
+    }
+    Option::<Scalar>::from(den.invert()).map(|inv| num * inv).ok_or(Error::InvalidPartyIndices)
+}
+
+/// Combine `(party_index, D_j)` partials (1-based indices) into `D = sk·C1`.
+pub fn combine_partials(partials: &[(u32, [u8; 33])]) -> Result<ProjectivePoint, Error> {
+    let indices: Vec<u32> = partials.iter().map(|(j, _)| *j).collect();
+    let mut d = ProjectivePoint::IDENTITY;
+    for (j, d_j) in partials {
+        d += decode_point(d_j)? * lagrange_coeff(*j, &indices)?;
+    }
+    Ok(d)
+}
+
+/// Combine a Shamir-split secret from `(party_index, sk_j)` shares.  Only for
+/// tests and DKG checks; a live election never reconstructs `sk`.
+pub fn reconstruct_secret(shares: &[(u32, Scalar)]) -> Result<Scalar, Error> {
+    let indices: Vec<u32> = shares.iter().map(|(j, _)| *j).collect();
+    let mut sk = Scalar::ZERO;
+    for (j, s) in shares {
+        sk += *s * lagrange_coeff(*j, &indices)?;
+    }
+    Ok(sk)
+}
+
+/// `C2 − D`, i.e. `(v+1)·G` (or `Σ w_i·(v_i+1)·G` for an aggregate).
+pub fn unblind(c2: &[u8], d: &ProjectivePoint) -> Result<ProjectivePoint, Error> {
+    Ok(decode_point(c2)? - d)
+}
+
+/// Recover `v` from `(v+1)·G` by linear search over `0..max`.  Fine for a
+/// single ballot, too slow for large aggregates.
+pub fn decode_vote(m: &ProjectivePoint, max: u32) -> Result<u32, Error> {
+    let mut acc = ProjectivePoint::GENERATOR;
+    for v in 0..max {
+        if acc == *m {
+            return Ok(v);
+        }
+        acc += ProjectivePoint::GENERATOR;
+    }
+    Err(Error::DiscreteLogNotFound)
+}
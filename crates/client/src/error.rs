@@ -0,0 +1,48 @@
+use core::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Bytes are not a valid compressed secp256k1 point
+    InvalidPoint,
+    /// Bytes are not a canonical non-zero secp256k1 scalar
+    InvalidScalar,
+    /// A shares blob is truncated or has trailing bytes
+    MalformedShares,
+    /// A shares blob has a layout version this library does not know
+    UnsupportedShareBatch,
+    /// A tree needs at least one leaf
+    EmptyTree,
+    /// Leaf index past the end of the tree
+    IndexOutOfRange,
+    /// The incremental ballot tree holds at most 2^TREE_DEPTH - 1 leaves
+    TreeFull,
+    /// Lagrange interpolation needs distinct, non-zero party indices
+    InvalidPartyIndices,
+    /// DKG threshold must satisfy 1 ≤ t ≤ m
+    InvalidThreshold,
+    /// A DKG share does not match its dealer's commitments
+    InvalidShare,
+    /// Decrypted point is not `(v+1)·G` for any `v` in the searched range
+    DiscreteLogNotFound,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Error::InvalidPoint => "invalid compressed secp256k1 point",
+            Error::InvalidScalar => "invalid secp256k1 scalar",
+            Error::MalformedShares => "malformed shares blob",
+            Error::UnsupportedShareBatch => "unsupported share batch version",
+            Error::EmptyTree => "empty leaf set",
+            Error::IndexOutOfRange => "leaf index out of range",
+            Error::TreeFull => "ballot tree is full",
+            Error::InvalidPartyIndices => "party indices must be distinct and non-zero",
+            Error::InvalidThreshold => "threshold must satisfy 1 <= t <= m",
+            Error::InvalidShare => "share does not match commitments",
+            Error::DiscreteLogNotFound => "discrete log not found in range",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,22 @@
+//! Native client library for the Stellot election contract.
+//!
+//! Mirrors the contract's message formats and the web app's cryptography
+//! (`web/src/lib/crypto.ts`, `threshold.ts`, `dkg.ts`, `merkle.ts`) in std Rust, for
+//! services and tools that run without a Node runtime.  The unit tests check
+//! every format against the contract crate itself, so the two cannot drift.
+
+mod error;
+
+pub mod cp;
+pub mod dkg;
+pub mod dlog;
+pub mod elgamal;
+pub mod merkle;
+pub mod msg;
+pub mod nullifier;
+
+#[cfg(test)]
+mod test;
+
+pub use error::Error;
+pub use k256::{ProjectivePoint, Scalar};
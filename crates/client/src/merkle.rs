@@ -0,0 +1,173 @@
+//! SHA-256 Merkle trees matching the contract's `merkle` module.
+//!
+//! Leaf hash:     SHA256("stellot:leaf" || leaf_bytes)
+//! Internal hash: SHA256("stellot:node" || left_32 || right_32)
+//!
+//! Proofs are bottom-up `(sibling, is_right_sibling)` pairs, the format
+//! `issue_account` and `get_ballot_proof` use.
+
+use sha2::{Digest, Sha256};
+
+use crate::Error;
+
+pub type Proof = Vec<([u8; 32], bool)>;
+
+pub fn leaf_hash(leaf: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"stellot:leaf");
+    hasher.update(leaf);
+    hasher.finalize().into()
+}
+
+pub fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"stellot:node");
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Fold `proof` over `leaf` and compare with `root`.
+pub fn verify(root: &[u8; 32], leaf: &[u8], proof: &[([u8; 32], bool)]) -> bool {
+    verify_hashed(root, &leaf_hash(leaf), proof)
+}
+
+pub fn verify_hashed(root: &[u8; 32], leaf: &[u8; 32], proof: &[([u8; 32], bool)]) -> bool {
+    let node = proof.iter().fold(*leaf, |node, (sibling, is_right)| {
+        if *is_right {
+            node_hash(&node, sibling)
+        } else {
+            node_hash(sibling, &node)
+        }
+    });
+    node == *root
+}
+
+// ── Eligibility tree ──────────────────────────────────────────────────────────
+
+/// Static eligibility tree over voter public keys.  Padded to a power of two
+/// by repeating the last leaf hash, as `web/src/lib/merkle.ts` does.
+#[derive(Clone, Debug)]
+pub struct EligibilityTree {
+    /// levels[0] = padded leaf hashes, last level = [root]
+    levels: Vec<Vec<[u8; 32]>>,
+    leaf_count: usize,
+}
+
+impl EligibilityTree {
+    pub fn build<L: AsRef<[u8]>>(leaves: &[L]) -> Result<Self, Error> {
+        let mut layer: Vec<[u8; 32]> = leaves.iter().map(|l| leaf_hash(l.as_ref())).collect();
+        let last = *layer.last().ok_or(Error::EmptyTree)?;
+        layer.resize(layer.len().next_power_of_two(), last);
+
+        let mut levels = vec![layer];
+        while levels.last().unwrap().len() > 1 {
+            let next = levels
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| node_hash(&pair[0], &pair[1]))
+                .collect();
+            levels.push(next);
+        }
+        Ok(Self { levels, leaf_count: leaves.len() })
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.levels.last().unwrap()[0]
+    }
+
+    pub fn prove(&self, index: usize) -> Result<Proof, Error> {
+        if index >= self.leaf_count {
+            return Err(Error::IndexOutOfRange);
+        }
+        let mut idx = index;
+        let proof = self.levels[..self.levels.len() - 1]
+            .iter()
+            .map(|layer| {
+                let is_right = idx & 1 == 0;
+                let sibling = layer[if is_right { idx + 1 } else { idx - 1 }];
+                idx /= 2;
+                (sibling, is_right)
+            })
+            .collect();
+        Ok(proof)
+    }
+}
+
+// ── Incremental ballot tree ───────────────────────────────────────────────────
+
+/// Depth of the contract's ballot tree: up to 2^20 - 1 leaves.
+pub const TREE_DEPTH: usize = 20;
+
+/// Off-chain mirror of the contract's fixed-depth ballot tree, where empty
+/// subtrees hash up from 32 zero bytes.  Leaves are `ballot_leaf` hashes.
+#[derive(Clone, Debug, Default)]
+pub struct BallotTree {
+    leaves: Vec<[u8; 32]>,
+}
+
+/// `leaf_hash(cast_msg)`, the ballot tree's leaf for one accepted ballot.
+pub fn ballot_leaf(cast_msg: &[u8; 32]) -> [u8; 32] {
+    leaf_hash(cast_msg)
+}
+
+impl BallotTree {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Append a `ballot_leaf`.  Revotes append too, as on-chain, and the
+    /// last of the 2^TREE_DEPTH slots stays empty there as well.
+    pub fn push(&mut self, leaf: [u8; 32]) -> Result<(), Error> {
+        if self.leaves.len() >= (1 << TREE_DEPTH) - 1 {
+            return Err(Error::TreeFull);
+        }
+        self.leaves.push(leaf);
+        Ok(())
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        let mut layer = self.leaves.clone();
+        let mut zero = [0u8; 32];
+        for _ in 0..TREE_DEPTH {
+            layer = next_layer(&layer, &zero);
+            zero = node_hash(&zero, &zero);
+        }
+        layer.first().copied().unwrap_or(zero)
+    }
+
+    pub fn prove(&self, index: usize) -> Result<Proof, Error> {
+        if index >= self.leaves.len() {
+            return Err(Error::IndexOutOfRange);
+        }
+        let mut proof = Vec::with_capacity(TREE_DEPTH);
+        let mut layer = self.leaves.clone();
+        let mut zero = [0u8; 32];
+        let mut idx = index;
+        for _ in 0..TREE_DEPTH {
+            let is_right = idx & 1 == 0;
+            let sibling_idx = if is_right { idx + 1 } else { idx - 1 };
+            proof.push((layer.get(sibling_idx).copied().unwrap_or(zero), is_right));
+            layer = next_layer(&layer, &zero);
+            zero = node_hash(&zero, &zero);
+            idx /= 2;
+        }
+        Ok(proof)
+    }
+}
+
+fn next_layer(layer: &[[u8; 32]], zero: &[u8; 32]) -> Vec<[u8; 32]> {
+    layer
+        .chunks(2)
+        .map(|pair| node_hash(&pair[0], pair.get(1).unwrap_or(zero)))
+        .collect()
+}
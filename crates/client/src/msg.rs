@@ -0,0 +1,204 @@
+//! Signed messages and the ballot-board hash chain, byte-for-byte as the
+//! contract builds them.
+
+use sha2::{Digest, Sha256};
+
+use crate::cp::CpProof;
+use crate::Error;
+
+fn sha256(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// `voter_32` of `issue_msg` for an issuance without an eligibility proof.
+pub const NO_VOTER: [u8; 32] = [0u8; 32];
+
+/// msg = SHA256("stellot:issue" || eid_le64 || pk_cast_32 || nf_issue_32 || voter_32)
+///
+/// `voter` is `voter_leaf` of the identity the issuance proves eligible, or
+/// `NO_VOTER`.
+pub fn issue_msg(eid: u64, pk_cast: &[u8; 32], nf_issue: &[u8; 32], voter: &[u8; 32]) -> [u8; 32] {
+    sha256(&[b"stellot:issue", &eid.to_le_bytes(), pk_cast, nf_issue, voter])
+}
+
+/// `voter_32` of `issue_msg`: the `leaf_hash` of the voter in the eligibility
+/// proof (static-tree leaf, registry key or token holder's XDR address).
+pub fn voter_leaf(voter: &[u8]) -> [u8; 32] {
+    crate::merkle::leaf_hash(voter)
+}
+
+/// msg = SHA256("stellot:batch" || eid_le64 || count_le32 || issue_msg_1 || …)
+///
+/// `items` are `(pk_cast, nf_issue, leaf)` in the batch's leaf order; each
+/// `issue_msg` binds `voter_leaf(leaf)`.
+pub fn batch_issue_msg(eid: u64, items: &[([u8; 32], [u8; 32], &[u8])]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"stellot:batch");
+    hasher.update(eid.to_le_bytes());
+    hasher.update((items.len() as u32).to_le_bytes());
+    for (pk_cast, nf_issue, leaf) in items {
+        hasher.update(issue_msg(eid, pk_cast, nf_issue, &voter_leaf(leaf)));
+    }
+    hasher.finalize().into()
+}
+
+/// msg = SHA256("stellot:cast" || eid_le64 || nf_cast_32 || c1 || c2)
+pub fn cast_msg(eid: u64, nf_cast: &[u8; 32], c1: &[u8], c2: &[u8]) -> [u8; 32] {
+    sha256(&[b"stellot:cast", &eid.to_le_bytes(), nf_cast, c1, c2])
+}
+
+/// msg = SHA256("stellot:shares" || eid_le64 || shares_blob)
+pub fn shares_msg(eid: u64, shares_blob: &[u8]) -> [u8; 32] {
+    sha256(&[b"stellot:shares", &eid.to_le_bytes(), shares_blob])
+}
+
+/// msg = SHA256("stellot:withdraw" || eid_le64 || nonce_le32 || shares_hash_32)
+pub fn withdraw_msg(eid: u64, nonce: u32, shares_hash: &[u8; 32]) -> [u8; 32] {
+    sha256(&[b"stellot:withdraw", &eid.to_le_bytes(), &nonce.to_le_bytes(), shares_hash])
+}
+
+/// msg = SHA256("stellot:begin" || eid_le64 || digest_32)
+pub fn shares_begin_msg(eid: u64, digest: &[u8; 32]) -> [u8; 32] {
+    sha256(&[b"stellot:begin", &eid.to_le_bytes(), digest])
+}
+
+/// hash = SHA256("stellot:chunk" || eid_le64 || start_le32 || chunk_blob)
+pub fn shares_chunk_hash(eid: u64, start: u32, chunk_blob: &[u8]) -> [u8; 32] {
+    sha256(&[b"stellot:chunk", &eid.to_le_bytes(), &start.to_le_bytes(), chunk_blob])
+}
+
+/// msg = SHA256("stellot:chunksig" || eid_le64 || digest_32 || chunk_hash_32)
+pub fn shares_chunk_msg(eid: u64, digest: &[u8; 32], chunk_hash: &[u8; 32]) -> [u8; 32] {
+    sha256(&[b"stellot:chunksig", &eid.to_le_bytes(), digest, chunk_hash])
+}
+
+/// Upload chain before any chunk.
+pub const ZERO_CHAIN: [u8; 32] = [0u8; 32];
+
+/// chain' = SHA256("stellot:chunks" || chain_32 || chunk_hash_32)
+pub fn shares_chain_step(chain: &[u8; 32], chunk_digest: &[u8; 32]) -> [u8; 32] {
+    sha256(&[b"stellot:chunks", chain, chunk_digest])
+}
+
+/// Digest a key holder signs in `begin_shares`: the chain over
+/// `(start, chunk_blob)` chunks in upload order.
+pub fn upload_digest(eid: u64, chunks: &[(u32, Vec<u8>)]) -> [u8; 32] {
+    chunks.iter().fold(ZERO_CHAIN, |chain, (start, blob)| {
+        shares_chain_step(&chain, &shares_chunk_hash(eid, *start, blob))
+    })
+}
+
+/// Board head before any ballot.
+pub const ZERO_HEAD: [u8; 32] = [0u8; 32];
+
+/// head' = SHA256("stellot:board" || head_32 || cast_msg_32)
+pub fn board_step(head: &[u8; 32], ballot_digest: &[u8; 32]) -> [u8; 32] {
+    sha256(&[b"stellot:board", head, ballot_digest])
+}
+
+/// Layout version of a share batch blob, its first byte.
+pub const SHARE_BATCH_VERSION: u8 = 1;
+
+/// One entry of a key holder's share batch (`DecryptionShare` on-chain).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DecryptionShare {
+    /// Board index of the ballot
+    pub ballot_index: u32,
+    /// That ballot's C1
+    pub c1: [u8; 33],
+    /// D_j = sk_j·C1
+    pub d: [u8; 33],
+    pub proof: Option<CpProof>,
+}
+
+/// Serialise a share batch to the blob a key holder signs and the contract
+/// stores.  Format: [version u8][share_count u32_le] then per share
+/// [ballot_index u32_le][c1 33][d 33][proof_kind u8], followed by
+/// [r1 33][r2 33][s 32] when proof_kind is 1.
+pub fn serialise_share_batch(shares: &[DecryptionShare]) -> Vec<u8> {
+    let mut out = vec![SHARE_BATCH_VERSION];
+    out.extend_from_slice(&(shares.len() as u32).to_le_bytes());
+    for share in shares {
+        out.extend_from_slice(&share.ballot_index.to_le_bytes());
+        out.extend_from_slice(&share.c1);
+        out.extend_from_slice(&share.d);
+        match &share.proof {
+            None => out.push(0),
+            Some(proof) => {
+                out.push(1);
+                out.extend_from_slice(&proof.r1);
+                out.extend_from_slice(&proof.r2);
+                out.extend_from_slice(&proof.s);
+            }
+        }
+    }
+    out
+}
+
+/// Inverse of `serialise_share_batch`; rejects other layout versions,
+/// truncated blobs and trailing bytes.
+pub fn deserialise_share_batch(blob: &[u8]) -> Result<Vec<DecryptionShare>, Error> {
+    let mut reader = Reader(blob);
+    if reader.take(1)?[0] != SHARE_BATCH_VERSION {
+        return Err(Error::UnsupportedShareBatch);
+    }
+    let count = reader.u32()?;
+    let mut shares = Vec::new();
+    for _ in 0..count {
+        let ballot_index = reader.u32()? as u32;
+        let (c1, d) = (reader.array()?, reader.array()?);
+        let proof = match reader.take(1)?[0] {
+            0 => None,
+            1 => Some(CpProof { r1: reader.array()?, r2: reader.array()?, s: reader.array()? }),
+            _ => return Err(Error::MalformedShares),
+        };
+        shares.push(DecryptionShare { ballot_index, c1, d, proof });
+    }
+    if !reader.0.is_empty() {
+        return Err(Error::MalformedShares);
+    }
+    Ok(shares)
+}
+
+/// Split a share batch into chunk blobs of at most `chunk_size` shares,
+/// each paired with the board index it starts at.
+pub fn share_chunks(shares: &[DecryptionShare], chunk_size: usize) -> Vec<(u32, Vec<u8>)> {
+    shares
+        .chunks(chunk_size.max(1))
+        .map(|chunk| (chunk[0].ballot_index, serialise_share_batch(chunk)))
+        .collect()
+}
+
+/// Reassemble a committed upload from its chunk blobs, in upload order.
+pub fn join_share_chunks(chunks: &[Vec<u8>]) -> Result<Vec<DecryptionShare>, Error> {
+    let mut shares = Vec::new();
+    for blob in chunks {
+        shares.extend(deserialise_share_batch(blob)?);
+    }
+    Ok(shares)
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        if self.0.len() < n {
+            return Err(Error::MalformedShares);
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        Ok(self.take(N)?.try_into().unwrap())
+    }
+
+    fn u32(&mut self) -> Result<usize, Error> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()) as usize)
+    }
+}
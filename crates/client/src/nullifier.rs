@@ -0,0 +1,22 @@
+//! Nullifier derivation.  Deterministic per (secret, election), so a second
+//! issuance or cast by the same key collides on-chain.
+
+use sha2::{Digest, Sha256};
+
+/// nf_issue = SHA256("stellot:issue" || sk_voter_32 || eid_le64)
+pub fn nullifier_issue(sk_voter: &[u8; 32], eid: u64) -> [u8; 32] {
+    derive(b"stellot:issue", sk_voter, eid)
+}
+
+/// nf_cast = SHA256("stellot:cast" || sk_cast_32 || eid_le64)
+pub fn nullifier_cast(sk_cast: &[u8; 32], eid: u64) -> [u8; 32] {
+    derive(b"stellot:cast", sk_cast, eid)
+}
+
+fn derive(domain: &[u8], sk: &[u8; 32], eid: u64) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(domain);
+    hasher.update(sk);
+    hasher.update(eid.to_le_bytes());
+    hasher.finalize().into()
+}
@@ -0,0 +1,532 @@
+#![cfg(test)]
+
+use ed25519_dalek::{Signer, SigningKey};
+use election::types::{
+    Committee, DecryptionShare, DisputePolicy, ElectionConfig, EligibilityMode, EligibilityProof,
+    IssueItem, ShareBatch, ShareProof, StakePolicy,
+};
+use election::{ElectionContract, ElectionContractClient};
+use soroban_sdk::testutils::{Address as _, EnvTestConfig, Ledger, LedgerInfo};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec as SVec};
+
+use crate::cp;
+use crate::dkg;
+use crate::dlog::BsgsTable;
+use crate::elgamal::{self, Ciphertext};
+use crate::merkle::{self, BallotTree, EligibilityTree};
+use crate::msg;
+use crate::nullifier::{nullifier_cast, nullifier_issue};
+use crate::{Error, ProjectivePoint, Scalar};
+
+/// An `Env` that writes no test snapshot: keys and ballot randomness come
+/// from `OsRng`, so a snapshot would change on every run.
+fn test_env() -> Env {
+    Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false })
+}
+
+// ── Conversions between client and contract types ─────────────────────────────
+
+fn bytes(env: &Env, b: &[u8]) -> Bytes {
+    Bytes::from_slice(env, b)
+}
+
+fn bytes_n(env: &Env, b: &[u8; 32]) -> BytesN<32> {
+    BytesN::from_array(env, b)
+}
+
+fn sig(env: &Env, key: &SigningKey, msg: &[u8; 32]) -> BytesN<64> {
+    BytesN::from_array(env, &key.sign(msg).to_bytes())
+}
+
+fn proof_val(env: &Env, proof: &merkle::Proof) -> SVec<(BytesN<32>, bool)> {
+    let mut out = SVec::new(env);
+    for (sibling, is_right) in proof {
+        out.push_back((bytes_n(env, sibling), *is_right));
+    }
+    out
+}
+
+fn share_batch(env: &Env, shares: &[msg::DecryptionShare]) -> ShareBatch {
+    let mut out = SVec::new(env);
+    for share in shares {
+        let proof = match &share.proof {
+            None => ShareProof::None,
+            Some(p) => ShareProof::Cp(election::types::CpProof {
+                r1: bytes(env, &p.r1),
+                r2: bytes(env, &p.r2),
+                s: bytes_n(env, &p.s),
+            }),
+        };
+        out.push_back(DecryptionShare {
+            ballot_index: share.ballot_index,
+            c1: bytes(env, &share.c1),
+            d: bytes(env, &share.d),
+            proof,
+        });
+    }
+    ShareBatch { version: msg::SHARE_BATCH_VERSION as u32, shares: out }
+}
+
+fn set_timestamp(env: &Env, ts: u64) {
+    env.ledger().set(LedgerInfo {
+        timestamp: ts,
+        protocol_version: 22,
+        sequence_number: env.ledger().sequence(),
+        network_id: Default::default(),
+        base_reserve: 10,
+        min_temp_entry_ttl: 10,
+        min_persistent_entry_ttl: 10,
+        max_entry_ttl: 3110400,
+    });
+}
+
+fn seed(n: u8) -> [u8; 32] {
+    let mut raw = [n; 32];
+    raw[31] = n.wrapping_add(1);
+    raw
+}
+
+/// Shamir-split `sk` into `m` shares with threshold `t` (party indices 1..=m).
+fn split(sk: &Scalar, t: usize, m: u32) -> Vec<(u32, Scalar)> {
+    let coeffs: Vec<Scalar> = std::iter::once(*sk)
+        .chain((1..t).map(|_| elgamal::random_scalar()))
+        .collect();
+    (1..=m)
+        .map(|i| {
+            let x = Scalar::from(i as u64);
+            let share = coeffs.iter().rev().fold(Scalar::ZERO, |acc, c| acc * x + c);
+            (i, share)
+        })
+        .collect()
+}
+
+// ── Test 1: Message formats match the contract ───────────────────────────────
+
+#[test]
+fn test_messages_match_contract() {
+    let env = test_env();
+    let eid = 7u64;
+    let (pk, nf) = (seed(1), seed(2));
+    let (c1, c2) = ([3u8; 33], [4u8; 33]);
+
+    let voter = election::voter_leaf(&env, &Some(bytes(&env, b"alice")));
+    assert_eq!(msg::voter_leaf(b"alice"), voter.to_array());
+    assert_eq!(
+        msg::issue_msg(eid, &pk, &nf, &voter.to_array()),
+        election::issue_msg(&env, eid, &bytes_n(&env, &pk), &bytes_n(&env, &nf), &voter).to_array()
+    );
+    assert_eq!(election::voter_leaf(&env, &None).to_array(), msg::NO_VOTER);
+    assert_eq!(
+        msg::cast_msg(eid, &nf, &c1, &c2),
+        election::cast_msg(&env, eid, &bytes_n(&env, &nf), &bytes(&env, &c1), &bytes(&env, &c2))
+            .to_array()
+    );
+
+    let items: [([u8; 32], [u8; 32], &[u8]); 2] = [(seed(5), seed(6), b"alice"), (seed(7), seed(8), b"bob")];
+    let mut contract_items = SVec::new(&env);
+    for (i, (pk_cast, nf_issue, leaf)) in items.iter().enumerate() {
+        contract_items.push_back(IssueItem {
+            pk_cast: bytes_n(&env, pk_cast),
+            nf_issue: bytes_n(&env, nf_issue),
+            leaf: bytes(&env, leaf),
+            leaf_index: i as u32,
+        });
+    }
+    assert_eq!(
+        msg::batch_issue_msg(eid, &items),
+        election::batch_issue_msg(&env, eid, &contract_items).to_array()
+    );
+
+    let proof = cp::CpProof { r1: [5u8; 33], r2: [6u8; 33], s: [7u8; 32] };
+    let shares = vec![
+        msg::DecryptionShare { ballot_index: 0, c1, d: c2, proof: Some(proof) },
+        msg::DecryptionShare { ballot_index: 1, c1: [9u8; 33], d: [10u8; 33], proof: None },
+    ];
+    let blob = msg::serialise_share_batch(&shares);
+    assert_eq!(bytes(&env, &blob), election::serialise_share_batch(&env, &share_batch(&env, &shares)));
+    assert_eq!(
+        msg::shares_msg(eid, &blob),
+        election::shares_msg(&env, eid, &bytes(&env, &blob)).to_array()
+    );
+    assert_eq!(msg::deserialise_share_batch(&blob), Ok(shares.clone()));
+    assert_eq!(msg::deserialise_share_batch(&blob[..blob.len() - 1]), Err(Error::MalformedShares));
+    let mut trailing = blob.clone();
+    trailing.push(0);
+    assert_eq!(msg::deserialise_share_batch(&trailing), Err(Error::MalformedShares));
+    let mut future = blob.clone();
+    future[0] = msg::SHARE_BATCH_VERSION + 1;
+    assert_eq!(msg::deserialise_share_batch(&future), Err(Error::UnsupportedShareBatch));
+
+    let chunks = msg::share_chunks(&shares, 1);
+    assert_eq!(chunks.iter().map(|(start, _)| *start).collect::<Vec<_>>(), [0, 1]);
+    assert_eq!(msg::join_share_chunks(&[chunks[0].1.clone(), chunks[1].1.clone()]), Ok(shares));
+    let mut chain = bytes_n(&env, &msg::ZERO_CHAIN);
+    for (start, blob) in &chunks {
+        let chunk_hash = msg::shares_chunk_hash(eid, *start, blob);
+        assert_eq!(
+            chunk_hash,
+            election::shares_chunk_hash(&env, eid, *start, &bytes(&env, blob)).to_array()
+        );
+        chain = election::shares_chain_step(&env, &chain, &bytes_n(&env, &chunk_hash));
+    }
+    let upload = msg::upload_digest(eid, &chunks);
+    assert_eq!(upload, chain.to_array());
+    let chunk_hash = msg::shares_chunk_hash(eid, 0, &chunks[0].1);
+    assert_eq!(
+        msg::shares_chunk_msg(eid, &upload, &chunk_hash),
+        election::shares_chunk_msg(&env, eid, &chain, &bytes_n(&env, &chunk_hash)).to_array()
+    );
+    assert_eq!(
+        msg::shares_begin_msg(eid, &upload),
+        election::shares_begin_msg(&env, eid, &chain).to_array()
+    );
+    assert_eq!(
+        msg::withdraw_msg(eid, 3, &upload),
+        election::withdraw_msg(&env, eid, 3, &chain).to_array()
+    );
+
+    let digest = msg::cast_msg(eid, &nf, &c1, &c2);
+    assert_eq!(
+        msg::board_step(&msg::ZERO_HEAD, &digest),
+        election::board_step(&env, &bytes_n(&env, &msg::ZERO_HEAD), &bytes_n(&env, &digest))
+            .to_array()
+    );
+}
+
+// ── Test 2: Nullifiers follow the documented derivation ──────────────────────
+
+#[test]
+fn test_nullifiers() {
+    let sk: [u8; 32] = core::array::from_fn(|i| i as u8);
+    // SHA256("stellot:issue" || 00..1f || 07 00..00), as crypto.ts derives it
+    assert_eq!(
+        nullifier_issue(&sk, 7),
+        hex32("5db1d664cc72b3fdb6b9547dbfe78cf8f2bcdeef73d73304d37ec5e2640b89ef")
+    );
+    assert_eq!(
+        nullifier_cast(&sk, 7),
+        hex32("43f9ff74b2693b30fd4bbf61356c7c606ca66e96f4a9a4cee35376593e7fde87")
+    );
+    assert_ne!(nullifier_issue(&sk, 7), nullifier_issue(&sk, 8));
+}
+
+fn hex32(s: &str) -> [u8; 32] {
+    core::array::from_fn(|i| u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap())
+}
+
+// ── Test 3: Merkle trees match the contract ──────────────────────────────────
+
+#[test]
+fn test_merkle_matches_contract() {
+    let env = test_env();
+
+    let voters: Vec<[u8; 32]> = (0..5).map(seed).collect();
+    let tree = EligibilityTree::build(&voters).unwrap();
+    for (i, voter) in voters.iter().enumerate() {
+        let proof = tree.prove(i).unwrap();
+        assert!(merkle::verify(&tree.root(), voter, &proof));
+        assert!(election::merkle::verify(
+            &env,
+            &bytes_n(&env, &tree.root()),
+            &bytes(&env, voter),
+            &proof_val(&env, &proof)
+        ));
+    }
+    assert_eq!(tree.prove(5), Err(Error::IndexOutOfRange));
+    assert_eq!(EligibilityTree::build::<[u8; 32]>(&[]).err(), Some(Error::EmptyTree));
+
+    let mut ballots = BallotTree::new();
+    let mut frontier = election::merkle::empty_frontier(&env);
+    let mut leaves = SVec::new(&env);
+    assert_eq!(ballots.root(), election::merkle::root(&env, &frontier).to_array());
+    for i in 0..6u8 {
+        let leaf = merkle::ballot_leaf(&seed(i));
+        ballots.push(leaf).unwrap();
+        election::merkle::append(&env, &mut frontier, &bytes_n(&env, &leaf));
+        leaves.push_back(bytes_n(&env, &leaf));
+        assert_eq!(ballots.root(), election::merkle::root(&env, &frontier).to_array());
+    }
+    for i in 0..6u32 {
+        let proof = ballots.prove(i as usize).unwrap();
+        assert_eq!(proof_val(&env, &proof), election::merkle::prove(&env, &leaves, i));
+        assert!(merkle::verify_hashed(&ballots.root(), &leaves.get(i).unwrap().to_array(), &proof));
+    }
+}
+
+// ── Test 4: Threshold ElGamal round trip ─────────────────────────────────────
+
+#[test]
+fn test_threshold_elgamal() {
+    let sk = elgamal::random_scalar();
+    let pk = elgamal::public_key(&sk);
+    let shares = split(&sk, 2, 3);
+    assert_eq!(elgamal::reconstruct_secret(&shares[1..]), Ok(sk));
+
+    for v in 0..4u32 {
+        let Ciphertext { c1, c2 } = elgamal::encrypt(v, &pk).unwrap();
+        // Any two of the three key holders suffice
+        for pair in [[0, 1], [0, 2], [1, 2]] {
+            let partials: Vec<(u32, [u8; 33])> = pair
+                .iter()
+                .map(|&p| (shares[p].0, elgamal::partial_decrypt(&c1, &shares[p].1).unwrap()))
+                .collect();
+            let d = elgamal::combine_partials(&partials).unwrap();
+            let m = elgamal::unblind(&c2, &d).unwrap();
+            assert_eq!(elgamal::decode_vote(&m, 4), Ok(v));
+        }
+    }
+
+    let key = elgamal::encode_scalar(&sk);
+    assert_eq!(elgamal::decode_scalar(&key), Ok(sk));
+    assert_eq!(elgamal::decode_scalar(&[0u8; 32]), Err(Error::InvalidScalar));
+    assert_eq!(elgamal::encode_point(&elgamal::decode_point(&pk).unwrap()), pk);
+    assert_eq!(elgamal::decode_point(&[5u8; 33]).err(), Some(Error::InvalidPoint));
+    assert_eq!(elgamal::lagrange_coeff(1, &[1, 1]), Err(Error::InvalidPartyIndices));
+}
+
+// ── Test 5: DKG shares reconstruct the combined key ──────────────────────────
+
+#[test]
+fn test_dkg() {
+    let out = dkg::run(3, 2).unwrap();
+    assert_eq!(out.commitments.len(), 3);
+    assert!(out.commitments.iter().all(|row| row.len() == 2));
+    for pair in [[0usize, 1], [0, 2], [1, 2]] {
+        let subset: Vec<(u32, Scalar)> = pair.iter().map(|&p| out.shares[p]).collect();
+        let sk = elgamal::reconstruct_secret(&subset).unwrap();
+        assert_eq!(elgamal::public_key(&sk), out.combined_pubkey);
+    }
+    // A single share is not enough
+    let sk = elgamal::reconstruct_secret(&out.shares[..1]).unwrap();
+    assert_ne!(elgamal::public_key(&sk), out.combined_pubkey);
+
+    // The contract pins the same matrix and derives the same public shares
+    let env = test_env();
+    let mut matrix = SVec::new(&env);
+    for row in &out.commitments {
+        let mut points = SVec::new(&env);
+        for a in row {
+            points.push_back(bytes(&env, a));
+        }
+        matrix.push_back(points);
+    }
+    assert_eq!(dkg::vss_digest(&out.commitments), election::cp::vss_digest(&env, &matrix).to_array());
+    for i in 1..=3 {
+        assert_eq!(
+            Some(dkg::public_share(&out.commitments, i).unwrap()),
+            election::cp::public_share(&matrix, i)
+        );
+    }
+
+    assert_eq!(dkg::run(2, 3).err(), Some(Error::InvalidThreshold));
+    assert_eq!(dkg::run(2, 0).err(), Some(Error::InvalidThreshold));
+}
+
+// ── Test 6: A full election driven by the client ─────────────────────────────
+
+#[test]
+fn test_full_election_against_contract() {
+    let env = test_env();
+    env.mock_all_auths();
+    set_timestamp(&env, 1000);
+    let contract_id = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract_id);
+
+    // 2-of-3 key holders, one distributor, four eligible voters
+    let sk = elgamal::random_scalar();
+    let kh_shares = split(&sk, 2, 3);
+    let kh_keys: Vec<SigningKey> = (10..13).map(|n| SigningKey::from_bytes(&seed(n))).collect();
+    let dist = SigningKey::from_bytes(&seed(20));
+    let voter_sks: Vec<[u8; 32]> = (30..34).map(seed).collect();
+    let voter_pks: Vec<[u8; 32]> = voter_sks
+        .iter()
+        .map(|s| SigningKey::from_bytes(s).verifying_key().to_bytes())
+        .collect();
+    let eligibility = EligibilityTree::build(&voter_pks).unwrap();
+
+    let mut kh_roster = SVec::new(&env);
+    for k in &kh_keys {
+        kh_roster.push_back(bytes_n(&env, &k.verifying_key().to_bytes()));
+    }
+    let mut dist_roster = SVec::new(&env);
+    dist_roster.push_back(bytes_n(&env, &dist.verifying_key().to_bytes()));
+    let eid = client.deploy(
+        &Address::generate(&env),
+        &bytes(&env, b"Client election"),
+        &3u32,
+        &1010,
+        &1200,
+        &bytes(&env, &elgamal::public_key(&sk)),
+        &bytes_n(&env, &eligibility.root()),
+        &Committee { members: dist_roster, threshold: 1 },
+        &Committee { members: kh_roster, threshold: 2 },
+        &ElectionConfig {
+            revoting: false,
+            max_end_time: 1200,
+            eligibility: EligibilityMode::Static,
+            disputes: DisputePolicy::None,
+            staking: StakePolicy::None,
+        },
+    );
+
+    // Stage 1: the voter's Ed25519 key doubles as the casting key here
+    for (i, (voter_sk, pk_cast)) in voter_sks.iter().zip(&voter_pks).enumerate() {
+        let nf_issue = nullifier_issue(voter_sk, eid);
+        let mut sigs = SVec::new(&env);
+        sigs.push_back((
+            bytes_n(&env, &dist.verifying_key().to_bytes()),
+            sig(&env, &dist, &msg::issue_msg(eid, pk_cast, &nf_issue, &msg::voter_leaf(pk_cast))),
+        ));
+        let proof = EligibilityProof::Merkle(
+            bytes(&env, pk_cast),
+            proof_val(&env, &eligibility.prove(i).unwrap()),
+        );
+        client.issue_account(&eid, &bytes_n(&env, pk_cast), &bytes_n(&env, &nf_issue), &Some(proof), &None, &sigs);
+    }
+
+    // Stage 2
+    set_timestamp(&env, 1015);
+    let votes = [2u32, 0, 2, 1];
+    let mut board = BallotTree::new();
+    let mut head = msg::ZERO_HEAD;
+    for (voter_sk, v) in voter_sks.iter().zip(votes) {
+        let ct = elgamal::encrypt(v, &elgamal::public_key(&sk)).unwrap();
+        let nf_cast = nullifier_cast(voter_sk, eid);
+        let digest = msg::cast_msg(eid, &nf_cast, &ct.c1, &ct.c2);
+        let key = SigningKey::from_bytes(voter_sk);
+        client.cast(
+            &eid,
+            &bytes_n(&env, &nf_cast),
+            &bytes(&env, &ct.c1),
+            &bytes(&env, &ct.c2),
+            &bytes_n(&env, &key.verifying_key().to_bytes()),
+            &sig(&env, &key, &digest),
+        );
+        board.push(merkle::ballot_leaf(&digest)).unwrap();
+        head = msg::board_step(&head, &digest);
+    }
+    assert_eq!(client.get_ballot_root(&eid).to_array(), board.root());
+    assert_eq!(client.get_board_head(&eid).to_array(), head);
+
+    // Stage 3: key holders 1 and 3 decrypt what is on the board
+    set_timestamp(&env, 1300);
+    let ballots = client.get_ballots(&eid, &0, &100);
+    for kh in [0usize, 2] {
+        let shares: Vec<msg::DecryptionShare> = ballots
+            .iter()
+            .enumerate()
+            .map(|(i, b)| {
+                let c1: [u8; 33] = b.c1.iter().collect::<Vec<u8>>().try_into().unwrap();
+                let (d, proof) = cp::prove(&c1, &kh_shares[kh].1).unwrap();
+                msg::DecryptionShare { ballot_index: i as u32, c1, d, proof: Some(proof) }
+            })
+            .collect();
+        let blob = msg::serialise_share_batch(&shares);
+        client.post_share(
+            &eid,
+            &(kh as u32),
+            &share_batch(&env, &shares),
+            &bytes_n(&env, &kh_keys[kh].verifying_key().to_bytes()),
+            &sig(&env, &kh_keys[kh], &msg::shares_msg(eid, &blob)),
+        );
+    }
+
+    // Combine the on-chain shares and count
+    let mut posted = Vec::new();
+    for (kh_idx, blob) in client.get_kh_shares_range(&eid, &0, &3).iter() {
+        let blob: Vec<u8> = blob.iter().collect();
+        posted.push((kh_idx + 1, msg::deserialise_share_batch(&blob).unwrap()));
+    }
+    let mut tally = [0u32; 3];
+    for (i, ballot) in ballots.iter().enumerate() {
+        let partials: Vec<(u32, [u8; 33])> = posted
+            .iter()
+            .map(|(j, shares)| (*j, shares[i].d))
+            .collect();
+        let d = elgamal::combine_partials(&partials).unwrap();
+        let c2: Vec<u8> = ballot.c2.iter().collect();
+        let v = elgamal::decode_vote(&elgamal::unblind(&c2, &d).unwrap(), 3).unwrap();
+        tally[v as usize] += ballot.weight;
+    }
+    assert_eq!(tally, [1, 1, 2]);
+
+    let mut onchain = SVec::new(&env);
+    for count in tally {
+        onchain.push_back(count);
+    }
+    client.finalize_tally(&eid, &onchain);
+    assert_eq!(client.get_tally_record(&eid).unwrap().board_head.to_array(), head);
+}
+
+// ── Test 7: CP proofs against Feldman public shares ──────────────────────────
+
+#[test]
+fn test_cp_proofs() {
+    let out = dkg::run(3, 2).unwrap();
+    let ct = elgamal::encrypt(1, &out.combined_pubkey).unwrap();
+    for (i, sk_i) in &out.shares {
+        let pk_i = dkg::public_share(&out.commitments, *i).unwrap();
+        assert_eq!(elgamal::encode_point(&pk_i), elgamal::public_key(sk_i));
+
+        let (d, proof) = cp::prove(&ct.c1, sk_i).unwrap();
+        assert_eq!(d, elgamal::partial_decrypt(&ct.c1, sk_i).unwrap());
+        assert_eq!(cp::verify(&pk_i, &ct.c1, &d, &proof), Ok(true));
+
+        // Wrong share, wrong key holder, or a proof for another ciphertext
+        let wrong = elgamal::partial_decrypt(&ct.c2, sk_i).unwrap();
+        assert_eq!(cp::verify(&pk_i, &ct.c1, &wrong, &proof), Ok(false));
+        let other = dkg::public_share(&out.commitments, i % 3 + 1).unwrap();
+        assert_eq!(cp::verify(&other, &ct.c1, &d, &proof), Ok(false));
+        assert_eq!(cp::verify(&pk_i, &ct.c2, &d, &proof), Ok(false));
+    }
+}
+
+// ── Test 8: Baby-step giant-step recovers large aggregates ───────────────────
+
+#[test]
+fn test_bsgs() {
+    let g = |x: u64| ProjectivePoint::GENERATOR * Scalar::from(x);
+
+    // Window edges of a small table: centre ± m, negative candidates, bounds
+    let table = BsgsTable::new(100);
+    assert_eq!((table.baby_steps(), table.memory_bytes(), table.giant_steps(1000)), (100, 1200, 6));
+    for x in [0, 1, 99, 100, 101, 200, 201, 301, 302, 1000] {
+        assert_eq!(table.solve(&g(x), 1000), Ok(x), "x = {x}");
+    }
+    assert_eq!(table.solve(&g(1001), 1000), Err(Error::DiscreteLogNotFound));
+    assert_eq!(table.solve(&-g(5), 1000), Err(Error::DiscreteLogNotFound));
+
+    // Threshold-decrypted option aggregates, all solved in one pass
+    let table = BsgsTable::new(1 << 16);
+    let sk = elgamal::random_scalar();
+    let pk = elgamal::public_key(&sk);
+    let counts = [1_000_000u32, 0, 123_456_789];
+    let aggregates: Vec<ProjectivePoint> = counts
+        .iter()
+        .map(|&n| {
+            let Ciphertext { c1, c2 } = elgamal::encrypt(n, &pk).unwrap();
+            let d = elgamal::decode_point(&elgamal::partial_decrypt(&c1, &sk).unwrap()).unwrap();
+            elgamal::unblind(&c2, &d).unwrap()
+        })
+        .collect();
+    let solved = table.solve_all(&aggregates, 1 << 27).unwrap();
+    assert_eq!(solved, counts.iter().map(|&n| n as u64 + 1).collect::<Vec<_>>());
+    assert_eq!(table.solve(&g(u32::MAX as u64), u32::MAX as u64), Ok(u32::MAX as u64));
+
+    // Persisted tables load back; foreign or truncated files are rebuilt
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("bsgs.bin");
+    let small = BsgsTable::load_or_build(&path, 100).unwrap();
+    assert_eq!(BsgsTable::load(&path).unwrap(), small);
+    assert_eq!(std::fs::metadata(&path).unwrap().len(), 12 + 1200);
+    let mut bytes = std::fs::read(&path).unwrap();
+    bytes.truncate(500);
+    assert_eq!(BsgsTable::read_from(&bytes[..]).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    // A header claiming more entries than any table holds is refused up front
+    let mut huge = bytes[..12].to_vec();
+    huge[8..].copy_from_slice(&u32::MAX.to_le_bytes());
+    assert_eq!(BsgsTable::read_from(&huge[..]).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    std::fs::write(&path, &bytes).unwrap();
+    assert_eq!(BsgsTable::load_or_build(&path, 100).unwrap(), small);
+    assert_eq!(BsgsTable::load_or_build(&path, 50).unwrap().baby_steps(), 50);
+}
@@ -0,0 +1,409 @@
+//! The audit: each check re-derives its result from the snapshot alone,
+//! using the client crate's copies of the contract's formats.
+
+use std::collections::{HashMap, HashSet};
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use stellot_client::merkle::{ballot_leaf, BallotTree};
+use stellot_client::{cp, dkg, elgamal, msg, ProjectivePoint};
+
+use crate::hex::Hex;
+use crate::report::{Check, Report};
+use crate::snapshot::{Account, ShareBatch, Snapshot, UploadSigs};
+
+/// A share batch that decoded and matched the board: the key holder's
+/// 1-based party index and `D_j` per ballot.
+type Partials = (u32, Vec<[u8; 33]>);
+
+/// Run every check over `s`.
+pub fn verify(s: &Snapshot) -> Report {
+    let (batches, usable) = check_share_batches(s);
+    Report::new(
+        s.eid,
+        vec![
+            check_keys(s),
+            check_issue_signatures(s),
+            check_nullifiers(s),
+            check_ballot_accounts(s),
+            check_cast_signatures(s),
+            check_board(s),
+            check_share_signatures(s),
+            batches,
+            check_share_proofs(s),
+            check_lagrange(s, &usable),
+            check_tally(s, &usable),
+        ],
+    )
+}
+
+/// Thresholds, and the combined key against the KH commitments.
+fn check_keys(s: &Snapshot) -> Check {
+    let mut c = Check::new("keys");
+    for (name, committee) in [("distributor", &s.dist), ("key-holder", &s.kh)] {
+        let (n, t) = (committee.members.len(), committee.threshold as usize);
+        c.expect(t >= 1 && t <= n, || format!("{name} threshold {t} of {n}"));
+    }
+    c.expect(s.kh_commitments.len() == s.kh.members.len(), || {
+        format!("{} KH commitments for {} key holders", s.kh_commitments.len(), s.kh.members.len())
+    });
+
+    if let Some(vss) = &s.vss_commitments {
+        for (j, row) in vss.iter().enumerate() {
+            c.expect(row.len() == s.kh.threshold as usize, || {
+                format!("dealer {j} committed to {} coefficients, threshold {}", row.len(), s.kh.threshold)
+            });
+            let on_chain = s.kh_commitments.get(j).copied().flatten();
+            c.expect(on_chain.is_none() || row.first() == on_chain.as_ref(), || {
+                format!("dealer {j}: A_j0 differs from the on-chain commitment")
+            });
+        }
+        c.expect(vss.len() == s.kh.members.len(), || {
+            format!("{} VSS dealers for {} key holders", vss.len(), s.kh.members.len())
+        });
+    }
+
+    let unset = s.kh_commitments.iter().filter(|a| a.is_none()).count();
+    if unset > 0 {
+        return c.note(format!("{unset} KH commitments not set; combined key not checked"));
+    }
+    let combined = s.kh_commitments.iter().flatten().try_fold(ProjectivePoint::IDENTITY, |acc, a| {
+        elgamal::decode_point(&a.0).map(|p| acc + p)
+    });
+    let expected = elgamal::decode_point(&s.enc_pubkey.0);
+    c.expect(matches!((combined, expected), (Ok(a), Ok(b)) if a == b), || {
+        "enc_pubkey is not the sum of the KH commitments".into()
+    });
+    c
+}
+
+/// Each issued account carries a threshold of distinct distributor
+/// signatures over `issue_msg`.
+fn check_issue_signatures(s: &Snapshot) -> Check {
+    let mut c = Check::new("issue_signatures");
+    let mut unsigned = 0;
+    for (i, account) in s.accounts.iter().enumerate() {
+        let (false, Some(voter)) = (account.dist_sigs.is_empty(), account.voter) else {
+            unsigned += 1;
+            continue;
+        };
+        let digest = msg::issue_msg(s.eid, &account.pk_cast.0, &account.nf_issue.0, &voter.0);
+        let signers: HashSet<&Hex<32>> = account
+            .dist_sigs
+            .iter()
+            .filter(|(pk, sig)| s.dist.members.contains(pk) && verify_sig(pk, &digest, sig))
+            .map(|(pk, _)| pk)
+            .collect();
+        c.expect(signers.len() >= s.dist.threshold as usize, || {
+            format!("account {i}: {} valid distributor signatures, threshold {}", signers.len(), s.dist.threshold)
+        });
+    }
+    c.missing(unsigned, "accounts without distributor signatures or voter")
+}
+
+/// No issue or cast nullifier is used twice, and every ballot's nullifier
+/// was consumed.
+fn check_nullifiers(s: &Snapshot) -> Check {
+    let mut c = Check::new("nullifiers");
+    let mut issued = HashSet::new();
+    for (i, account) in s.accounts.iter().enumerate() {
+        c.expect(issued.insert(account.nf_issue), || format!("account {i}: issue nullifier reused"));
+    }
+    let mut consumed = HashSet::new();
+    for (n, nf) in s.cast_nullifiers.iter().enumerate() {
+        c.expect(consumed.insert(*nf), || format!("cast {n}: cast nullifier reused"));
+    }
+    let mut on_board = HashSet::new();
+    for (i, ballot) in s.ballots.iter().enumerate() {
+        c.expect(on_board.insert(ballot.nf_cast), || format!("ballot {i}: nullifier shared with another ballot"));
+        c.expect(consumed.contains(&ballot.nf_cast), || format!("ballot {i}: nullifier was never consumed"));
+    }
+    c
+}
+
+/// Exactly one casting account per ballot, with the ballot's weight.
+fn check_ballot_accounts(s: &Snapshot) -> Check {
+    let mut c = Check::new("ballot_accounts");
+    let mut by_index: HashMap<u32, &Account> = HashMap::new();
+    for (i, account) in s.accounts.iter().enumerate() {
+        if let Some(index) = account.ballot_index {
+            c.expect((index as usize) < s.ballots.len(), || format!("account {i}: ballot {index} does not exist"));
+            c.expect(by_index.insert(index, account).is_none(), || format!("ballot {index}: claimed twice"));
+        }
+    }
+    for (i, ballot) in s.ballots.iter().enumerate() {
+        let weight = by_index.get(&(i as u32)).map(|a| a.weight);
+        c.expect(weight == Some(ballot.weight), || match weight {
+            Some(w) => format!("ballot {i}: weight {} but account weight {w}", ballot.weight),
+            None => format!("ballot {i}: no casting account"),
+        });
+    }
+    c
+}
+
+/// Each ballot is signed by its casting account over `cast_msg`.
+fn check_cast_signatures(s: &Snapshot) -> Check {
+    let mut c = Check::new("cast_signatures");
+    let mut unsigned = 0;
+    for (i, ballot) in s.ballots.iter().enumerate() {
+        let Some(sig) = &ballot.sig else {
+            unsigned += 1;
+            continue;
+        };
+        let account = s.accounts.iter().find(|a| a.ballot_index == Some(i as u32));
+        let digest = msg::cast_msg(s.eid, &ballot.nf_cast.0, &ballot.c1.0, &ballot.c2.0);
+        c.expect(account.is_some_and(|a| verify_sig(&a.pk_cast, &digest, sig)), || {
+            format!("ballot {i}: signature does not verify")
+        });
+    }
+    c.missing(unsigned, "ballots without a cast signature")
+}
+
+/// The board hash chain and ballot tree rebuilt from the cast digests match
+/// the tally record, and every final ballot is on the board.
+fn check_board(s: &Snapshot) -> Check {
+    let digests: Vec<[u8; 32]> = s
+        .ballots
+        .iter()
+        .map(|b| msg::cast_msg(s.eid, &b.nf_cast.0, &b.c1.0, &b.c2.0))
+        .collect();
+    let board: Vec<[u8; 32]> = match &s.board {
+        Some(board) => board.iter().map(|d| d.0).collect(),
+        None if s.cast_nullifiers.len() == s.ballots.len() => digests.clone(),
+        None => return Check::skipped("board", "revotes occurred but the snapshot has no board log"),
+    };
+
+    let mut c = Check::new("board");
+    c.expect(board.len() == s.cast_nullifiers.len(), || {
+        format!("{} board entries for {} casts", board.len(), s.cast_nullifiers.len())
+    });
+    let entries: HashSet<&[u8; 32]> = board.iter().collect();
+    for (i, digest) in digests.iter().enumerate() {
+        c.expect(entries.contains(digest), || format!("ballot {i}: not on the board"));
+    }
+
+    let mut head = msg::ZERO_HEAD;
+    let mut tree = BallotTree::new();
+    for digest in &board {
+        head = msg::board_step(&head, digest);
+        if tree.push(ballot_leaf(digest)).is_err() {
+            c.expect(false, || "board exceeds the ballot tree".into());
+            return c;
+        }
+    }
+    let Some(tally) = &s.tally else {
+        return c.note("not tallied; final head and root not compared");
+    };
+    c.expect(tally.board_head.0 == head, || "board head differs from the tally record".into());
+    c.expect(tally.ballot_root.0 == tree.root(), || "ballot root differs from the tally record".into());
+    c.expect(tally.ballot_count as usize == s.ballots.len(), || {
+        format!("tally record counts {} ballots, board has {}", tally.ballot_count, s.ballots.len())
+    });
+    c
+}
+
+/// Each share batch is signed by its roster key holder over `shares_msg`,
+/// or, for a chunked upload, chunk by chunk with a begin signature over the
+/// upload digest.
+fn check_share_signatures(s: &Snapshot) -> Check {
+    let mut c = Check::new("share_signatures");
+    let mut unsigned = 0;
+    for batch in &s.shares {
+        let Some(pk) = s.kh.members.get(batch.kh_idx as usize) else {
+            c.expect(false, || format!("KH {}: not on the roster", batch.kh_idx));
+            continue;
+        };
+        match (&batch.sig, &batch.upload) {
+            (Some(sig), _) => {
+                let digest = msg::shares_msg(s.eid, &batch.blob.0);
+                c.expect(verify_sig(pk, &digest, sig), || {
+                    format!("KH {}: signature does not verify", batch.kh_idx)
+                });
+            }
+            (None, Some(upload)) => {
+                let signed = upload_signed(s.eid, pk, &batch.blob.0, upload);
+                c.expect(signed.is_ok(), || format!("KH {}: {}", batch.kh_idx, signed.unwrap_err()));
+            }
+            (None, None) => unsigned += 1,
+        }
+    }
+    c.missing(unsigned, "share batches without a KH signature")
+}
+
+/// Re-split a committed blob at the upload's chunk starts, check the begin
+/// signature over the chained digest and each chunk's signature under it.
+fn upload_signed(eid: u64, pk: &Hex<32>, blob: &[u8], upload: &UploadSigs) -> Result<(), String> {
+    let shares = msg::deserialise_share_batch(blob).map_err(|_| "blob does not decode".to_string())?;
+    let mut chunks = Vec::with_capacity(upload.chunks.len());
+    let mut covered = 0;
+    for (i, (start, _)) in upload.chunks.iter().enumerate() {
+        let end = upload.chunks.get(i + 1).map_or(u32::MAX, |(next, _)| *next);
+        let part: Vec<_> = shares.iter().filter(|sh| (*start..end).contains(&sh.ballot_index)).cloned().collect();
+        covered += part.len();
+        chunks.push((*start, msg::serialise_share_batch(&part)));
+    }
+    if covered != shares.len() {
+        return Err("chunks do not cover the blob".into());
+    }
+    let digest = msg::upload_digest(eid, &chunks);
+    if !verify_sig(pk, &msg::shares_begin_msg(eid, &digest), &upload.begin_sig) {
+        return Err("begin signature does not cover the chunks".into());
+    }
+    for ((start, chunk), (_, sig)) in chunks.iter().zip(&upload.chunks) {
+        let chunk_msg = msg::shares_chunk_msg(eid, &digest, &msg::shares_chunk_hash(eid, *start, chunk));
+        if !verify_sig(pk, &chunk_msg, sig) {
+            return Err(format!("chunk at {start}: signature does not verify"));
+        }
+    }
+    Ok(())
+}
+
+/// Each batch decodes, comes from a distinct roster slot and has one share
+/// per ballot, in board order, for that ballot's C1.  Returns the batches that passed.
+fn check_share_batches(s: &Snapshot) -> (Check, Vec<Partials>) {
+    let mut c = Check::new("share_batches");
+    let mut seen = HashSet::new();
+    let mut usable = Vec::new();
+    for batch in &s.shares {
+        let k = batch.kh_idx;
+        let fresh = seen.insert(k);
+        c.expect(fresh, || format!("KH {k}: posted twice"));
+        c.expect((k as usize) < s.kh.members.len(), || format!("KH {k}: outside the roster"));
+        match decode_batch(s, batch) {
+            Ok(partials) if fresh && (k as usize) < s.kh.members.len() => usable.push((k + 1, partials)),
+            Ok(_) => {}
+            Err(e) => c.expect(false, || e),
+        }
+    }
+    (c, usable)
+}
+
+fn decode_batch(s: &Snapshot, batch: &ShareBatch) -> Result<Vec<[u8; 33]>, String> {
+    let k = batch.kh_idx;
+    let shares = msg::deserialise_share_batch(&batch.blob.0).map_err(|e| format!("KH {k}: {e}"))?;
+    if shares.len() != s.ballots.len() {
+        return Err(format!("KH {k}: {} shares for {} ballots", shares.len(), s.ballots.len()));
+    }
+    shares
+        .iter()
+        .zip(&s.ballots)
+        .enumerate()
+        .map(|(i, (share, ballot))| {
+            if share.ballot_index != i as u32 {
+                return Err(format!("KH {k}: share {i} is for ballot {}", share.ballot_index));
+            }
+            if share.c1 != ballot.c1.0 {
+                return Err(format!("KH {k}: share {i} is for a different C1"));
+            }
+            Ok(share.d)
+        })
+        .collect()
+}
+
+/// Each share's CP proof against the key holder's Feldman public share.
+/// Proofs carried in the blob take precedence over the snapshot's `proofs`.
+fn check_share_proofs(s: &Snapshot) -> Check {
+    let Some(vss) = &s.vss_commitments else {
+        return Check::skipped("share_proofs", "no VSS commitments to derive public shares from");
+    };
+    let vss: Vec<Vec<[u8; 33]>> = vss.iter().map(|row| row.iter().map(|a| a.0).collect()).collect();
+
+    let mut c = Check::new("share_proofs");
+    let mut unproven = 0;
+    for batch in &s.shares {
+        let k = batch.kh_idx;
+        // Undecodable blobs are reported by `share_batches`
+        let Ok(shares) = msg::deserialise_share_batch(&batch.blob.0) else {
+            continue;
+        };
+        let sidecar = batch.proofs.as_ref();
+        if sidecar.is_none() && shares.iter().all(|share| share.proof.is_none()) {
+            unproven += 1;
+            continue;
+        }
+        let pk_j = match dkg::public_share(&vss, k + 1) {
+            Ok(pk) => pk,
+            Err(e) => {
+                c.expect(false, || format!("VSS commitments: {e}"));
+                return c;
+            }
+        };
+        if let Some(proofs) = sidecar {
+            c.expect(proofs.len() == shares.len(), || format!("KH {k}: {} proofs for {} shares", proofs.len(), shares.len()));
+        }
+        for (i, share) in shares.iter().enumerate() {
+            let sidecar_proof = || sidecar?.get(i).map(|p| cp::CpProof { r1: p.r1.0, r2: p.r2.0, s: p.s.0 });
+            let Some(proof) = share.proof.clone().or_else(sidecar_proof) else {
+                c.expect(false, || format!("KH {k}: share {i} has no proof"));
+                continue;
+            };
+            c.expect(cp::verify(&pk_j, &share.c1, &share.d, &proof) == Ok(true), || {
+                format!("KH {k}: proof {i} does not verify")
+            });
+        }
+    }
+    c.missing(unproven, "share batches without CP proofs")
+}
+
+/// Every window of `t` consecutive valid batches combines to the same
+/// `D = sk·C1` for each ballot, so no single key holder skews the result.
+fn check_lagrange(s: &Snapshot, usable: &[Partials]) -> Check {
+    let t = s.kh.threshold as usize;
+    if t == 0 || usable.len() <= t {
+        return Check::skipped("lagrange", format!("{} valid batches, threshold {t}: no second subset", usable.len()));
+    }
+    let mut c = Check::new("lagrange");
+    for (i, _) in s.ballots.iter().enumerate() {
+        let reference = combine(&usable[..t], i);
+        for window in usable.windows(t).skip(1) {
+            let indices: Vec<u32> = window.iter().map(|(j, _)| *j).collect();
+            let d = combine(window, i);
+            c.expect(reference.is_ok() && d == reference, || {
+                format!("ballot {i}: key holders {indices:?} combine to a different D")
+            });
+        }
+    }
+    c
+}
+
+/// Decrypting every ballot with `t` valid batches reproduces `Tally(eid)`.
+fn check_tally(s: &Snapshot, usable: &[Partials]) -> Check {
+    let Some(record) = &s.tally else {
+        return Check::skipped("tally", "election not tallied");
+    };
+    let mut c = Check::new("tally");
+    c.expect(record.share_count as usize == s.shares.len(), || {
+        format!("tally record counts {} share batches, snapshot has {}", record.share_count, s.shares.len())
+    });
+    c.expect(record.tally.len() == s.options_count as usize, || {
+        format!("{} counts for {} options", record.tally.len(), s.options_count)
+    });
+    let t = s.kh.threshold as usize;
+    if t == 0 || usable.len() < t {
+        c.expect(false, || format!("{} valid share batches, threshold {t}", usable.len()));
+        return c;
+    }
+
+    let mut counts = vec![0u64; s.options_count as usize];
+    for (i, ballot) in s.ballots.iter().enumerate() {
+        let vote = combine(&usable[..t], i)
+            .and_then(|d| elgamal::unblind(&ballot.c2.0, &d))
+            .and_then(|m| elgamal::decode_vote(&m, s.options_count));
+        match vote {
+            Ok(v) => counts[v as usize] += ballot.weight as u64,
+            Err(e) => c.expect(false, || format!("ballot {i}: {e}")),
+        }
+    }
+    let recorded: Vec<u64> = record.tally.iter().map(|&n| n as u64).collect();
+    c.expect(counts == recorded, || format!("decrypted {counts:?}, recorded {recorded:?}"));
+    c
+}
+
+fn combine(batches: &[Partials], ballot: usize) -> Result<ProjectivePoint, stellot_client::Error> {
+    let partials: Vec<(u32, [u8; 33])> = batches.iter().map(|(j, d)| (*j, d[ballot])).collect();
+    elgamal::combine_partials(&partials)
+}
+
+fn verify_sig(pk: &Hex<32>, digest: &[u8; 32], sig: &Hex<64>) -> bool {
+    VerifyingKey::from_bytes(&pk.0)
+        .is_ok_and(|pk| pk.verify(digest, &Signature::from_bytes(&sig.0)).is_ok())
+}
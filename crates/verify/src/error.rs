@@ -0,0 +1,39 @@
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    Json(serde_json::Error),
+    Cbor(String),
+    /// The export was written by an incompatible format version
+    UnsupportedVersion(u32),
+    /// The snapshot does not hash to the export's `content_hash`
+    ContentHash,
+    /// The event stream is incomplete or out of order
+    Events(String),
+    /// A share blob was unavailable or does not match its `share` event
+    ShareBlob(u32),
+    /// The snapshot lacks data the importer needs
+    Incomplete(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Json(e) => write!(f, "invalid JSON: {e}"),
+            Error::Cbor(e) => write!(f, "invalid CBOR: {e}"),
+            Error::UnsupportedVersion(v) => write!(f, "unsupported snapshot version {v}"),
+            Error::ContentHash => f.write_str("content hash does not match the snapshot"),
+            Error::Events(e) => write!(f, "event stream: {e}"),
+            Error::ShareBlob(k) => write!(f, "share blob of KH {k} missing or does not match its digest"),
+            Error::Incomplete(what) => write!(f, "snapshot has no {what}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Json(e)
+    }
+}
@@ -0,0 +1,92 @@
+//! Versioned, deterministic on-disk form of a `Snapshot`.
+//!
+//! An export wraps the snapshot with the format version and a content hash,
+//! `SHA256(CBOR(snapshot))`.  Field order is fixed by the struct definitions
+//! and every collection is a list, so the same election always encodes to the
+//! same bytes and the hash is the same whichever encoding carries it.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::hex::Hex;
+use crate::snapshot::Snapshot;
+use crate::Error;
+
+/// Current export format version.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Pretty-printed JSON, bytes as hex
+    Json,
+    /// CBOR, bytes as byte strings
+    Cbor,
+}
+
+impl Format {
+    /// CBOR for a `.cbor` extension, JSON otherwise.
+    pub fn from_path(path: &Path) -> Format {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("cbor") => Format::Cbor,
+            _ => Format::Json,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Export {
+    pub version: u32,
+    pub content_hash: Hex<32>,
+    pub snapshot: Snapshot,
+}
+
+/// Read ahead of the snapshot so that a future version is reported as such
+/// rather than as a parse error.
+#[derive(Deserialize)]
+struct Header {
+    version: u32,
+}
+
+pub fn content_hash(snapshot: &Snapshot) -> [u8; 32] {
+    Sha256::digest(to_cbor(snapshot)).into()
+}
+
+pub fn export(snapshot: &Snapshot, format: Format) -> Vec<u8> {
+    let export = Export {
+        version: SNAPSHOT_VERSION,
+        content_hash: Hex(content_hash(snapshot)),
+        snapshot: snapshot.clone(),
+    };
+    match format {
+        Format::Json => serde_json::to_vec_pretty(&export).expect("snapshot serialises"),
+        Format::Cbor => to_cbor(&export),
+    }
+}
+
+/// Decode an export, checking its version and content hash.
+pub fn import(bytes: &[u8], format: Format) -> Result<Snapshot, Error> {
+    let header: Header = decode(bytes, format)?;
+    if header.version != SNAPSHOT_VERSION {
+        return Err(Error::UnsupportedVersion(header.version));
+    }
+    let export: Export = decode(bytes, format)?;
+    if export.content_hash.0 != content_hash(&export.snapshot) {
+        return Err(Error::ContentHash);
+    }
+    Ok(export.snapshot)
+}
+
+fn decode<T: for<'de> Deserialize<'de>>(bytes: &[u8], format: Format) -> Result<T, Error> {
+    match format {
+        Format::Json => Ok(serde_json::from_slice(bytes)?),
+        Format::Cbor => ciborium::from_reader(bytes).map_err(|e| Error::Cbor(e.to_string())),
+    }
+}
+
+fn to_cbor<T: Serialize>(value: &T) -> Vec<u8> {
+    let mut out = Vec::new();
+    ciborium::into_writer(value, &mut out).expect("snapshot serialises");
+    out
+}
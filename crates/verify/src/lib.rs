@@ -0,0 +1,22 @@
+//! Universal verifiability for Stellot elections: re-checks an exported
+//! election snapshot without trusting the organizer, the distributors or
+//! the key holders, and reports each check as pass, fail or skip.
+
+mod checks;
+mod error;
+
+pub mod format;
+pub mod hex;
+pub mod report;
+pub mod snapshot;
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
+
+#[cfg(test)]
+mod test;
+
+pub use checks::verify;
+pub use error::Error;
+pub use format::{export, import, Format};
+pub use report::{Check, Report, Status};
+pub use snapshot::Snapshot;
@@ -0,0 +1,46 @@
+//! `stellot-verify SNAPSHOT [--output REPORT]` — audit an exported election
+//! (JSON, or CBOR for a `.cbor` file).  Prints the JSON report and exits 0 if every check passed or was skipped,
+//! 1 if any failed and 2 if the snapshot could not be read.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use clap::Parser;
+
+use stellot_verify::{import, verify, Format};
+
+#[derive(Parser)]
+#[command(name = "stellot-verify", version, about = "Audit an exported Stellot election")]
+struct Cli {
+    /// Snapshot export
+    snapshot: PathBuf,
+    /// Also write the report here
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let snapshot = match fs::read(&cli.snapshot)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| import(&bytes, Format::from_path(&cli.snapshot)).map_err(|e| e.to_string()))
+    {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            eprintln!("error: {}: {e}", cli.snapshot.display());
+            process::exit(2);
+        }
+    };
+
+    let report = verify(&snapshot);
+    let json = serde_json::to_string_pretty(&report).expect("report serialises");
+    println!("{json}");
+    if let Some(path) = &cli.output {
+        if let Err(e) = fs::write(path, &json) {
+            eprintln!("error: {}: {e}", path.display());
+            process::exit(2);
+        }
+    }
+    process::exit(if report.pass { 0 } else { 1 });
+}
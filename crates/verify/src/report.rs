@@ -0,0 +1,80 @@
+//! Machine-readable audit result.
+
+use serde::Serialize;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub eid: u64,
+    /// `true` iff no check failed (skipped checks do not fail the report)
+    pub pass: bool,
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    pub fn new(eid: u64, checks: Vec<Check>) -> Self {
+        let pass = checks.iter().all(|c| c.status != Status::Fail);
+        Report { eid, pass, checks }
+    }
+
+    pub fn check(&self, name: &str) -> Option<&Check> {
+        self.checks.iter().find(|c| c.name == name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail,
+    /// The snapshot lacks the data this check needs
+    Skip,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Check {
+    pub name: &'static str,
+    pub status: Status,
+    /// Number of individual items verified
+    pub checked: usize,
+    pub failures: Vec<String>,
+    /// What was not checked, and why
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl Check {
+    pub(crate) fn new(name: &'static str) -> Self {
+        Check { name, status: Status::Pass, checked: 0, failures: Vec::new(), note: None }
+    }
+
+    pub(crate) fn skipped(name: &'static str, note: impl Into<String>) -> Self {
+        Check { status: Status::Skip, note: Some(note.into()), ..Check::new(name) }
+    }
+
+    /// Record one item; `failure` describes it if `ok` is false.
+    pub(crate) fn expect(&mut self, ok: bool, failure: impl FnOnce() -> String) {
+        self.checked += 1;
+        if !ok {
+            self.failures.push(failure());
+            self.status = Status::Fail;
+        }
+    }
+
+    pub(crate) fn note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    /// Note `count` items that lacked the data to be checked; a check that
+    /// verified nothing at all becomes a skip.
+    pub(crate) fn missing(self, count: usize, what: &str) -> Self {
+        if count == 0 {
+            return self;
+        }
+        let mut check = self.note(format!("{count} {what}"));
+        if check.checked == 0 {
+            check.status = Status::Skip;
+        }
+        check
+    }
+}
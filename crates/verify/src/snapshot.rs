@@ -0,0 +1,175 @@
+//! Exported election state, as the auditor reads it.
+//!
+//! Most fields mirror contract storage.  Signatures, and CP proofs for
+//! share batches posted without them, are not kept on-chain; an exporter
+//! that has them (from transaction history or the key holders' files) fills
+//! them in, and the checks that need them are skipped where they are absent.
+
+use serde::{Deserialize, Serialize};
+
+use crate::hex::{Hex, HexBytes};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub eid: u64,
+    /// Organizer address as a strkey
+    pub organizer: String,
+    pub title: HexBytes,
+    pub options_count: u32,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// Combined KH public key (`ElectionParams::enc_pubkey`)
+    pub enc_pubkey: Hex<33>,
+    pub status: ElectionStatus,
+    pub config: Config,
+    /// Current eligibility root
+    pub eligibility_root: Hex<32>,
+    pub dist: Committee,
+    pub kh: Committee,
+    /// On-chain `A_j0` per KH roster slot; `None` where never set
+    pub kh_commitments: Vec<Option<Hex<33>>>,
+    /// Full Feldman matrix `A_jk` from the DKG transcript, needed to check
+    /// CP proofs
+    #[serde(default)]
+    pub vss_commitments: Option<Vec<Vec<Hex<33>>>>,
+    /// Casting accounts in issuance order
+    pub accounts: Vec<Account>,
+    /// Every consumed cast nullifier in casting order, revotes included
+    pub cast_nullifiers: Vec<Hex<32>>,
+    /// `cast_msg` digest of every accepted cast, in order (the `cast` and
+    /// `recast` events).  May be omitted when nobody revoted: the board is
+    /// then the ballots themselves.
+    #[serde(default)]
+    pub board: Option<Vec<Hex<32>>>,
+    /// Final ballots in board order
+    pub ballots: Vec<Ballot>,
+    /// Posted KH share batches in roster order
+    pub shares: Vec<ShareBatch>,
+    /// `Tally(eid)`, once finalized
+    pub tally: Option<Tally>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ElectionStatus {
+    Active,
+    Paused,
+    Cancelled,
+}
+
+/// `ElectionConfig`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Config {
+    pub revoting: bool,
+    pub max_end_time: u64,
+    pub eligibility: Eligibility,
+    /// Dispute window, if the election has one
+    #[serde(default)]
+    pub disputes: Option<Disputes>,
+    /// Key-holder bond, if the election has one
+    #[serde(default)]
+    pub staking: Option<Staking>,
+}
+
+/// `DisputeWindow`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Disputes {
+    pub secs: u64,
+    pub vss_digest: Hex<32>,
+}
+
+/// `KhBond`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Staking {
+    pub token: String,
+    pub amount: i128,
+    pub recipient: String,
+    pub tally_deadline: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Eligibility {
+    Static,
+    Registry,
+    Token { token: String, min_balance: i128, weighted: bool },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Committee {
+    pub members: Vec<Hex<32>>,
+    pub threshold: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Account {
+    pub pk_cast: Hex<32>,
+    pub nf_issue: Hex<32>,
+    pub weight: u32,
+    pub ballot_index: Option<u32>,
+    /// `voter_32` bound into `issue_msg`, from the `issued` event; zero for
+    /// an issuance without an eligibility proof. `None` when unknown.
+    #[serde(default)]
+    pub voter: Option<Hex<32>>,
+    /// `(dist_pk, sig)` over `issue_msg`, from the `issue_account` call.
+    /// Empty for batch issuance or when unknown.
+    #[serde(default)]
+    pub dist_sigs: Vec<(Hex<32>, Hex<64>)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ballot {
+    pub nf_cast: Hex<32>,
+    pub c1: Hex<33>,
+    pub c2: Hex<33>,
+    pub weight: u32,
+    /// Casting account's signature over `cast_msg`, from the `cast` call
+    #[serde(default)]
+    pub sig: Option<Hex<64>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShareBatch {
+    /// 0-based KH roster slot
+    pub kh_idx: u32,
+    /// `serialise_share_batch` blob as stored under `KhShare(eid, kh_idx)`
+    pub blob: HexBytes,
+    /// KH signature over `shares_msg`, from the `post_share` call
+    #[serde(default)]
+    pub sig: Option<Hex<64>>,
+    /// One CP proof per share, in blob order, for blobs posted without
+    /// them
+    #[serde(default)]
+    pub proofs: Option<Vec<CpProof>>,
+    /// For a chunked upload, which has no `shares_msg` signature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub upload: Option<UploadSigs>,
+}
+
+/// KH signatures of a chunked upload, from the `begin_shares` and
+/// `post_share_chunk` calls
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadSigs {
+    /// Signature over `shares_begin_msg` for the upload digest
+    pub begin_sig: Hex<64>,
+    /// `(start, sig)` per chunk in upload order, `sig` over `shares_chunk_msg`
+    /// under the upload digest
+    pub chunks: Vec<(u32, Hex<64>)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CpProof {
+    pub r1: Hex<33>,
+    pub r2: Hex<33>,
+    pub s: Hex<32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Tally {
+    pub tally: Vec<u32>,
+    pub ballot_count: u32,
+    pub board_head: Hex<32>,
+    pub ballot_root: Hex<32>,
+    pub share_count: u32,
+    pub tallied_at: u64,
+}
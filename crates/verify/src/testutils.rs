@@ -0,0 +1,576 @@
+//! Snapshots of elections in a soroban test `Env`: exporters from contract
+//! state and from the contract's event stream, and `replay`, which rebuilds
+//! a snapshot's election in a fresh `Env`.
+//!
+//! Stored points must be 33 bytes; the exporters panic otherwise.
+
+use std::collections::BTreeMap;
+
+use election::types::{
+    self as ct, AccountStatus, CastingAccount, DataKey, DisputePolicy, DisputeWindow,
+    ElectionConfig, EligibilityMode, EncryptedBallot, KhBond, StakePolicy, TallyRecord, TokenGate,
+    VersionedBallot, VersionedElection,
+};
+use election::{events, ElectionContract, ElectionContractClient};
+use soroban_sdk::testutils::{Address as _, EnvTestConfig};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Symbol, TryFromVal, Val, Vec as SVec};
+use stellot_client::msg;
+
+use crate::hex::{Hex, HexBytes};
+use crate::snapshot::{
+    Account, Ballot, Committee, Config, Disputes, ElectionStatus, Eligibility, ShareBatch, Snapshot,
+    Staking, Tally,
+};
+use crate::Error;
+
+/// One contract event, as `env.events().all()` yields it.
+pub type Event = (Address, SVec<Val>, Val);
+
+const PAGE: u32 = 100;
+
+// ── From contract state ───────────────────────────────────────────────────────
+
+/// Export election `eid` from `contract`'s storage.  `None` if there is no
+/// such (live) election.  Storage keeps only the board's head, so `board`
+/// is left `None`.
+pub fn from_contract(env: &Env, contract: &Address, eid: u64) -> Option<Snapshot> {
+    let client = ElectionContractClient::new(env, contract);
+    let params = client.get_election(&eid)?;
+    let (dist, kh) = env.as_contract(contract, || {
+        let storage = env.storage().persistent();
+        let committee = |roster: DataKey, threshold: DataKey| {
+            committee(&storage.get(&roster).unwrap(), storage.get(&threshold).unwrap())
+        };
+        (
+            committee(DataKey::DistRoster(eid), DataKey::DistThreshold(eid)),
+            committee(DataKey::KhRoster(eid), DataKey::KhThreshold(eid)),
+        )
+    });
+
+    let mut accounts = Vec::new();
+    let mut cursor = Some(0);
+    while let Some(start) = cursor {
+        let page = client.list_casting_accounts(&eid, &start);
+        for (pk_cast, account) in page.accounts.iter() {
+            accounts.push(Account {
+                pk_cast: hex_n(&pk_cast),
+                nf_issue: hex_n(&account.nf_issue),
+                weight: account.weight,
+                ballot_index: account.ballot_index,
+                voter: None,
+                dist_sigs: Vec::new(),
+            });
+        }
+        cursor = page.next;
+    }
+
+    let mut cast_nullifiers = Vec::new();
+    let mut cursor = Some(0);
+    while let Some(start) = cursor {
+        let page = client.list_cast_nullifiers(&eid, &start);
+        cast_nullifiers.extend(page.nullifiers.iter().map(|nf| hex_n(&nf)));
+        cursor = page.next;
+    }
+
+    let ballot_count = client.get_ballot_count(&eid);
+    let ballots: Vec<Ballot> = (0..ballot_count)
+        .step_by(PAGE as usize)
+        .flat_map(|start| client.get_ballots(&eid, &start, &PAGE))
+        .map(|b| ballot(&b))
+        .collect();
+
+    let mut shares: Vec<ShareBatch> = (0..kh.members.len() as u32)
+        .step_by(PAGE as usize)
+        .flat_map(|start| client.get_kh_shares_range(&eid, &start, &PAGE))
+        .map(|(kh_idx, blob)| ShareBatch {
+            kh_idx,
+            blob: HexBytes(blob.iter().collect()),
+            sig: None,
+            proofs: None,
+            upload: None,
+        })
+        .collect();
+    // Committed chunked uploads, reassembled into one batch blob
+    for kh_idx in 0..kh.members.len() as u32 {
+        let Some(upload) = client.get_share_upload(&eid, &kh_idx).filter(|u| u.committed) else {
+            continue;
+        };
+        let chunks: Vec<Vec<u8>> = (0..upload.chunks)
+            .map(|c| client.get_share_chunk(&eid, &kh_idx, &c).unwrap().iter().collect())
+            .collect();
+        let batch = msg::join_share_chunks(&chunks).ok()?;
+        shares.push(ShareBatch {
+            kh_idx,
+            blob: HexBytes(msg::serialise_share_batch(&batch)),
+            sig: None,
+            proofs: None,
+            upload: None,
+        });
+    }
+    shares.sort_by_key(|batch| batch.kh_idx);
+
+    Some(Snapshot {
+        eid,
+        organizer: address_string(&params.organizer),
+        title: HexBytes(params.title.iter().collect()),
+        options_count: params.options_count,
+        start_time: params.start_time,
+        end_time: params.end_time,
+        enc_pubkey: hex_fixed(&params.enc_pubkey),
+        status: status(params.status),
+        config: config(&params.config),
+        eligibility_root: hex_n(&client.get_eligibility_root(&eid).unwrap()),
+        kh_commitments: (0..kh.members.len() as u32)
+            .map(|j| client.get_kh_commitment(&eid, &j).map(|a| hex_fixed(&a)))
+            .collect(),
+        dist,
+        kh,
+        vss_commitments: None,
+        accounts,
+        cast_nullifiers,
+        board: None,
+        ballots,
+        shares,
+        tally: client.get_tally_record(&eid).map(|record| tally(&record)),
+    })
+}
+
+/// `(start, count, chunk_hash)` from a `chunk` event.
+type Chunk = (u32, u32, [u8; 32]);
+
+/// Chain digest of `blob` re-split along an upload's `(start, count, _)`
+/// chunks, if the chunks tile the blob and each re-serialises to its
+/// posted hash.
+fn chunked_digest(eid: u64, blob: &[u8], chunks: &[Chunk]) -> Option<[u8; 32]> {
+    let shares = msg::deserialise_share_batch(blob).ok()?;
+    let mut parts = Vec::with_capacity(chunks.len());
+    let mut next = 0;
+    for &(start, count, chunk_hash) in chunks {
+        let end = start as usize + count as usize;
+        let chunk = shares.get(next..end).filter(|_| start as usize == next)?;
+        let chunk_blob = msg::serialise_share_batch(chunk);
+        if msg::shares_chunk_hash(eid, start, &chunk_blob) != chunk_hash {
+            return None;
+        }
+        parts.push((start, chunk_blob));
+        next = end;
+    }
+    (next == shares.len()).then(|| msg::upload_digest(eid, &parts))
+}
+
+// ── From the event stream ─────────────────────────────────────────────────────
+
+/// Rebuild election `eid` by folding `contract`'s events in emission order.
+/// Share blobs are not carried by events: `share_blob(kh_idx)` supplies each
+/// one (from the key holder's file, an RPC read, …) and it is checked against
+/// the digest in its `share` event, or for a chunked upload against the
+/// `chunk` events it was posted in.  Batches later `struck` or `withdrawn`
+/// are dropped.
+pub fn from_events(
+    env: &Env,
+    contract: &Address,
+    eid: u64,
+    events: &[Event],
+    mut share_blob: impl FnMut(u32) -> Option<Vec<u8>>,
+) -> Result<Snapshot, Error> {
+    let mut snapshot: Option<Snapshot> = None;
+    // Chunks of each KH's current upload
+    let mut uploads: BTreeMap<u32, Vec<Chunk>> = BTreeMap::new();
+    // Digest and chunks of each KH's counted shares
+    let mut posted: BTreeMap<u32, ([u8; 32], Vec<Chunk>)> = BTreeMap::new();
+    for (source, topics, data) in events {
+        if source != contract || topics.len() != 2 {
+            continue;
+        }
+        let name = Symbol::try_from_val(env, &topics.get_unchecked(0));
+        let topic_eid = u64::try_from_val(env, &topics.get_unchecked(1));
+        let (Ok(name), Ok(topic_eid)) = (name, topic_eid) else {
+            continue;
+        };
+        if topic_eid != eid {
+            continue;
+        }
+        let is = |topic: &str| name == Symbol::new(env, topic);
+
+        if is("deploy") {
+            let e: events::Deployed = decode(env, data, "deploy")?;
+            snapshot = Some(Snapshot {
+                eid,
+                organizer: address_string(&e.organizer),
+                title: HexBytes(e.title.iter().collect()),
+                options_count: e.options_count,
+                start_time: e.start_time,
+                end_time: e.end_time,
+                enc_pubkey: hex_fixed(&e.enc_pubkey),
+                status: ElectionStatus::Active,
+                config: config(&e.config),
+                eligibility_root: hex_n(&e.eligibility_root),
+                dist: committee(&e.dist.members, e.dist.threshold),
+                kh_commitments: vec![None; e.kh.members.len() as usize],
+                kh: committee(&e.kh.members, e.kh.threshold),
+                vss_commitments: None,
+                accounts: Vec::new(),
+                cast_nullifiers: Vec::new(),
+                board: Some(Vec::new()),
+                ballots: Vec::new(),
+                shares: Vec::new(),
+                tally: None,
+            });
+            continue;
+        }
+        let s = snapshot.as_mut().ok_or_else(|| Error::Events("event before deploy".into()))?;
+
+        if is("commit") {
+            let e: events::CommitmentSet = decode(env, data, "commit")?;
+            let slot = s
+                .kh_commitments
+                .get_mut(e.kh_idx as usize)
+                .ok_or_else(|| Error::Events(format!("commitment for KH {}", e.kh_idx)))?;
+            *slot = Some(hex_fixed(&e.commitment));
+        } else if is("voter_add") || is("voter_rev") {
+            let e: events::RegistryUpdated = decode(env, data, "registry")?;
+            s.eligibility_root = hex_n(&e.root);
+        } else if is("issued") {
+            let e: events::AccountIssued = decode(env, data, "issued")?;
+            s.accounts.push(Account {
+                pk_cast: hex_n(&e.pk_cast),
+                nf_issue: hex_n(&e.nf_issue),
+                weight: e.weight,
+                ballot_index: None,
+                voter: Some(hex_n(&e.voter)),
+                dist_sigs: Vec::new(),
+            });
+        } else if is("cast") || is("recast") {
+            let e: events::BallotCast = decode(env, data, "cast")?;
+            let ballot = ballot(&EncryptedBallot { nf_cast: e.nf_cast, c1: e.c1, c2: e.c2, weight: e.weight });
+            if is("cast") {
+                if e.index as usize != s.ballots.len() {
+                    return Err(Error::Events(format!("ballot {} out of order", e.index)));
+                }
+                let pk_cast = hex_n(&e.pk_cast);
+                let account = s
+                    .accounts
+                    .iter_mut()
+                    .find(|a| a.pk_cast == pk_cast)
+                    .ok_or_else(|| Error::Events(format!("ballot {} from an unissued account", e.index)))?;
+                account.ballot_index = Some(e.index);
+                s.ballots.push(ballot.clone());
+            } else {
+                let slot = s
+                    .ballots
+                    .get_mut(e.index as usize)
+                    .ok_or_else(|| Error::Events(format!("revote of missing ballot {}", e.index)))?;
+                *slot = ballot.clone();
+            }
+            s.cast_nullifiers.push(ballot.nf_cast);
+            s.board.get_or_insert_with(Vec::new).push(hex_n(&e.msg_hash));
+        } else if is("upload") {
+            let e: events::ShareUploadBegun = decode(env, data, "upload")?;
+            uploads.insert(e.kh_idx, Vec::new());
+        } else if is("chunk") {
+            let e: events::ShareChunkPosted = decode(env, data, "chunk")?;
+            let chunks = uploads
+                .get_mut(&e.kh_idx)
+                .ok_or_else(|| Error::Events(format!("chunk from KH {} before its upload", e.kh_idx)))?;
+            chunks.push((e.start, e.count, e.chunk_hash.to_array()));
+        } else if is("share") {
+            let e: events::SharePosted = decode(env, data, "share")?;
+            let chunks = uploads.get(&e.kh_idx).cloned().unwrap_or_default();
+            posted.insert(e.kh_idx, (e.shares_hash.to_array(), chunks));
+        } else if is("struck") {
+            let e: events::SharesStruck = decode(env, data, "struck")?;
+            posted.remove(&e.kh_idx);
+            uploads.remove(&e.kh_idx);
+        } else if is("withdrawn") {
+            let e: events::SharesWithdrawn = decode(env, data, "withdrawn")?;
+            posted.remove(&e.kh_idx);
+            uploads.remove(&e.kh_idx);
+        } else if is("tallied") {
+            let e: events::Tallied = decode(env, data, "tallied")?;
+            s.tally = Some(Tally {
+                tally: e.tally.iter().collect(),
+                ballot_count: s.ballots.len() as u32,
+                board_head: hex_n(&e.board_head),
+                ballot_root: hex_n(&e.ballot_root),
+                share_count: e.share_count,
+                tallied_at: e.tallied_at,
+            });
+        } else if is("paused") {
+            s.status = ElectionStatus::Paused;
+        } else if is("resumed") {
+            s.status = ElectionStatus::Active;
+        } else if is("cancelled") {
+            s.status = ElectionStatus::Cancelled;
+        } else if is("extended") {
+            let e: events::VotingExtended = decode(env, data, "extended")?;
+            s.end_time = e.new_end_time;
+        }
+    }
+    // Posted shares are listed in roster order, as `from_contract` lists them
+    let mut snapshot = snapshot.ok_or_else(|| Error::Events(format!("no deploy event for eid {eid}")))?;
+    for (kh_idx, (digest, chunks)) in posted {
+        let blob = share_blob(kh_idx)
+            .filter(|blob| msg::shares_msg(eid, blob) == digest || chunked_digest(eid, blob, &chunks) == Some(digest))
+            .ok_or(Error::ShareBlob(kh_idx))?;
+        snapshot.shares.push(ShareBatch { kh_idx, blob: HexBytes(blob), sig: None, proofs: None, upload: None });
+    }
+    Ok(snapshot)
+}
+
+fn decode<T: TryFromVal<Env, Val>>(env: &Env, data: &Val, topic: &str) -> Result<T, Error> {
+    T::try_from_val(env, data).map_err(|_| {
+        Error::Events(format!("`{topic}` payload is not event version {}", events::EVENT_VERSION))
+    })
+}
+
+// ── Replay ────────────────────────────────────────────────────────────────────
+
+/// Rebuild the snapshot's election in a fresh `Env` under the same eid.
+///
+/// `deploy` and `set_kh_commitment` run through the contract; the voting
+/// record is written to storage directly, since the signatures its entry
+/// points check are not part of contract state.  Returns the `Env` and the
+/// contract address.  The `Env` writes no test snapshot when dropped.
+pub fn replay(s: &Snapshot) -> Result<(Env, Address), Error> {
+    let board: Vec<[u8; 32]> = match &s.board {
+        Some(board) => board.iter().map(|d| d.0).collect(),
+        None if s.cast_nullifiers.len() == s.ballots.len() => {
+            s.ballots.iter().map(|b| digest(s.eid, b)).collect()
+        }
+        None => return Err(Error::Incomplete("board log")),
+    };
+
+    let env = Env::new_with_config(EnvTestConfig { capture_snapshot_at_drop: false });
+    env.mock_all_auths();
+    let contract = env.register(ElectionContract, (Address::generate(&env), false));
+    let client = ElectionContractClient::new(&env, &contract);
+
+    env.as_contract(&contract, || env.storage().instance().set(&DataKey::NextElectionId, &s.eid));
+    let eid = client.deploy(
+        &Address::from_str(&env, &s.organizer),
+        &Bytes::from_slice(&env, &s.title.0),
+        &s.options_count,
+        &s.start_time,
+        &s.end_time,
+        &Bytes::from_slice(&env, &s.enc_pubkey.0),
+        &BytesN::from_array(&env, &s.eligibility_root.0),
+        &contract_committee(&env, &s.dist),
+        &contract_committee(&env, &s.kh),
+        &contract_config(&env, &s.config),
+    );
+    for (j, commitment) in s.kh_commitments.iter().enumerate() {
+        if let Some(a) = commitment {
+            client.set_kh_commitment(&eid, &(j as u32), &Bytes::from_slice(&env, &a.0));
+        }
+    }
+
+    let mut params = client.get_election(&eid).unwrap();
+    env.as_contract(&contract, || {
+        let storage = env.storage().persistent();
+        let bytes_n = |h: &Hex<32>| BytesN::from_array(&env, &h.0);
+
+        for (i, a) in s.accounts.iter().enumerate() {
+            storage.set(&DataKey::IssueNullifier(eid, bytes_n(&a.nf_issue)), &true);
+            storage.set(
+                &DataKey::CastingAccount(eid, bytes_n(&a.pk_cast)),
+                &CastingAccount {
+                    status: if a.ballot_index.is_some() { AccountStatus::Voted } else { AccountStatus::Issued },
+                    issued_at: env.ledger().timestamp(),
+                    nf_issue: bytes_n(&a.nf_issue),
+                    ballot_index: a.ballot_index,
+                    holder: None,
+                    weight: a.weight,
+                },
+            );
+            storage.set(&DataKey::Issued(eid, i as u32), &bytes_n(&a.pk_cast));
+        }
+        storage.set(&DataKey::IssuedCount(eid), &(s.accounts.len() as u32));
+
+        for (n, nf) in s.cast_nullifiers.iter().enumerate() {
+            storage.set(&DataKey::CastNullifier(eid, bytes_n(nf)), &true);
+            storage.set(&DataKey::Cast(eid, n as u32), &bytes_n(nf));
+        }
+        storage.set(&DataKey::CastCount(eid), &(s.cast_nullifiers.len() as u32));
+
+        for (i, b) in s.ballots.iter().enumerate() {
+            let ballot = EncryptedBallot {
+                nf_cast: bytes_n(&b.nf_cast),
+                c1: Bytes::from_slice(&env, &b.c1.0),
+                c2: Bytes::from_slice(&env, &b.c2.0),
+                weight: b.weight,
+            };
+            storage.set(&DataKey::Ballot(eid, i as u32), &VersionedBallot::V1(ballot));
+        }
+        storage.set(&DataKey::BallotCount(eid), &(s.ballots.len() as u32));
+
+        let mut tree = election::merkle::empty_frontier(&env);
+        let mut head = BytesN::from_array(&env, &msg::ZERO_HEAD);
+        for d in &board {
+            let d = BytesN::from_array(&env, d);
+            let leaf = election::ballot_leaf(&env, &d);
+            storage.set(&DataKey::BallotLeaf(eid, tree.size), &leaf);
+            election::merkle::append(&env, &mut tree, &leaf);
+            head = election::board_step(&env, &head, &d);
+        }
+        storage.set(&DataKey::BallotTree(eid), &tree);
+        storage.set(&DataKey::BoardHead(eid), &head);
+
+        for batch in &s.shares {
+            storage.set(&DataKey::KhShare(eid, batch.kh_idx), &Bytes::from_slice(&env, &batch.blob.0));
+        }
+        storage.set(&DataKey::ShareCount(eid), &(s.shares.len() as u32));
+
+        if let Some(t) = &s.tally {
+            let mut tally = SVec::new(&env);
+            for n in &t.tally {
+                tally.push_back(*n);
+            }
+            storage.set(
+                &DataKey::Tally(eid),
+                &TallyRecord {
+                    tally,
+                    ballot_count: t.ballot_count,
+                    board_head: BytesN::from_array(&env, &t.board_head.0),
+                    ballot_root: BytesN::from_array(&env, &t.ballot_root.0),
+                    share_count: t.share_count,
+                    tallied_at: t.tallied_at,
+                },
+            );
+            params.tallied = true;
+        }
+        params.status = match s.status {
+            ElectionStatus::Active => ct::ElectionStatus::Active,
+            ElectionStatus::Paused => ct::ElectionStatus::Paused,
+            ElectionStatus::Cancelled => ct::ElectionStatus::Cancelled,
+        };
+        storage.set(&DataKey::Election(eid), &VersionedElection::V3(params));
+    });
+
+    Ok((env, contract))
+}
+
+// ── Conversions ───────────────────────────────────────────────────────────────
+
+fn hex_n<const N: usize>(b: &BytesN<N>) -> Hex<N> {
+    Hex(b.to_array())
+}
+
+fn hex_fixed<const N: usize>(b: &Bytes) -> Hex<N> {
+    let bytes: Vec<u8> = b.iter().collect();
+    Hex(bytes.try_into().unwrap_or_else(|_| panic!("expected {N} bytes")))
+}
+
+fn address_string(address: &Address) -> String {
+    let strkey = address.to_string();
+    let mut out = vec![0u8; strkey.len() as usize];
+    strkey.copy_into_slice(&mut out);
+    String::from_utf8(out).expect("strkeys are ASCII")
+}
+
+fn ballot(b: &EncryptedBallot) -> Ballot {
+    Ballot {
+        nf_cast: hex_n(&b.nf_cast),
+        c1: hex_fixed(&b.c1),
+        c2: hex_fixed(&b.c2),
+        weight: b.weight,
+        sig: None,
+    }
+}
+
+fn digest(eid: u64, b: &Ballot) -> [u8; 32] {
+    msg::cast_msg(eid, &b.nf_cast.0, &b.c1.0, &b.c2.0)
+}
+
+fn tally(record: &TallyRecord) -> Tally {
+    Tally {
+        tally: record.tally.iter().collect(),
+        ballot_count: record.ballot_count,
+        board_head: hex_n(&record.board_head),
+        ballot_root: hex_n(&record.ballot_root),
+        share_count: record.share_count,
+        tallied_at: record.tallied_at,
+    }
+}
+
+fn status(status: ct::ElectionStatus) -> ElectionStatus {
+    match status {
+        ct::ElectionStatus::Active => ElectionStatus::Active,
+        ct::ElectionStatus::Paused => ElectionStatus::Paused,
+        ct::ElectionStatus::Cancelled => ElectionStatus::Cancelled,
+    }
+}
+
+fn committee(members: &SVec<BytesN<32>>, threshold: u32) -> Committee {
+    Committee { members: members.iter().map(|pk| hex_n(&pk)).collect(), threshold }
+}
+
+fn contract_committee(env: &Env, c: &Committee) -> ct::Committee {
+    let mut members = SVec::new(env);
+    for pk in &c.members {
+        members.push_back(BytesN::from_array(env, &pk.0));
+    }
+    ct::Committee { members, threshold: c.threshold }
+}
+
+fn config(c: &ElectionConfig) -> Config {
+    Config {
+        revoting: c.revoting,
+        max_end_time: c.max_end_time,
+        eligibility: match &c.eligibility {
+            EligibilityMode::Static => Eligibility::Static,
+            EligibilityMode::Registry => Eligibility::Registry,
+            EligibilityMode::Token(gate) => Eligibility::Token {
+                token: address_string(&gate.token),
+                min_balance: gate.min_balance,
+                weighted: gate.weighted,
+            },
+        },
+        disputes: match &c.disputes {
+            DisputePolicy::None => None,
+            DisputePolicy::Window(w) => {
+                Some(Disputes { secs: w.secs, vss_digest: hex_n(&w.vss_digest) })
+            }
+        },
+        staking: match &c.staking {
+            StakePolicy::None => None,
+            StakePolicy::Bond(bond) => Some(Staking {
+                token: address_string(&bond.token),
+                amount: bond.amount,
+                recipient: address_string(&bond.recipient),
+                tally_deadline: bond.tally_deadline,
+            }),
+        },
+    }
+}
+
+fn contract_config(env: &Env, c: &Config) -> ElectionConfig {
+    ElectionConfig {
+        revoting: c.revoting,
+        max_end_time: c.max_end_time,
+        eligibility: match &c.eligibility {
+            Eligibility::Static => EligibilityMode::Static,
+            Eligibility::Registry => EligibilityMode::Registry,
+            Eligibility::Token { token, min_balance, weighted } => EligibilityMode::Token(TokenGate {
+                token: Address::from_str(env, token),
+                min_balance: *min_balance,
+                weighted: *weighted,
+            }),
+        },
+        disputes: match &c.disputes {
+            None => DisputePolicy::None,
+            Some(d) => DisputePolicy::Window(DisputeWindow {
+                secs: d.secs,
+                vss_digest: BytesN::from_array(env, &d.vss_digest.0),
+            }),
+        },
+        staking: match &c.staking {
+            None => StakePolicy::None,
+            Some(s) => StakePolicy::Bond(KhBond {
+                token: Address::from_str(env, &s.token),
+                amount: s.amount,
+                recipient: Address::from_str(env, &s.recipient),
+                tally_deadline: s.tally_deadline,
+            }),
+        },
+    }
+}
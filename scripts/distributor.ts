@@ -15,7 +15,7 @@
  *   1. Reads the on-chain eligibility root
  *   2. Verifies the voter's Merkle inclusion proof
  *   3. Checks the issue nullifier has not been used
- *   4. Signs the issue message (eid || pk_cast || nf_issue)
+ *   4. Signs the issue message (eid || pk_cast || nf_issue || voter leaf)
  *   5. Returns the signature (in a real system: over a REST API)
  *
  * For the PoC this script also calls issue_account on-chain directly.
@@ -88,6 +88,7 @@ function issueMsgHash(
   eid: bigint,
   pkCast: Uint8Array,
   nfIssue: Uint8Array,
+  voter: Uint8Array = new Uint8Array(32),
 ): Uint8Array {
   return sha256(
     concatBytes(
@@ -95,10 +96,16 @@ function issueMsgHash(
       bigintToLE64(eid),
       pkCast,
       nfIssue,
+      voter,
     ),
   );
 }
 
+/** Eligibility leaf hash (mirrors merkle.ts::leafHash). */
+function leafHash(leaf: Uint8Array): Uint8Array {
+  return sha256(concatBytes(new TextEncoder().encode("stellot:leaf"), leaf));
+}
+
 // ── Main ───────────────────────────────────────────────────────────────────────
 
 async function main() {
@@ -130,7 +137,10 @@ async function main() {
   const nfIssue = fromHex(args.nfIssueHex);
 
   // Compute issue message and sign
-  const msgHash = issueMsgHash(args.eid, pkCast, nfIssue);
+  // The voter's eligibility leaf is bound into the message, so the signature
+  // only covers an issuance that proves that voter
+  const voter = args.voterPkHex ? leafHash(fromHex(args.voterPkHex)) : undefined;
+  const msgHash = issueMsgHash(args.eid, pkCast, nfIssue, voter);
   const sig = ed25519.sign(msgHash, distSk);
 
   console.log(`\nIssue message hash: ${toHex(msgHash)}`);
@@ -142,6 +152,7 @@ async function main() {
     eid: args.eid.toString(),
     pk_cast: toHex(pkCast),
     nf_issue: toHex(nfIssue),
+    ...(args.voterPkHex ? { leaf: args.voterPkHex } : {}),
     dist_pk: toHex(distPk),
     dist_sig: toHex(sig),
   };
@@ -186,7 +186,10 @@ step "6. Deploying election contract"
 CONTRACT_ID=$(stellar contract deploy \
   --wasm "$WASM" \
   --source deploy \
-  --network $STELLAR_NETWORK)
+  --network $STELLAR_NETWORK \
+  -- \
+  --admin "$DEPLOY_PK" \
+  --single_election false)
 ok "Contract deployed: $CONTRACT_ID"
 export CONTRACT_ID
 
@@ -221,16 +224,16 @@ stellar contract invoke \
   --source deploy \
   --network $STELLAR_NETWORK \
   -- deploy \
+  --organizer "$DEPLOY_PK" \
   --title "$TITLE_HEX" \
   --options_count 2 \
   --start_time "$START" \
   --end_time "$END" \
   --enc_pubkey "$COMBINED_PUBKEY" \
   --eligibility_root "$MERKLE_ROOT" \
-  --dist_roster "[\"$DIST_PK\"]" \
-  --dist_threshold 1 \
-  --kh_roster "[\"$KH1_ED_PK\",\"$KH2_ED_PK\",\"$KH3_ED_PK\"]" \
-  --kh_threshold 2
+  --dist "{\"members\":[\"$DIST_PK\"],\"threshold\":1}" \
+  --kh "{\"members\":[\"$KH1_ED_PK\",\"$KH2_ED_PK\",\"$KH3_ED_PK\"],\"threshold\":2}" \
+  --config "{\"revoting\":false,\"max_end_time\":$END,\"eligibility\":\"Static\"}"
 
 EID=0
 ok "Election deployed with eid=$EID"
@@ -294,6 +297,7 @@ const msg = sha256(concatBytes(
   eid,
   pkCast,
   nfIssue,
+  new Uint8Array(32),
 ));
 const sig = ed25519.sign(msg, distSk);
 console.log(Buffer.from(sig).toString("hex"));
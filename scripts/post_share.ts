@@ -8,7 +8,12 @@
  *     --eid 0 \
  *     --contract <contract-id> \
  *     --rpc local \
- *     --source deploy
+ *     --source deploy \
+ *     [--chunk-size 500]
+ *
+ * With --chunk-size the shares go up as a chunked upload (begin_shares,
+ * post_share_chunk per chunk, commit_shares) instead of one post_share;
+ * re-running resumes an interrupted upload.
  *
  * Usage (finalize tally after all shares posted):
  *   npx tsx scripts/post_share.ts \
@@ -69,10 +74,58 @@ function partialDecrypt(c1Hex: string, skBig: bigint): Uint8Array {
   return C1.multiply(skBig).toRawBytes(true);
 }
 
-function serialiseShares(shares: Array<[Uint8Array, Uint8Array]>): Uint8Array {
-  const parts: Uint8Array[] = [u32LE(shares.length)];
-  for (const [c1, d] of shares) {
-    parts.push(u32LE(c1.length), c1, u32LE(d.length), d);
+interface CpProof {
+  r1: Uint8Array;
+  r2: Uint8Array;
+  s: Uint8Array; // big-endian
+}
+
+interface DecryptionShare {
+  ballotIndex: number;
+  c1: Uint8Array;
+  d: Uint8Array;
+  proof?: CpProof;
+}
+
+/** Chaum-Pedersen proof that log_G(PK_j) == log_C1(D_j). */
+function cpProve(c1: Uint8Array, d: Uint8Array, skBig: bigint): CpProof {
+  const r = BigInt("0x" + toHex(secp256k1.utils.randomPrivateKey()));
+  const C1 = secp256k1.ProjectivePoint.fromHex(c1);
+  const R1 = secp256k1.ProjectivePoint.BASE.multiply(r);
+  const R2 = C1.multiply(r);
+  const PK_j = secp256k1.ProjectivePoint.BASE.multiply(skBig);
+
+  const cpChallenge = sha256(
+    concatBytes(
+      secp256k1.ProjectivePoint.BASE.toRawBytes(true),
+      PK_j.toRawBytes(true),
+      c1,
+      d,
+      R1.toRawBytes(true),
+      R2.toRawBytes(true),
+    ),
+  );
+  const c = BigInt("0x" + toHex(cpChallenge)) % Q;
+  const s = (r + c * skBig) % Q;
+  return {
+    r1: R1.toRawBytes(true),
+    r2: R2.toRawBytes(true),
+    s: fromHex(s.toString(16).padStart(64, "0")),
+  };
+}
+
+const SHARE_BATCH_VERSION = 1;
+
+/** Mirrors the contract's serialise_share_batch. */
+function serialiseShareBatch(shares: DecryptionShare[]): Uint8Array {
+  const parts: Uint8Array[] = [Uint8Array.of(SHARE_BATCH_VERSION), u32LE(shares.length)];
+  for (const share of shares) {
+    parts.push(u32LE(share.ballotIndex), share.c1, share.d);
+    if (share.proof) {
+      parts.push(Uint8Array.of(1), share.proof.r1, share.proof.r2, share.proof.s);
+    } else {
+      parts.push(Uint8Array.of(0));
+    }
   }
   return concatBytes(...parts);
 }
@@ -83,6 +136,28 @@ function sharesMsgHash(eid: bigint, blob: Uint8Array): Uint8Array {
   );
 }
 
+function sharesBeginMsgHash(eid: bigint, digest: Uint8Array): Uint8Array {
+  return sha256(
+    concatBytes(new TextEncoder().encode("stellot:begin"), bigintToLE64(eid), digest),
+  );
+}
+
+function sharesChunkHash(eid: bigint, start: number, blob: Uint8Array): Uint8Array {
+  return sha256(
+    concatBytes(new TextEncoder().encode("stellot:chunk"), bigintToLE64(eid), u32LE(start), blob),
+  );
+}
+
+function sharesChunkMsgHash(eid: bigint, digest: Uint8Array, chunkHash: Uint8Array): Uint8Array {
+  return sha256(
+    concatBytes(new TextEncoder().encode("stellot:chunksig"), bigintToLE64(eid), digest, chunkHash),
+  );
+}
+
+function sharesChainStep(chain: Uint8Array, chunkHash: Uint8Array): Uint8Array {
+  return sha256(concatBytes(new TextEncoder().encode("stellot:chunks"), chain, chunkHash));
+}
+
 function lagrangeCoeff(j: number, S: number[]): bigint {
   let num = 1n;
   let den = 1n;
@@ -134,6 +209,7 @@ function parseArgs() {
     rpcUrl: get("--rpc") ?? process.env.RPC_URL ?? "local",
     source: get("--source") ?? "deploy",
     optionsCount: parseInt(get("--options-count") ?? "2"),
+    chunkSize: get("--chunk-size") ? parseInt(get("--chunk-size")!) : undefined,
   };
 }
 
@@ -197,6 +273,7 @@ async function postSharesForKH(
   contractId: string,
   rpcUrl: string,
   source: string,
+  chunkSize?: number,
 ) {
   const skBig = BigInt("0x" + kh.sk);
   const edSk = fromHex(kh.ed_sk);
@@ -204,46 +281,27 @@ async function postSharesForKH(
 
   console.log(`\nKH ${kh.index}: computing ${ballots.length} partial decryption(s)…`);
 
-  const shares: Array<[Uint8Array, Uint8Array]> = ballots.map((b) => [
-    b.c1,
-    partialDecrypt(toHex(b.c1), skBig),
-  ]);
+  const shares: DecryptionShare[] = ballots.map((b, i) => {
+    const d = partialDecrypt(toHex(b.c1), skBig);
+    return { ballotIndex: i, c1: b.c1, d, proof: cpProve(b.c1, d, skBig) };
+  });
 
-  for (let i = 0; i < shares.length; i++) {
-    const [c1, d] = shares[i];
-    console.log(`  ballot ${i}: C1=${toHex(c1).slice(0, 16)}…  D=${toHex(d).slice(0, 16)}…`);
-  }
-
-  // Compute Chaum-Pedersen proof (off-chain verification; on-chain only checks KH sig)
-  if (ballots.length > 0) {
-    const r = BigInt("0x" + toHex(secp256k1.utils.randomPrivateKey()));
-    const C1 = secp256k1.ProjectivePoint.fromHex(shares[0][0]);
-    const D  = secp256k1.ProjectivePoint.fromHex(shares[0][1]);
-    const R1 = secp256k1.ProjectivePoint.BASE.multiply(r);
-    const R2 = C1.multiply(r);
-    const PK_j = secp256k1.ProjectivePoint.BASE.multiply(skBig);
-
-    const cpChallenge = sha256(
-      concatBytes(
-        secp256k1.ProjectivePoint.BASE.toRawBytes(true),
-        PK_j.toRawBytes(true),
-        C1.toRawBytes(true),
-        D.toRawBytes(true),
-        R1.toRawBytes(true),
-        R2.toRawBytes(true),
-      ),
+  for (const { ballotIndex, c1, d, proof } of shares) {
+    console.log(
+      `  ballot ${ballotIndex}: C1=${toHex(c1).slice(0, 16)}…  D=${toHex(d).slice(0, 16)}…` +
+        `  CP R1=${toHex(proof!.r1).slice(0, 16)}…`,
     );
-    const c = BigInt("0x" + toHex(cpChallenge)) % Q;
-    const s = (r + c * skBig) % Q;
+  }
+  console.log(`  (CP proofs are carried in the batch and verified off-chain)`);
+  const khIdxStr = String(kh.index - 1); // contract uses 0-based index
 
-    console.log(`\n  Chaum-Pedersen proof (ballot 0):`);
-    console.log(`    R1 = ${toHex(R1.toRawBytes(true)).slice(0, 16)}…`);
-    console.log(`    s  = ${s.toString(16).slice(0, 16)}…`);
-    console.log(`  (verified off-chain; on-chain only checks KH signature)`);
+  if (chunkSize) {
+    postSharesChunked(kh, eid, shares, chunkSize, contractId, rpcUrl, source);
+    return;
   }
 
   // Sign the shares blob with Ed25519
-  const blob = serialiseShares(shares);
+  const blob = serialiseShareBatch(shares);
   const msgHash = sharesMsgHash(eid, blob);
   const sig = ed25519.sign(msgHash, edSk);
 
@@ -251,15 +309,11 @@ async function postSharesForKH(
   console.log(`  Message hash: ${toHex(msgHash).slice(0, 16)}…`);
   console.log(`  Signature:    ${toHex(sig).slice(0, 16)}…`);
 
-  // Format shares for Soroban CLI: Vec<(Bytes, Bytes)> → [["c1hex","dhex"],...]
-  const sharesJson = JSON.stringify(shares.map(([c1, d]) => [toHex(c1), toHex(d)]));
-  const khIdxStr = String(kh.index - 1); // contract uses 0-based index
-
   stellarInvoke(contractId, rpcUrl, source, [
     "post_share",
     "--eid", String(Number(eid)),
     "--kh_idx", khIdxStr,
-    "--shares", sharesJson,
+    "--batch", batchJson(shares),
     "--kh_pk", kh.ed_pk,
     "--sig", toHex(sig),
   ]);
@@ -267,6 +321,80 @@ async function postSharesForKH(
   console.log(`\nKH ${kh.index} shares posted successfully.`);
 }
 
+/**
+ * Post shares as a chunked upload.  begin_shares with an unchanged digest
+ * is a no-op, so a re-run after a failure re-posts from the first chunk the
+ * contract has not stored yet (chunks it already holds fail as out of order
+ * and are skipped).
+ */
+function postSharesChunked(
+  kh: KHFile,
+  eid: bigint,
+  shares: DecryptionShare[],
+  chunkSize: number,
+  contractId: string,
+  rpcUrl: string,
+  source: string,
+) {
+  const edSk = fromHex(kh.ed_sk);
+  const khIdxStr = String(kh.index - 1);
+  const chunks: { start: number; shares: DecryptionShare[]; hash: Uint8Array }[] = [];
+  let digest: Uint8Array = new Uint8Array(32);
+  for (let start = 0; start < shares.length; start += chunkSize) {
+    const chunk = shares.slice(start, start + chunkSize);
+    const hash = sharesChunkHash(eid, start, serialiseShareBatch(chunk));
+    digest = sharesChainStep(digest, hash);
+    chunks.push({ start, shares: chunk, hash });
+  }
+  console.log(`\nUploading in ${chunks.length} chunk(s), digest ${toHex(digest).slice(0, 16)}…`);
+
+  stellarInvoke(contractId, rpcUrl, source, [
+    "begin_shares",
+    "--eid", String(Number(eid)),
+    "--kh_idx", khIdxStr,
+    "--digest", toHex(digest),
+    "--kh_pk", kh.ed_pk,
+    "--sig", toHex(ed25519.sign(sharesBeginMsgHash(eid, digest), edSk)),
+  ]);
+  for (const chunk of chunks) {
+    try {
+      stellarInvoke(contractId, rpcUrl, source, [
+        "post_share_chunk",
+        "--eid", String(Number(eid)),
+        "--kh_idx", khIdxStr,
+        "--start", String(chunk.start),
+        "--batch", batchJson(chunk.shares),
+        "--sig", toHex(ed25519.sign(sharesChunkMsgHash(eid, digest, chunk.hash), edSk)),
+      ]);
+    } catch (e) {
+      console.warn(`  chunk at ${chunk.start} not posted (already stored?): ${e}`);
+    }
+  }
+  stellarInvoke(contractId, rpcUrl, source, [
+    "commit_shares",
+    "--eid", String(Number(eid)),
+    "--kh_idx", khIdxStr,
+  ]);
+
+  console.log(`\nKH ${kh.index} shares uploaded and committed.`);
+}
+
+/** The ShareBatch struct for the Soroban CLI; proof is the ShareProof enum,
+ *  {"Cp": {...}} or "None". */
+function batchJson(shares: DecryptionShare[]): string {
+  return JSON.stringify({
+    version: SHARE_BATCH_VERSION,
+    shares: shares.map((share) => ({
+      ballot_index: share.ballotIndex,
+      c1: toHex(share.c1),
+      d: toHex(share.d),
+      proof: share.proof
+        ? { Cp: { r1: toHex(share.proof.r1), r2: toHex(share.proof.r2), s: toHex(share.proof.s) } }
+        : "None",
+    })),
+  });
+}
+
 // ── Main: finalize tally ───────────────────────────────────────────────────────
 
 async function finalizeAndPrint(
@@ -368,7 +496,7 @@ async function main() {
     const raw = await readFile(args.khFile, "utf-8");
     const kh: KHFile = JSON.parse(raw);
     await postSharesForKH(
-      kh, args.eid, ballots, args.contractId, args.rpcUrl, args.source,
+      kh, args.eid, ballots, args.contractId, args.rpcUrl, args.source, args.chunkSize,
     );
   }
 }
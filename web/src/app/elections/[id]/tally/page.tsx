@@ -6,28 +6,35 @@ import Nav from "@/components/Nav";
 import {
   getElection,
   getBallotCount,
-  getBallot,
+  getAllBallots,
   getKhShares,
   getKhRoster,
   getTally,
   postShare,
+  beginShares,
+  postShareChunk,
+  commitShares,
   finalizeTally,
   ElectionInfo,
 } from "@/lib/contract";
 import {
   partialDecrypt,
   elgamalDecrypt,
-  serialiseShares,
-  deserialiseShares,
+  serialiseShareBatch,
+  deserialiseShareBatch,
   sharesMsgHash,
+  sharesBeginMsgHash,
+  planShareUpload,
   ed25519Sign,
   bytesToHex,
   hexToBytes,
+  type DecryptionShare,
 } from "@/lib/crypto";
 import { combinePartialDecryptions } from "@/lib/threshold";
 import { getSessionKeypair } from "@/lib/wallet";
 
 const MAX_KH = 10; // maximum slots to probe on-chain
+const SHARE_CHUNK_SIZE = 100; // shares per transaction before switching to a chunked upload
 
 function hexToBigInt(hex: string): bigint {
   return BigInt("0x" + hex);
@@ -137,29 +144,37 @@ export default function TallyPage() {
         throw new Error("Ed25519 secret key must be 64 hex chars");
 
       setStatus("Fetching ballots…");
-      const ballots = [];
-      for (let i = 0; i < ballotCount; i++) {
-        const b = await getBallot(eid, i);
-        if (b) ballots.push(b);
-      }
+      const ballots = await getAllBallots(eid, ballotCount);
       if (ballots.length === 0) throw new Error("No ballots cast yet");
 
       setStatus("Computing partial decryptions…");
       const skBig = hexToBigInt(khSecpSk.trim());
-      const shares: Array<[Uint8Array, Uint8Array]> = ballots.map((b) => [
-        b.c1,
-        partialDecrypt(b.c1, skBig),
-      ]);
+      const shares: DecryptionShare[] = ballots.map((b, i) => ({
+        ballotIndex: i,
+        c1: b.c1,
+        d: partialDecrypt(b.c1, skBig),
+      }));
 
       setStatus("Signing and posting shares…");
-      const blob = serialiseShares(shares);
-      const msgHash = sharesMsgHash(eid, blob);
       const edSkBytes = hexToBytes(khEdSk.trim());
       const edPkBytes = (await import("@noble/curves/ed25519")).ed25519.getPublicKey(edSkBytes);
-      const sig = ed25519Sign(edSkBytes, msgHash);
-
       const kp = getSessionKeypair();
-      await postShare(kp, eid, idx - 1, shares, edPkBytes, sig);
+
+      if (shares.length <= SHARE_CHUNK_SIZE) {
+        const sig = ed25519Sign(edSkBytes, sharesMsgHash(eid, serialiseShareBatch(shares)));
+        await postShare(kp, eid, idx - 1, shares, edPkBytes, sig);
+      } else {
+        // Too large for one transaction: upload in chunks, resuming any
+        // earlier attempt with the same shares
+        const { digest, chunks } = planShareUpload(eid, shares, SHARE_CHUNK_SIZE);
+        const beginSig = ed25519Sign(edSkBytes, sharesBeginMsgHash(eid, digest));
+        const next = await beginShares(kp, eid, idx - 1, digest, edPkBytes, beginSig);
+        for (const chunk of chunks.filter((c) => c.start >= next)) {
+          setStatus(`Posting shares ${chunk.start + 1}–${chunk.start + chunk.shares.length} of ${shares.length}…`);
+          await postShareChunk(kp, eid, idx - 1, chunk.start, chunk.shares, ed25519Sign(edSkBytes, chunk.msg));
+        }
+        await commitShares(kp, eid, idx - 1);
+      }
 
       setStatus("Share posted successfully.");
       await refreshPostedSlots();
@@ -178,11 +193,7 @@ export default function TallyPage() {
 
     try {
       setStatus("Fetching ballots…");
-      const ballots = [];
-      for (let i = 0; i < ballotCount; i++) {
-        const b = await getBallot(eid, i);
-        if (b) ballots.push(b);
-      }
+      const ballots = await getAllBallots(eid, ballotCount);
 
       setStatus("Fetching on-chain decryption shares…");
       // allDji[ballotIdx] = Map<khLagrangeIdx (1-based), D_ji bytes>
@@ -192,10 +203,10 @@ export default function TallyPage() {
       for (const slot of slots) {
         const blob = await getKhShares(eid, slot);
         if (!blob) continue;
-        const pairs = deserialiseShares(blob); // pairs[i] = (c1_i, D_ji)
+        const shares = deserialiseShareBatch(blob); // shares[i].d = D_ji
         const lagrangeIdx = slot + 1; // on-chain slot 0 → KH index 1
-        for (let i = 0; i < pairs.length && i < ballots.length; i++) {
-          allDji[i].set(lagrangeIdx, pairs[i][1]);
+        for (let i = 0; i < shares.length && i < ballots.length; i++) {
+          allDji[i].set(lagrangeIdx, shares[i].d);
         }
       }
 
@@ -205,7 +216,7 @@ export default function TallyPage() {
         if (allDji[i].size === 0) continue;
         const Di = combinePartialDecryptions(allDji[i]);
         const vote = elgamalDecrypt(ballots[i].c2, Di, election.optionsCount);
-        if (vote >= 0 && vote < election.optionsCount) counts[vote]++;
+        if (vote >= 0 && vote < election.optionsCount) counts[vote] += ballots[i].weight;
       }
 
       setStatus("Submitting tally…");
@@ -18,6 +18,12 @@ import {
   Keypair,
 } from "@stellar/stellar-sdk";
 import { bytesToHex } from "@noble/hashes/utils";
+import {
+  SHARE_BATCH_VERSION,
+  deserialiseShareBatch,
+  serialiseShareBatch,
+  type DecryptionShare,
+} from "./crypto";
 
 // ── Configuration ──────────────────────────────────────────────────────────────
 
@@ -38,12 +44,41 @@ export interface ElectionInfo {
   endTime: bigint;
   encPubkey: Uint8Array;
   tallied: boolean;
+  organizer: string;
+  status: "Active" | "Paused" | "Cancelled";
 }
 
 export interface EncryptedBallot {
   nfCast: Uint8Array;
   c1: Uint8Array;
   c2: Uint8Array;
+  /** Tally weight (1 unless the election is token-weighted). */
+  weight: number;
+}
+
+/** Token-gated eligibility: holders of `token` with at least `minBalance`. */
+export interface TokenGate {
+  token: string;
+  minBalance: bigint;
+  weighted: boolean;
+}
+
+/** Dispute window: `finalize_tally` waits `secs` after the KH threshold is
+ *  met, and CP proofs are disputed against the DKG matrix hashing to
+ *  `vssDigest` (`dkg::vss_digest`). */
+export interface DisputeWindow {
+  secs: bigint;
+  vssDigest: Uint8Array;
+}
+
+/** Key-holder bond: each roster slot stakes `amount` of `token` before
+ *  `startTime`; slots without counted shares at `tallyDeadline` forfeit it
+ *  to `recipient` via `slash_absent`. */
+export interface KhBond {
+  token: string;
+  amount: bigint;
+  recipient: string;
+  tallyDeadline: bigint;
 }
 
 // ── RPC client factory ─────────────────────────────────────────────────────────
@@ -52,8 +87,8 @@ function getRpc() {
   return new SorobanRpc.Server(RPC_URL, { allowHttp: true });
 }
 
-function getContract() {
-  return new Contract(CONTRACT_ID);
+function getContract(contractId: string = CONTRACT_ID) {
+  return new Contract(contractId);
 }
 
 // ── Friendbot helper ───────────────────────────────────────────────────────────
@@ -101,11 +136,12 @@ async function submitTx(
   kp: Keypair,
   methodName: string,
   args: xdr.ScVal[],
+  contractId: string = CONTRACT_ID,
 ): Promise<xdr.ScVal> {
   const rpc = getRpc();
   await fundAccountIfNeeded(kp.publicKey());
   const account = await rpc.getAccount(kp.publicKey());
-  const contract = getContract();
+  const contract = getContract(contractId);
 
   const tx = new TransactionBuilder(account, {
     fee: BASE_FEE,
@@ -184,40 +220,168 @@ async function callReadOnly(
   return simResult.result?.retval ?? xdr.ScVal.scvVoid();
 }
 
+// ── ScVal helpers ─────────────────────────────────────────────────────────────
+
+/**
+ * Encode a `#[contracttype]` struct. Soroban expects an ScMap keyed by field
+ * name symbols, sorted lexicographically.
+ */
+function structToScVal(fields: Record<string, xdr.ScVal>): xdr.ScVal {
+  return xdr.ScVal.scvMap(
+    Object.keys(fields)
+      .sort()
+      .map(
+        (k) =>
+          new xdr.ScMapEntry({ key: xdr.ScVal.scvSymbol(k), val: fields[k] }),
+      ),
+  );
+}
+
+function committeeToScVal(members: Uint8Array[], threshold: number): xdr.ScVal {
+  return structToScVal({
+    members: xdr.ScVal.scvVec(members.map((pk) => xdr.ScVal.scvBytes(Buffer.from(pk)))),
+    threshold: nativeToScVal(threshold, { type: "u32" }),
+  });
+}
+
+/**
+ * Encode `EligibilityMode`. Enum variants encode as a vec holding the variant
+ * symbol followed by its fields.
+ */
+function eligibilityModeToScVal(mode: "Static" | "Registry" | TokenGate): xdr.ScVal {
+  if (typeof mode === "string") {
+    return xdr.ScVal.scvVec([xdr.ScVal.scvSymbol(mode)]);
+  }
+  return xdr.ScVal.scvVec([
+    xdr.ScVal.scvSymbol("Token"),
+    structToScVal({
+      token: new Address(mode.token).toScVal(),
+      min_balance: nativeToScVal(mode.minBalance, { type: "i128" }),
+      weighted: xdr.ScVal.scvBool(mode.weighted),
+    }),
+  ]);
+}
+
 // ── Public API ────────────────────────────────────────────────────────────────
 
-export async function deployElection(
-  kp: Keypair,
-  params: {
-    title: string;
-    optionsCount: number;
-    startTime: bigint;
-    endTime: bigint;
-    encPubkey: Uint8Array;
-    eligibilityRoot: Uint8Array;
-    distRoster: Uint8Array[];
-    distThreshold: number;
-    khRoster: Uint8Array[];
-    khThreshold: number;
-  },
-): Promise<bigint> {
-  const args = [
+export interface DeployParams {
+  title: string;
+  optionsCount: number;
+  startTime: bigint;
+  endTime: bigint;
+  encPubkey: Uint8Array;
+  eligibilityRoot: Uint8Array;
+  distRoster: Uint8Array[];
+  distThreshold: number;
+  khRoster: Uint8Array[];
+  khThreshold: number;
+  revoting?: boolean;
+  /** Latest deadline `extend_voting` may move to (defaults to endTime). */
+  maxEndTime?: bigint;
+  /** "Registry" lets the organizer edit the voter set until startTime. */
+  eligibilityMode?: "Static" | "Registry" | TokenGate;
+  /** Allow `dispute_share` and delay finalization (default: no window). */
+  disputes?: DisputeWindow;
+  /** Require key-holder stakes (default: none). */
+  staking?: KhBond;
+}
+
+function disputePolicyToScVal(window?: DisputeWindow): xdr.ScVal {
+  if (!window) {
+    return xdr.ScVal.scvVec([xdr.ScVal.scvSymbol("None")]);
+  }
+  return xdr.ScVal.scvVec([
+    xdr.ScVal.scvSymbol("Window"),
+    structToScVal({
+      secs: nativeToScVal(window.secs, { type: "u64" }),
+      vss_digest: xdr.ScVal.scvBytes(Buffer.from(window.vssDigest)),
+    }),
+  ]);
+}
+
+function stakePolicyToScVal(bond?: KhBond): xdr.ScVal {
+  if (!bond) {
+    return xdr.ScVal.scvVec([xdr.ScVal.scvSymbol("None")]);
+  }
+  return xdr.ScVal.scvVec([
+    xdr.ScVal.scvSymbol("Bond"),
+    structToScVal({
+      token: new Address(bond.token).toScVal(),
+      amount: nativeToScVal(bond.amount, { type: "i128" }),
+      recipient: new Address(bond.recipient).toScVal(),
+      tally_deadline: nativeToScVal(bond.tallyDeadline, { type: "u64" }),
+    }),
+  ]);
+}
+
+/** `deploy` arguments after `organizer`. */
+function deployArgs(params: DeployParams): xdr.ScVal[] {
+  return [
     xdr.ScVal.scvBytes(Buffer.from(new TextEncoder().encode(params.title))),
     nativeToScVal(params.optionsCount, { type: "u32" }),
     nativeToScVal(params.startTime, { type: "u64" }),
     nativeToScVal(params.endTime, { type: "u64" }),
     xdr.ScVal.scvBytes(Buffer.from(params.encPubkey)),
     xdr.ScVal.scvBytes(Buffer.from(params.eligibilityRoot)),
-    xdr.ScVal.scvVec(params.distRoster.map((pk) => xdr.ScVal.scvBytes(Buffer.from(pk)))),
-    nativeToScVal(params.distThreshold, { type: "u32" }),
-    xdr.ScVal.scvVec(params.khRoster.map((pk) => xdr.ScVal.scvBytes(Buffer.from(pk)))),
-    nativeToScVal(params.khThreshold, { type: "u32" }),
+    committeeToScVal(params.distRoster, params.distThreshold),
+    committeeToScVal(params.khRoster, params.khThreshold),
+    structToScVal({
+      revoting: xdr.ScVal.scvBool(params.revoting ?? false),
+      max_end_time: nativeToScVal(params.maxEndTime ?? params.endTime, { type: "u64" }),
+      eligibility: eligibilityModeToScVal(params.eligibilityMode ?? "Static"),
+      disputes: disputePolicyToScVal(params.disputes),
+      staking: stakePolicyToScVal(params.staking),
+    }),
   ];
+}
 
-  const result = await submitTx(kp, "deploy", args);
+export async function deployElection(
+  kp: Keypair,
+  params: DeployParams,
+): Promise<bigint> {
+  const result = await submitTx(kp, "deploy", [
+    new Address(kp.publicKey()).toScVal(),
+    ...deployArgs(params),
+  ]);
   return scValToNative(result) as bigint;
 }
 
+/**
+ * Create the election in its own single-election contract instance through
+ * the factory at `factoryId`, which takes `deploy`'s arguments. Returns the
+ * new instance's contract id; its election has eid 0.
+ */
+export async function createElectionInstance(
+  kp: Keypair,
+  factoryId: string,
+  params: DeployParams,
+): Promise<string> {
+  const result = await submitTx(
+    kp,
+    "create_election",
+    [new Address(kp.publicKey()).toScVal(), ...deployArgs(params)],
+    factoryId,
+  );
+  return scValToNative(result) as string;
+}
+
+/** Bond roster slot `khIdx` with the election's stake, paid by `kp`'s
+ *  account, which gets it back after the tally. `sig` is the key holder's
+ *  signature over `stakeMsgHash(eid, khIdx, stakerXdr)` for that account. */
+export async function depositStake(
+  kp: Keypair,
+  eid: bigint,
+  khIdx: number,
+  sig: Uint8Array,
+): Promise<void> {
+  await submitTx(kp, "deposit_stake", [
+    nativeToScVal(eid, { type: "u64" }),
+    nativeToScVal(khIdx, { type: "u32" }),
+    new Address(kp.publicKey()).toScVal(),
+    xdr.ScVal.scvBytes(Buffer.from(sig)),
+  ]);
+}
+
 export async function setKhCommitment(
   kp: Keypair,
   eid: bigint,
@@ -237,6 +401,8 @@ export async function issueAccount(
   pkCast: Uint8Array,
   nfIssue: Uint8Array,
   distSigs: Array<{ pk: Uint8Array; sig: Uint8Array }>,
+  /** Token-gated elections: the holder address, which must sign the tx. */
+  tokenHolder?: string,
 ): Promise<void> {
   // dist_sigs: Vec<(BytesN<32>, BytesN<64>)>
   // Soroban encodes tuples as inner scvVec, outer scvVec wraps the list.
@@ -253,10 +419,69 @@ export async function issueAccount(
     nativeToScVal(eid, { type: "u64" }),
     xdr.ScVal.scvBytes(Buffer.from(pkCast)),
     xdr.ScVal.scvBytes(Buffer.from(nfIssue)),
+    // eligibility: None — static-mode elections rely on the distributors
+    tokenHolder
+      ? xdr.ScVal.scvVec([
+          xdr.ScVal.scvSymbol("Token"),
+          new Address(tokenHolder).toScVal(),
+        ])
+      : xdr.ScVal.scvVoid(),
+    // revocation: None — only required once a revocation root is set
+    xdr.ScVal.scvVoid(),
     sigsVal,
   ]);
 }
 
+/**
+ * Issue several casting accounts in one atomic call (static-mode elections).
+ * `distSigs` are over the batch digest
+ * SHA256("stellot:batch" ‖ eid_le64 ‖ count_le32 ‖ issue_msg_1 ‖ …), each
+ * `issue_msg` binding its item's `leafHash(leaf)`, and
+ * `siblings` is the eligibility-tree multiproof for the items' leaf indices
+ * (bottom-up, left to right).  Items must be sorted by `leafIndex`.
+ */
+export async function batchIssueAccounts(
+  kp: Keypair,
+  eid: bigint,
+  items: Array<{
+    pkCast: Uint8Array;
+    nfIssue: Uint8Array;
+    leaf: Uint8Array;
+    leafIndex: number;
+  }>,
+  proof: { depth: number; siblings: Uint8Array[] },
+  distSigs: Array<{ pk: Uint8Array; sig: Uint8Array }>,
+): Promise<number> {
+  const result = await submitTx(kp, "batch_issue_accounts", [
+    nativeToScVal(eid, { type: "u64" }),
+    xdr.ScVal.scvVec(
+      items.map((it) =>
+        structToScVal({
+          pk_cast: xdr.ScVal.scvBytes(Buffer.from(it.pkCast)),
+          nf_issue: xdr.ScVal.scvBytes(Buffer.from(it.nfIssue)),
+          leaf: xdr.ScVal.scvBytes(Buffer.from(it.leaf)),
+          leaf_index: nativeToScVal(it.leafIndex, { type: "u32" }),
+        }),
+      ),
+    ),
+    structToScVal({
+      depth: nativeToScVal(proof.depth, { type: "u32" }),
+      siblings: xdr.ScVal.scvVec(
+        proof.siblings.map((s) => xdr.ScVal.scvBytes(Buffer.from(s))),
+      ),
+    }),
+    xdr.ScVal.scvVec(
+      distSigs.map(({ pk, sig }) =>
+        xdr.ScVal.scvVec([
+          xdr.ScVal.scvBytes(Buffer.from(pk)),
+          xdr.ScVal.scvBytes(Buffer.from(sig)),
+        ]),
+      ),
+    ),
+  ]);
+  return scValToNative(result) as number;
+}
+
 export async function castBallot(
   kp: Keypair,
   eid: bigint,
@@ -277,34 +502,179 @@ export async function castBallot(
   return scValToNative(result) as number;
 }
 
+/** ShareBatch { version, shares: Vec<DecryptionShare> }, where proof is the
+ *  ShareProof enum (None | Cp(CpProof)). */
+function shareBatchToScVal(shares: DecryptionShare[]): xdr.ScVal {
+  return structToScVal({
+    version: nativeToScVal(SHARE_BATCH_VERSION, { type: "u32" }),
+    shares: xdr.ScVal.scvVec(
+      shares.map((share) =>
+        structToScVal({
+          ballot_index: nativeToScVal(share.ballotIndex, { type: "u32" }),
+          c1: xdr.ScVal.scvBytes(Buffer.from(share.c1)),
+          d: xdr.ScVal.scvBytes(Buffer.from(share.d)),
+          proof: share.proof
+            ? xdr.ScVal.scvVec([
+                xdr.ScVal.scvSymbol("Cp"),
+                structToScVal({
+                  r1: xdr.ScVal.scvBytes(Buffer.from(share.proof.r1)),
+                  r2: xdr.ScVal.scvBytes(Buffer.from(share.proof.r2)),
+                  s: xdr.ScVal.scvBytes(Buffer.from(share.proof.s)),
+                }),
+              ])
+            : xdr.ScVal.scvVec([xdr.ScVal.scvSymbol("None")]),
+        }),
+      ),
+    ),
+  });
+}
+
 export async function postShare(
   kp: Keypair,
   eid: bigint,
   khIdx: number,
-  shares: Array<[Uint8Array, Uint8Array]>,
+  shares: DecryptionShare[],
   khPk: Uint8Array,
   sig: Uint8Array,
 ): Promise<number> {
-  // shares: Vec<(Bytes, Bytes)> — same tuple encoding as dist_sigs
-  const sharesVal = xdr.ScVal.scvVec(
-    shares.map(([c1, d]) =>
-      xdr.ScVal.scvVec([
-        xdr.ScVal.scvBytes(Buffer.from(c1)),
-        xdr.ScVal.scvBytes(Buffer.from(d)),
-      ]),
-    ),
-  );
-
   const result = await submitTx(kp, "post_share", [
     nativeToScVal(eid, { type: "u64" }),
     nativeToScVal(khIdx, { type: "u32" }),
-    sharesVal,
+    shareBatchToScVal(shares),
+    xdr.ScVal.scvBytes(Buffer.from(khPk)),
+    xdr.ScVal.scvBytes(Buffer.from(sig)),
+  ]);
+  return scValToNative(result) as number;
+}
+
+/** Open (or resume) a chunked upload; returns the index the next chunk
+ *  must start at. */
+export async function beginShares(
+  kp: Keypair,
+  eid: bigint,
+  khIdx: number,
+  digest: Uint8Array,
+  khPk: Uint8Array,
+  sig: Uint8Array,
+): Promise<number> {
+  const result = await submitTx(kp, "begin_shares", [
+    nativeToScVal(eid, { type: "u64" }),
+    nativeToScVal(khIdx, { type: "u32" }),
+    xdr.ScVal.scvBytes(Buffer.from(digest)),
     xdr.ScVal.scvBytes(Buffer.from(khPk)),
     xdr.ScVal.scvBytes(Buffer.from(sig)),
   ]);
   return scValToNative(result) as number;
 }
 
+export async function postShareChunk(
+  kp: Keypair,
+  eid: bigint,
+  khIdx: number,
+  start: number,
+  shares: DecryptionShare[],
+  sig: Uint8Array,
+): Promise<number> {
+  const result = await submitTx(kp, "post_share_chunk", [
+    nativeToScVal(eid, { type: "u64" }),
+    nativeToScVal(khIdx, { type: "u32" }),
+    nativeToScVal(start, { type: "u32" }),
+    shareBatchToScVal(shares),
+    xdr.ScVal.scvBytes(Buffer.from(sig)),
+  ]);
+  return scValToNative(result) as number;
+}
+
+/** Close a complete upload so it counts towards the share count. Anyone may
+ *  call this. */
+export async function commitShares(
+  kp: Keypair,
+  eid: bigint,
+  khIdx: number,
+): Promise<number> {
+  const result = await submitTx(kp, "commit_shares", [
+    nativeToScVal(eid, { type: "u64" }),
+    nativeToScVal(khIdx, { type: "u32" }),
+  ]);
+  return scValToNative(result) as number;
+}
+
+/** Withdraw a key holder's counted shares; `sig` signs
+ *  `withdraw_msg(eid, nonce, sharesHash)` with the nonce from
+ *  get_withdraw_nonce. Returns the new share count. */
+export async function withdrawShares(
+  kp: Keypair,
+  eid: bigint,
+  khIdx: number,
+  sig: Uint8Array,
+): Promise<number> {
+  const result = await submitTx(kp, "withdraw_shares", [
+    nativeToScVal(eid, { type: "u64" }),
+    nativeToScVal(khIdx, { type: "u32" }),
+    xdr.ScVal.scvBytes(Buffer.from(sig)),
+  ]);
+  return scValToNative(result) as number;
+}
+
+/** Strike a key holder's shares by showing the CP proof of the share for
+ *  `ballotIndex` missing or failing against the DKG commitment matrix.
+ *  Anyone may call this. Returns the new share count. */
+export async function disputeShare(
+  kp: Keypair,
+  eid: bigint,
+  khIdx: number,
+  ballotIndex: number,
+  evidence: { badProof: Uint8Array[][] },
+): Promise<number> {
+  const evidenceVal = xdr.ScVal.scvVec([
+    xdr.ScVal.scvSymbol("BadProof"),
+    xdr.ScVal.scvVec(
+      evidence.badProof.map((row) =>
+        xdr.ScVal.scvVec(row.map((a) => xdr.ScVal.scvBytes(Buffer.from(a)))),
+      ),
+    ),
+  ]);
+  const result = await submitTx(kp, "dispute_share", [
+    nativeToScVal(eid, { type: "u64" }),
+    nativeToScVal(khIdx, { type: "u32" }),
+    nativeToScVal(ballotIndex, { type: "u32" }),
+    evidenceVal,
+  ]);
+  return scValToNative(result) as number;
+}
+
+/** Earliest time `finalize_tally` may run, or null without a window or
+ *  while the threshold is unmet. */
+export async function getDisputeDeadline(eid: bigint): Promise<bigint | null> {
+  const result = await callReadOnly("get_dispute_deadline", [
+    nativeToScVal(eid, { type: "u64" }),
+  ]);
+  return (scValToNative(result) as bigint | undefined) ?? null;
+}
+
+/** Forfeit the stakes of key holders without counted shares after the
+ *  tally deadline. Anyone may call this. Returns the number slashed. */
+export async function slashAbsent(kp: Keypair, eid: bigint): Promise<number> {
+  const result = await submitTx(kp, "slash_absent", [
+    nativeToScVal(eid, { type: "u64" }),
+  ]);
+  return scValToNative(result) as number;
+}
+
+/** Return a token holder's escrowed balance once the election is tallied
+ *  or cancelled. Anyone may call this. Returns the amount released. */
+export async function releaseTokens(
+  kp: Keypair,
+  eid: bigint,
+  holder: string,
+): Promise<bigint> {
+  const result = await submitTx(kp, "release_tokens", [
+    nativeToScVal(eid, { type: "u64" }),
+    new Address(holder).toScVal(),
+  ]);
+  return scValToNative(result) as bigint;
+}
+
 export async function finalizeTally(
   kp: Keypair,
   eid: bigint,
@@ -331,6 +701,8 @@ export async function getElection(eid: bigint): Promise<ElectionInfo | null> {
     endTime: native.end_time,
     encPubkey: Uint8Array.from(native.enc_pubkey),
     tallied: native.tallied,
+    organizer: native.organizer,
+    status: native.status[0],
   };
 }
 
@@ -351,13 +723,49 @@ export async function getBallot(
   ]);
   const native = scValToNative(result) as any;
   if (!native) return null;
+  return parseBallot(native);
+}
+
+function parseBallot(native: any): EncryptedBallot {
   return {
     nfCast: Uint8Array.from(native.nf_cast),
     c1: Uint8Array.from(native.c1),
     c2: Uint8Array.from(native.c2),
+    weight: Number(native.weight),
   };
 }
 
+/** Contract-side cap on entries per paginated view (`MAX_PAGE`). */
+export const MAX_PAGE = 100;
+
+/** Ballots `start .. start + limit` (at most `MAX_PAGE`), in board order. */
+export async function getBallots(
+  eid: bigint,
+  start: number,
+  limit: number,
+): Promise<EncryptedBallot[]> {
+  const result = await callReadOnly("get_ballots", [
+    nativeToScVal(eid, { type: "u64" }),
+    nativeToScVal(start, { type: "u32" }),
+    nativeToScVal(limit, { type: "u32" }),
+  ]);
+  return (scValToNative(result) as any[]).map(parseBallot);
+}
+
+/** Every ballot on the board, fetched one page per RPC. */
+export async function getAllBallots(
+  eid: bigint,
+  ballotCount: number,
+): Promise<EncryptedBallot[]> {
+  const ballots: EncryptedBallot[] = [];
+  while (ballots.length < ballotCount) {
+    const page = await getBallots(eid, ballots.length, MAX_PAGE);
+    if (page.length === 0) break;
+    ballots.push(...page);
+  }
+  return ballots;
+}
+
 export async function getNextElectionId(): Promise<bigint> {
   const result = await callReadOnly("get_next_election_id", []);
   return scValToNative(result) as bigint;
@@ -371,6 +779,22 @@ export async function getTally(eid: bigint): Promise<number[] | null> {
   return native;
 }
 
+/** Head of the ballot-board hash chain (32 zero bytes before any ballot). */
+export async function getBoardHead(eid: bigint): Promise<Uint8Array> {
+  const result = await callReadOnly("get_board_head", [
+    nativeToScVal(eid, { type: "u64" }),
+  ]);
+  return Uint8Array.from(scValToNative(result) as Buffer);
+}
+
+/** Root of the incremental Merkle tree over accepted ballots. */
+export async function getBallotRoot(eid: bigint): Promise<Uint8Array> {
+  const result = await callReadOnly("get_ballot_root", [
+    nativeToScVal(eid, { type: "u64" }),
+  ]);
+  return Uint8Array.from(scValToNative(result) as Buffer);
+}
+
 export async function isCastNullifierUsed(
   eid: bigint,
   nf: Uint8Array,
@@ -382,12 +806,68 @@ export async function isCastNullifierUsed(
   return scValToNative(result) as boolean;
 }
 
-export async function deleteElection(kp: Keypair, eid: bigint): Promise<void> {
-  await submitTx(kp, "delete_election", [
+export async function deleteElection(kp: Keypair, eid: bigint, limit = 20): Promise<void> {
+  await walkPages(kp, "delete_election", eid, limit);
+}
+
+export async function pauseElection(kp: Keypair, eid: bigint): Promise<void> {
+  await submitTx(kp, "pause", [nativeToScVal(eid, { type: "u64" })]);
+}
+
+export async function resumeElection(kp: Keypair, eid: bigint): Promise<void> {
+  await submitTx(kp, "resume", [nativeToScVal(eid, { type: "u64" })]);
+}
+
+export async function cancelElection(
+  kp: Keypair,
+  eid: bigint,
+  reason: string,
+): Promise<void> {
+  await submitTx(kp, "cancel", [
     nativeToScVal(eid, { type: "u64" }),
+    xdr.ScVal.scvBytes(Buffer.from(new TextEncoder().encode(reason))),
   ]);
 }
 
+export async function extendVoting(
+  kp: Keypair,
+  eid: bigint,
+  newEndTime: bigint,
+): Promise<void> {
+  await submitTx(kp, "extend_voting", [
+    nativeToScVal(eid, { type: "u64" }),
+    nativeToScVal(newEndTime, { type: "u64" }),
+  ]);
+}
+
+/**
+ * Call a paged maintenance entry point (`bump_election`, `archive_election`,
+ * `delete_election`) from cursor 0 until it returns no `next`, one
+ * transaction per page of `limit` walk positions.
+ */
+async function walkPages(kp: Keypair, method: string, eid: bigint, limit: number): Promise<void> {
+  let cursor: number | null = 0;
+  while (cursor !== null) {
+    const page = scValToNative(
+      await submitTx(kp, method, [
+        nativeToScVal(eid, { type: "u64" }),
+        nativeToScVal(cursor, { type: "u32" }),
+        nativeToScVal(limit, { type: "u32" }),
+      ]),
+    ) as { next?: number | null };
+    cursor = page.next ?? null;
+  }
+}
+
+export async function archiveElection(kp: Keypair, eid: bigint, limit = 20): Promise<void> {
+  await walkPages(kp, "archive_election", eid, limit);
+}
+
+/** Renew the TTL of every ledger entry of `eid`. Anyone may call this. */
+export async function bumpElection(kp: Keypair, eid: bigint, limit = 20): Promise<void> {
+  await walkPages(kp, "bump_election", eid, limit);
+}
+
 export async function getKhRoster(eid: bigint): Promise<Uint8Array[]> {
   const result = await callReadOnly("get_kh_roster", [
     nativeToScVal(eid, { type: "u64" }),
@@ -397,15 +877,27 @@ export async function getKhRoster(eid: bigint): Promise<Uint8Array[]> {
   return native.map((b) => Uint8Array.from(b));
 }
 
+/** A KH's share blob, posted whole or reassembled from a committed chunked
+ *  upload. */
 export async function getKhShares(
   eid: bigint,
   khIdx: number,
 ): Promise<Uint8Array | null> {
-  const result = await callReadOnly("get_kh_shares", [
-    nativeToScVal(eid, { type: "u64" }),
-    nativeToScVal(khIdx, { type: "u32" }),
-  ]);
-  const native = scValToNative(result);
-  if (!native) return null;
-  return Uint8Array.from(native as Buffer);
+  const args = [nativeToScVal(eid, { type: "u64" }), nativeToScVal(khIdx, { type: "u32" })];
+  const native = scValToNative(await callReadOnly("get_kh_shares", args));
+  if (native) return Uint8Array.from(native as Buffer);
+
+  const upload = scValToNative(await callReadOnly("get_share_upload", args)) as
+    | { chunks: number; committed: boolean }
+    | null;
+  if (!upload?.committed) return null;
+  const shares: DecryptionShare[] = [];
+  for (let c = 0; c < upload.chunks; c++) {
+    const chunk = await callReadOnly("get_share_chunk", [
+      ...args,
+      nativeToScVal(c, { type: "u32" }),
+    ]);
+    shares.push(...deserialiseShareBatch(Uint8Array.from(scValToNative(chunk) as Buffer)));
+  }
+  return serialiseShareBatch(shares);
 }
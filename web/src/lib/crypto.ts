@@ -81,11 +81,13 @@ export function nullifierCast(skCast: Uint8Array, eid: bigint): Uint8Array {
 }
 
 /** Message that distributors sign for issue approval.
- *  SHA256("stellot:issue" || eid_le64 || pk_cast_32 || nf_issue_32) */
+ *  SHA256("stellot:issue" || eid_le64 || pk_cast_32 || nf_issue_32 || voter_32)
+ *  `voter` is `leafHash` of the eligibility leaf, or zeros without a proof. */
 export function issueMsgHash(
   eid: bigint,
   pkCast: Uint8Array,
   nfIssue: Uint8Array,
+  voter: Uint8Array = new Uint8Array(32),
 ): Uint8Array {
   return sha256(
     concatBytes(
@@ -93,6 +95,7 @@ export function issueMsgHash(
       bigintToLE64(eid),
       pkCast,
       nfIssue,
+      voter,
     ),
   );
 }
@@ -128,6 +131,68 @@ export function sharesMsgHash(eid: bigint, sharesBlob: Uint8Array): Uint8Array {
   );
 }
 
+/** Message that a KH signs to accept a staker's bond on its roster slot;
+ *  `stakerXdr` is the staker's `Address` as ScVal XDR.
+ *  SHA256("stellot:stake" || eid_le64 || kh_idx_le32 || staker_xdr) */
+export function stakeMsgHash(eid: bigint, khIdx: number, stakerXdr: Uint8Array): Uint8Array {
+  return sha256(
+    concatBytes(
+      new TextEncoder().encode("stellot:stake"),
+      bigintToLE64(eid),
+      u32LE(khIdx),
+      stakerXdr,
+    ),
+  );
+}
+
+/** Message that a KH signs to open a chunked upload.
+ *  SHA256("stellot:begin" || eid_le64 || digest_32) */
+export function sharesBeginMsgHash(eid: bigint, digest: Uint8Array): Uint8Array {
+  return sha256(
+    concatBytes(new TextEncoder().encode("stellot:begin"), bigintToLE64(eid), digest),
+  );
+}
+
+/** Hash of one chunk of an upload, chained into its digest.
+ *  SHA256("stellot:chunk" || eid_le64 || start_le32 || chunk_blob) */
+export function sharesChunkHash(
+  eid: bigint,
+  start: number,
+  chunkBlob: Uint8Array,
+): Uint8Array {
+  return sha256(
+    concatBytes(
+      new TextEncoder().encode("stellot:chunk"),
+      bigintToLE64(eid),
+      u32LE(start),
+      chunkBlob,
+    ),
+  );
+}
+
+/** Message that a KH signs for each chunk of the upload with `digest`.
+ *  SHA256("stellot:chunksig" || eid_le64 || digest_32 || chunk_hash_32) */
+export function sharesChunkMsgHash(
+  eid: bigint,
+  digest: Uint8Array,
+  chunkHash: Uint8Array,
+): Uint8Array {
+  return sha256(
+    concatBytes(
+      new TextEncoder().encode("stellot:chunksig"),
+      bigintToLE64(eid),
+      digest,
+      chunkHash,
+    ),
+  );
+}
+
+/** One link of an upload's chunk chain, which starts from 32 zero bytes.
+ *  SHA256("stellot:chunks" || chain_32 || chunk_hash_32) */
+export function sharesChainStep(chain: Uint8Array, chunkHash: Uint8Array): Uint8Array {
+  return sha256(concatBytes(new TextEncoder().encode("stellot:chunks"), chain, chunkHash));
+}
+
 // ── Exponential ElGamal on secp256k1 ──────────────────────────────────────────
 
 /**
@@ -232,49 +297,110 @@ export function elgamalDecrypt(
   return -1;
 }
 
-// ── Serialise shares blob (mirrors Rust serialise_shares) ─────────────────────
+// ── Serialise share batch blob (mirrors Rust serialise_share_batch) ────────────
+
+/** Layout version of a share batch blob, its first byte. */
+export const SHARE_BATCH_VERSION = 1;
+
+/** Chaum-Pedersen proof log_G(PK_j) == log_C1(D_j). */
+export interface CpProof {
+  r1: Uint8Array; // r·G (33 bytes)
+  r2: Uint8Array; // r·C1 (33 bytes)
+  s: Uint8Array; // r + c·sk_j, big-endian (32 bytes)
+}
+
+/** One entry of a key holder's share batch (DecryptionShare on-chain). */
+export interface DecryptionShare {
+  ballotIndex: number;
+  c1: Uint8Array;
+  d: Uint8Array; // D_j = sk_j·C1
+  proof?: CpProof;
+}
 
 /**
- * Serialise a Vec<(c1, d)> to the same format as the Rust contract:
- * [count u32_le] then pairs of [len u32_le][bytes][len u32_le][bytes]
+ * Serialise a share batch to the same format as the Rust contract:
+ * [version u8][count u32_le] then per share
+ * [ballot_index u32_le][c1 33][d 33][proof_kind u8], followed by
+ * [r1 33][r2 33][s 32] when proof_kind is 1.
  */
-export function serialiseShares(
-  shares: Array<[Uint8Array, Uint8Array]>,
-): Uint8Array {
-  const parts: Uint8Array[] = [];
-  parts.push(u32LE(shares.length));
-  for (const [c1, d] of shares) {
-    parts.push(u32LE(c1.length));
-    parts.push(c1);
-    parts.push(u32LE(d.length));
-    parts.push(d);
+export function serialiseShareBatch(shares: DecryptionShare[]): Uint8Array {
+  const parts: Uint8Array[] = [Uint8Array.of(SHARE_BATCH_VERSION), u32LE(shares.length)];
+  for (const share of shares) {
+    parts.push(u32LE(share.ballotIndex), share.c1, share.d);
+    if (share.proof) {
+      parts.push(Uint8Array.of(1), share.proof.r1, share.proof.r2, share.proof.s);
+    } else {
+      parts.push(Uint8Array.of(0));
+    }
   }
   return concatBytes(...parts);
 }
 
 /**
- * Deserialise a shares blob (produced by serialiseShares / the Rust contract)
- * back into an array of (c1, D_j) pairs.
+ * Deserialise a share batch blob (produced by serialiseShareBatch / the Rust
+ * contract).  Throws on an unknown version or a malformed blob.
  */
-export function deserialiseShares(blob: Uint8Array): Array<[Uint8Array, Uint8Array]> {
+export function deserialiseShareBatch(blob: Uint8Array): DecryptionShare[] {
   let off = 0;
+  const take = (n: number) => {
+    if (off + n > blob.length) throw new Error("malformed shares blob");
+    const out = blob.slice(off, off + n);
+    off += n;
+    return out;
+  };
   const readU32 = () => {
-    const v = blob[off] | (blob[off+1] << 8) | (blob[off+2] << 16) | (blob[off+3] << 24);
-    off += 4;
-    return v >>> 0; // unsigned
+    const [a, b, c, d] = take(4);
+    return (a | (b << 8) | (c << 16) | (d << 24)) >>> 0; // unsigned
   };
+  if (take(1)[0] !== SHARE_BATCH_VERSION) {
+    throw new Error("unsupported share batch version");
+  }
   const count = readU32();
-  const result: Array<[Uint8Array, Uint8Array]> = [];
+  const result: DecryptionShare[] = [];
   for (let i = 0; i < count; i++) {
-    const c1Len = readU32();
-    const c1 = blob.slice(off, off + c1Len); off += c1Len;
-    const dLen = readU32();
-    const d = blob.slice(off, off + dLen); off += dLen;
-    result.push([c1, d]);
+    const ballotIndex = readU32();
+    const c1 = take(33);
+    const d = take(33);
+    const kind = take(1)[0];
+    if (kind > 1) throw new Error("malformed shares blob");
+    const proof = kind === 1 ? { r1: take(33), r2: take(33), s: take(32) } : undefined;
+    result.push({ ballotIndex, c1, d, proof });
   }
+  if (off !== blob.length) throw new Error("malformed shares blob");
   return result;
 }
 
+/** One chunk of a chunked upload: shares from board index `start`, and the
+ *  message its post_share_chunk signature covers. */
+export interface ShareChunk {
+  start: number;
+  shares: DecryptionShare[];
+  msg: Uint8Array;
+}
+
+/** A share batch split for a chunked upload, with the digest to sign in
+ *  begin_shares. */
+export function planShareUpload(
+  eid: bigint,
+  shares: DecryptionShare[],
+  chunkSize: number,
+): { digest: Uint8Array; chunks: ShareChunk[] } {
+  const hashed: { start: number; shares: DecryptionShare[]; hash: Uint8Array }[] = [];
+  let digest = new Uint8Array(32);
+  for (let start = 0; start < shares.length; start += chunkSize) {
+    const chunk = shares.slice(start, start + chunkSize);
+    const hash = sharesChunkHash(eid, start, serialiseShareBatch(chunk));
+    digest = sharesChainStep(digest, hash);
+    hashed.push({ start, shares: chunk, hash });
+  }
+  const chunks = hashed.map(({ start, shares, hash }) => ({
+    start,
+    shares,
+    msg: sharesChunkMsgHash(eid, digest, hash),
+  }));
+  return { digest, chunks };
+}
+
 // ── Utility ────────────────────────────────────────────────────────────────────
 
 function bigintToLE64(n: bigint): Uint8Array {